@@ -1,5 +1,7 @@
 // example todomvc mostly copied from Yew's examples
 
+#![cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+
 use gloo::storage::{LocalStorage, Storage};
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumIter, IntoEnumIterator};
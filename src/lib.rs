@@ -112,312 +112,4990 @@
 //!     }
 //! }
 //! ```
+//!
+//! A curried `Option` can be given either a value or `None`, for example to have a callback for the
+//! header of a table next to the callbacks of its rows: `self.cb.on_click(i)` and
+//! `self.cb.on_click(None)` are both accepted for `OnClick(#[curry] Option<usize>, MouseEvent)`.
+//!
+//! Every curried variant also gets a `<variant>_keyed()` method that takes the keys of a whole list
+//! and returns them along with their callbacks. The cache is borrowed once for all of them:
+//!
+//! ```rust,ignore
+//! self.cb
+//!     .on_click_keyed(0..self.games.len())
+//!     .zip(self.games.iter())
+//!     .map(|((_, onclick), game)| html! {
+//!         <button {onclick}>{ format!("You should try {game}") }</button>
+//!     })
+//!     .collect()
+//! ```
+//!
+//! To avoid creating many callbacks during the first render of a big list, `warm_<variant>()`
+//! creates them ahead of time, for example with `self.cb.warm_on_click(0..2000)` in `create()`.
+//! `sync_<variant>_keys()` does the opposite: it drops the callbacks of the keys that are not in
+//! the given list anymore, for example after some items have been removed.
+//!
+//! A variant with more than one curried field also gets `invalidate_<variant>_prefix()`, which drops
+//! the callbacks whose first key is the given one and keeps all the others. For
+//! `OnCell(#[curry] usize, #[curry] usize, #[curry] usize, MouseEvent)`, closing a sheet drops the
+//! callbacks of its cells with `self.cb.invalidate_on_cell_prefix(sheet)`.
+//!
+//! When a variant has many curried fields, their order is easy to get wrong. With
+//! `#[callbacks(builder)]` on a variant with named fields, `<variant>_builder()` gives the keys by
+//! name instead and `get()` returns the callback. It only exists once all the keys are given, a
+//! missing key does not compile:
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[callbacks(builder)]
+//!     OnCell {
+//!         #[curry]
+//!         sheet: usize,
+//!         #[curry]
+//!         row: usize,
+//!         #[curry]
+//!         col: usize,
+//!         event: MouseEvent,
+//!     },
+//! }
+//!
+//! let onclick = self.cb.on_cell_builder().sheet(s).row(r).col(c).get();
+//! ```
+//!
+//! The callbacks of a variant with more than one curried named field are kept by a struct of their
+//! keys, `MsgCallbacksOnCellKey { sheet, row, col }` for `on_cell()`. It is `Clone`, `Eq` and
+//! `Hash`, and `Debug` when all the keys are. The methods that take or return the keys of the
+//! variant (`on_cell_keyed()`, `sync_on_cell_keys()`, the `keys()` of the handles of
+//! `handle_on_cell()` and the factory of `on_cell_factory()`) use it instead of a tuple, and
+//! `on_cell_by_key()` is the same getter as `on_cell()` taking the struct:
+//!
+//! ```rust,ignore
+//! let key = MsgCallbacksOnCellKey { sheet: s, row: r, col: c };
+//! assert_eq!(self.cb.on_cell_by_key(key), self.cb.on_cell(s, r, c));
+//! ```
+//!
+//! A curried field can also be read from the event with `#[curry(from_event = index)]` instead of
+//! being given to the getter, for example from an attribute `data-index` of the element. The
+//! function receives a reference to what the callback receives and returns the value of the field.
+//! The whole list then shares a single callback instead of having one per item, without changing
+//! the variant nor `update()`:
+//!
+//! ```rust,ignore
+//! fn index(event: &MouseEvent) -> usize {
+//!     event
+//!         .target_unchecked_into::<web_sys::HtmlElement>()
+//!         .dataset()
+//!         .get("index")
+//!         .and_then(|index| index.parse().ok())
+//!         .unwrap_or_default()
+//! }
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     OnClick(#[curry(from_event = index)] usize, MouseEvent),
+//! }
+//!
+//! let onclick = self.cb.on_click();
+//! self.games
+//!     .iter()
+//!     .enumerate()
+//!     .map(|(i, game)| html! {
+//!         <button data-index={i.to_string()} onclick={onclick.clone()}>{ game }</button>
+//!     })
+//!     .collect()
+//! ```
+//!
+//! The variant must have a single field received by the callback.
+//!
+//! With the feature `delegate` enabled, `#[delegate("data-row")]` does the same for a variant with
+//! a single curried field without writing the function. `<variant>_delegated()` returns a single
+//! callback to attach to the container of the items: the key is parsed from the attribute
+//! `data-row` of the closest element of the target that has it. Nothing is sent when the event did
+//! not happen in an item or when the key cannot be parsed. The getter of the variant still returns
+//! a callback per key:
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[delegate("data-row")]
+//!     SelectRow(#[curry] usize, MouseEvent),
+//! }
+//!
+//! html! {
+//!     <table onclick={self.cb.select_row_delegated()}>
+//!         { for self.rows.iter().enumerate().map(|(i, row)| html! {
+//!             <tr data-row={i.to_string()}>{ row }</tr>
+//!         }) }
+//!     </table>
+//! }
+//! ```
+//!
+//! A curried getter creates the callback of its keys when it is called. To give the items of a list
+//! to child components whose properties must stay equal across the renders of the parent,
+//! `handle_<variant>(keys)` returns a handle instead, `MsgCallbacksBoundOnClick` for `on_click()`.
+//! It is `Clone` and `PartialEq` (two handles are equal when they come from the same cache and have
+//! the same keys) and all the handles of a variant share a single callback that receives the keys
+//! along with the input, so nothing is created per item:
+//!
+//! ```rust,ignore
+//! #[derive(PartialEq, Properties)]
+//! struct ItemProps {
+//!     onclick: MsgCallbacksBoundOnClick,
+//! }
+//!
+//! #[function_component]
+//! fn Item(props: &ItemProps) -> Html {
+//!     let onclick = props.onclick.clone();
+//!     html! {
+//!         <button onclick={move |event| onclick.emit(event)}>{ "Select" }</button>
+//!     }
+//! }
+//!
+//! // in `view()` of the parent
+//! html! {
+//!     for (0..self.items.len()).map(|i| html! { <Item onclick={self.cb.handle_on_click(i)} /> })
+//! }
+//! ```
+//!
+//! `emit()` sends the message right away and `to_callback()` creates a callback that does, it is
+//! not cached.
+//!
+//! A virtualized list only knows which items it renders once it has been scrolled, the parent
+//! cannot create the callbacks of these items ahead of time. `<variant>_factory()` returns a
+//! factory instead, `MsgCallbacksOnClickFactory` for `on_click()`. It is `Clone` and `PartialEq`
+//! (the factories of a cache are always equal) and `get(keys)` creates the callback of any key in
+//! the child, the same one every time for the same keys:
+//!
+//! ```rust,ignore
+//! #[derive(PartialEq, Properties)]
+//! struct ListProps {
+//!     onclick: MsgCallbacksOnClickFactory,
+//! }
+//!
+//! #[function_component]
+//! fn List(props: &ListProps) -> Html {
+//!     let visible = use_visible_range();
+//!     html! {
+//!         for visible.map(|i| html! {
+//!             <button onclick={props.onclick.get(i)}>{ "Select" }</button>
+//!         })
+//!     }
+//! }
+//!
+//! // in `view()` of the parent
+//! html! { <List onclick={self.cb.on_click_factory()} /> }
+//! ```
+//!
+//! `retain(current)` drops the callbacks of the keys that are not rendered anymore.
+//!
+//! # Converting the events
+//!
+//! By default the callback receives the fields of the variant. With `#[input(MouseEvent)]`, it
+//! receives a `MouseEvent` instead and converts it with `From` into the field of the variant, so
+//! the message does not have to store the event itself. `#[input(KeyboardEvent, with = key)]`
+//! converts it with the function `key` instead:
+//!
+//! ```rust
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug)]
+//! struct Point {
+//!     x: i32,
+//!     y: i32,
+//! }
+//!
+//! impl From<MouseEvent> for Point {
+//!     fn from(event: MouseEvent) -> Self {
+//!         Self {
+//!             x: event.client_x(),
+//!             y: event.client_y(),
+//!         }
+//!     }
+//! }
+//!
+//! fn key(event: KeyboardEvent) -> String {
+//!     event.key()
+//! }
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[input(MouseEvent)]
+//!     Clicked(#[curry] usize, Point),
+//!     #[input(KeyboardEvent, with = key)]
+//!     Pressed(String),
+//! }
+//! ```
+//!
+//! The variant must have a single field that is not curried.
+//!
+//! # Aliasing the events
+//!
+//! A variant can be sent by more than one kind of event, for example to support the mouse and the
+//! touch screens with the same message and the same arm in `update()`.
+//! `#[alias(KeyboardEvent, map = key_step)]` generates another getter whose name ends with the name
+//! of the event, `on_step_keyboard()` for `on_step()`, that converts the `KeyboardEvent` with the
+//! function `key_step` into the field of the variant. Without `map`, it is converted with `From`:
+//!
+//! ```rust
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! fn wheel_step(event: WheelEvent) -> i32 {
+//!     event.delta_y().signum() as i32
+//! }
+//!
+//! fn key_step(event: KeyboardEvent) -> i32 {
+//!     match event.key().as_str() {
+//!         "ArrowUp" => -1,
+//!         "ArrowDown" => 1,
+//!         _ => 0,
+//!     }
+//! }
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[input(WheelEvent, with = wheel_step)]
+//!     #[alias(KeyboardEvent, map = key_step)]
+//!     OnStep(i32),
+//! }
+//! ```
+//!
+//! The variant must have a single field and no curried field.
+//!
+//! # Validating the values
+//!
+//! With `#[validate(check_email, error = InvalidEmail)]`, the value received by the callback is
+//! checked before the message is sent, after its conversion if there is one. The function takes a
+//! reference to the value and returns a `Result<(), E>`: the variant is sent when it is `Ok` and
+//! the variant given with `error` is sent with the error otherwise, so `update()` only receives
+//! values that are valid:
+//!
+//! ```rust
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! fn check_email(email: &str) -> Result<(), String> {
+//!     if email.contains('@') {
+//!         Ok(())
+//!     } else {
+//!         Err(format!("{email:?} is not an email address"))
+//!     }
+//! }
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[validate(check_email, error = InvalidEmail)]
+//!     SetEmail(String),
+//!     InvalidEmail(String),
+//! }
+//! ```
+//!
+//! The variant must have a single field that is not curried and the variant of the errors a single
+//! field for the error.
+//!
+//! # Guarding the callbacks
+//!
+//! `#[guard(can_edit)]` makes the getters of a variant ask `can_edit` whether the variant is
+//! allowed. The function takes a reference to the context given to `set_guard_context()`, for
+//! example the role of the user, and returns a `bool`. While it returns `false`, the getters return
+//! a cached callback that does nothing instead of the callback of the variant, and the messages of
+//! the variant are not sent either by the callbacks that were created before or that do not come
+//! from a getter, like the handles of `handle_<variant>()`. Nothing is allowed while there is no
+//! context of the type taken by the function:
+//!
+//! ```rust
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(PartialEq)]
+//! enum Role {
+//!     Viewer,
+//!     Editor,
+//! }
+//!
+//! fn can_edit(role: &Role) -> bool {
+//!     *role == Role::Editor
+//! }
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[guard(can_edit)]
+//!     EditCell(#[curry] usize, String),
+//!     Select(usize),
+//! }
+//! ```
+//!
+//! The context is usually set in `create()` and `changed()`, for example with
+//! `self.cb.set_guard_context(ctx.props().role.clone())`: the getters return the callbacks it
+//! allows on the next render. `#[guard]` cannot be used together with `#[external]`.
+//!
+//! # Sub-messages
+//!
+//! The component does not need to use the enum as its `Message` type: it only needs to implement
+//! `From<Msg>`. This allows splitting the messages of a big component into smaller enums, each with
+//! its own callbacks.
+//!
+//! ```
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum ToolbarMsg {
+//!     OnSave(MouseEvent),
+//! }
+//!
+//! #[derive(Debug)]
+//! enum Msg {
+//!     Toolbar(ToolbarMsg),
+//! }
+//!
+//! impl From<ToolbarMsg> for Msg {
+//!     fn from(msg: ToolbarMsg) -> Self {
+//!         Self::Toolbar(msg)
+//!     }
+//! }
+//!
+//! #[derive(Debug)]
+//! struct App {
+//!     toolbar: ToolbarMsgCallbacks<Self>,
+//! }
+//!
+//! impl Component for App {
+//!     type Message = Msg;
+//!     type Properties = ();
+//!
+//!     fn create(ctx: &Context<Self>) -> Self {
+//!         Self {
+//!             toolbar: ctx.link().into(),
+//!         }
+//!     }
+//!
+//!     fn view(&self, _ctx: &Context<Self>) -> Html {
+//!         html! {
+//!             <button onclick={self.toolbar.on_save()}>
+//!                 { "Save" }
+//!             </button>
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! # Eager callbacks
+//!
+//! By default the callbacks are created the first time you ask for them. With
+//! `#[callbacks(eager)]` on the enum, every callback that is not curried is created right away in
+//! `new()` instead.
+//!
+//! ```
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Callbacks)]
+//! #[callbacks(eager)]
+//! enum Msg {
+//!     OnClick(MouseEvent),
+//! }
+//! ```
+//!
+//! # Borrowing the callbacks
+//!
+//! A getter returns a clone of the cached callback, which increments a reference count. The
+//! variants that are not curried also get a `<variant>_ref()` getter that borrows the callback from
+//! the cache instead, for the loops that only compare or pass the callbacks by reference:
+//!
+//! ```rust,ignore
+//! for row in self.rows.iter() {
+//!     let onclick: &Callback<MouseEvent> = self.cb.on_click_ref();
+//!     // ...
+//! }
+//! ```
+//!
+//! The curried callbacks are kept behind a `RefCell` and cannot be borrowed without holding a
+//! borrow of the cache, their getters always return a clone.
+//!
+//! # Adapting the callbacks in the view
+//!
+//! A callback adapted in `view()` with `reform()` is a new callback on every render, which makes
+//! the properties of the child change every time. `cached_reform(key, make)` keeps it in the cache
+//! instead: `make` is only called the first time this line of code is run with this key, the same
+//! callback is returned on the next renders. The key tells apart the callbacks created by the same
+//! line, use `()` when there is only one:
+//!
+//! ```rust,ignore
+//! fn view(&self, _ctx: &Context<Self>) -> Html {
+//!     let onclose = self.cb.cached_reform((), || self.cb.on_close().reform(|_| Reason::Button));
+//!
+//!     self.tabs
+//!         .iter()
+//!         .map(|tab| {
+//!             let id = tab.id;
+//!             let onclick = self.cb.cached_reform(id, || self.cb.on_select().reform(move |_| id));
+//!             html! { <Tab {onclick} onclose={onclose.clone()} /> }
+//!         })
+//!         .collect()
+//! }
+//! ```
+//!
+//! The callbacks are kept for as long as the cache, or until `clear_reforms()` drops all of them.
+//! The keys should not be different on every render. Both methods are only generated for an enum
+//! with at least one curried variant, the cache of the others has no map at all.
+//!
+//! # Keyboard accessibility
+//!
+//! An element that is not a button (a `div`, a row of a table, ...) needs to handle the keyboard
+//! too to be usable without a mouse. Every variant whose callback receives nothing gets a
+//! `<variant>_a11y()` method that takes the same keys as the getter and returns two callbacks: the
+//! first one for `onclick` and the second one for `onkeydown`, which sends the same message when
+//! Enter or Space is pressed (and prevents the page from scrolling on Space). Both are cached like
+//! the callback of the getter, nothing is stored for them until the method is first called:
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     SelectRow(#[curry] usize),
+//! }
+//!
+//! self.rows
+//!     .iter()
+//!     .enumerate()
+//!     .map(|(i, row)| {
+//!         let (onclick, onkeydown) = self.cb.select_row_a11y(i);
+//!         html! { <tr role="button" tabindex="0" {onclick} {onkeydown}>{ row }</tr> }
+//!     })
+//!     .collect()
+//! ```
+//!
+//! The variants sent by the browser itself (`#[listen]`, `#[interval]`, ...) do not get one.
+//!
+//! # Callbacks by name
+//!
+//! With `#[callbacks(by_name)]` on the enum, a method `by_name()` is generated. It returns the
+//! callback of a variant from the name of its getter (`"on_click"` for `OnClick`), as a
+//! `Callback<Event>`. This is useful if you build your UI from data.
+//!
+//! Only the variants that are not curried and whose callback receives nothing or an event
+//! (`MouseEvent`, `InputEvent`, ...) are available this way, `by_name()` returns `None` for the
+//! others. An `Event` of another type than the one of the callback is dropped.
+//!
+//! ```
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Callbacks)]
+//! #[callbacks(by_name)]
+//! enum Msg {
+//!     OnClick(MouseEvent),
+//!     OnReset,
+//! }
+//! ```
+//!
+//! # Message kinds
+//!
+//! With `#[callbacks(kind)]` on the enum, an enum `MsgKind` is generated with the same variants but
+//! without their fields. `MsgKind::ALL` lists all the variants and `Msg::kind()` gives the kind of
+//! a message. The method `callback_for()` works like `by_name()` (which is also generated) but
+//! takes a `MsgKind` instead of a name, it returns `None` for the same variants. This allows
+//! iterating over all the callbacks.
+//!
+//! Without any option, `MsgCallbacks::CALLBACKS` describes the getters of the cache, for example to
+//! list the available actions in a command palette. Every `CallbackInfo` gives the name of the
+//! variant, the name of the getter, the types of its curried arguments and the type received by
+//! the callback.
+//!
+//! # Skipping variants
+//!
+//! A variant annotated with `#[callbacks(skip)]` gets no getter and is left out of the cache. This
+//! is needed for the variants that only your code sends, when one of their fields cannot be a key
+//! of the cache or cannot be converted from an event, like a curried trait object:
+//!
+//! ```rust
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Callbacks)]
+//! enum Msg {
+//!     #[callbacks(skip)]
+//!     Loaded(#[curry] Box<dyn std::any::Any>, String),
+//! }
+//! ```
+//!
+//! A trait object that is not curried works as any other field: the caller of the callback supplies
+//! it, for example `self.cb.done().emit(Box::new(|| ...))` for `Done(Box<dyn FnOnce()>)`. It must
+//! be skipped with `#[callbacks(by_name)]` or `#[callbacks(kind)]` though. `MsgKind` still has the
+//! skipped variants but `callback_for()` returns `None` for them.
+//!
+//! A variant with more than one field that is not curried gets a callback that receives a tuple,
+//! which no element accepts. The derive warns about it: curry the fields known in advance, skip
+//! the variant or annotate it with `#[callbacks(tuple)]` if the tuple is intended, for example to
+//! emit it from your own code.
+//!
+//! # Skipping fields
+//!
+//! A field annotated with `#[skip]` is neither curried nor received by the callback, it is filled
+//! with `Default::default()` or with the expression given to the attribute. This is meant for the
+//! bookkeeping fields of a message that come neither from the DOM nor from the caller:
+//!
+//! ```rust
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Callbacks)]
+//! enum Msg {
+//!     // `on_save()` returns a `Callback<MouseEvent>`
+//!     OnSave(MouseEvent, #[skip] bool, #[skip(String::from("toolbar"))] String),
+//! }
+//! ```
+//!
+//! # Defining the enum and its callbacks together
+//!
+//! `yew_callbacks::define_callbacks! { ... }` takes the enum itself and generates both the enum and
+//! its cache, exactly like the derive. The attributes of the derive are accepted in the same way.
+//! As the enum is not given to the compiler as it is written, a variant with a single field can
+//! also be followed by a closure that transforms the event into the value of the field:
+//!
+//! ```rust
+//! use yew::prelude::*;
+//!
+//! yew_callbacks::define_callbacks! {
+//!     #[derive(Debug)]
+//!     enum Msg {
+//!         OnClick(#[curry] usize, MouseEvent),
+//!         OnInput(String) = |event: InputEvent| {
+//!             event.target_unchecked_into::<web_sys::HtmlInputElement>().value()
+//!         },
+//!     }
+//! }
+//! ```
+//!
+//! Here `on_input()` returns a `Callback<InputEvent>` and sends `Msg::OnInput` with the value of
+//! the input. The type of the argument of the closure is required. The macro is also in the
+//! prelude.
+//!
+//! # Enums of other crates
+//!
+//! The derive must be on the definition of the enum, which is not possible for an enum of another
+//! crate (for example a crate shared with the server). Like the `remote` of Serde, a copy of the
+//! enum can be annotated with `#[callbacks(remote = "other_crate::Msg")]`: the callbacks then send
+//! the variants of `other_crate::Msg` and the cache is named after the copy.
+//!
+//! ```rust,ignore
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[allow(dead_code)]
+//! #[derive(Callbacks)]
+//! #[callbacks(remote = "protocol::Msg")]
+//! enum Msg {
+//!     OnClick(#[curry] usize, MouseEvent),
+//!     OnReset,
+//! }
+//! ```
+//!
+//! The copy must have the same variants and fields as the original, it is otherwise never used. The
+//! option `kind` is not available as it adds a method to the enum.
+//!
+//! # Passing callbacks to a child component
+//!
+//! With `#[callbacks(props)]` on the enum, a struct `MsgCallbackProps` is generated. It implements
+//! `Properties` and has one field per variant that is not curried, named like the getters. The
+//! method `props()` fills it with the callbacks of the cache.
+//!
+//! ```
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Callbacks)]
+//! #[callbacks(props)]
+//! enum Msg {
+//!     OnClick(MouseEvent),
+//! }
+//!
+//! #[function_component]
+//! fn Child(props: &MsgCallbackProps) -> Html {
+//!     html! {
+//!         <button onclick={props.on_click.clone()}>
+//!             { "Hello World!" }
+//!         </button>
+//!     }
+//! }
+//!
+//! #[derive(Debug)]
+//! struct App {
+//!     cb: MsgCallbacks<Self>,
+//! }
+//!
+//! impl Component for App {
+//!     type Message = Msg;
+//!     type Properties = ();
+//!
+//!     fn create(ctx: &Context<Self>) -> Self {
+//!         Self {
+//!             cb: ctx.link().into(),
+//!         }
+//!     }
+//!
+//!     fn view(&self, _ctx: &Context<Self>) -> Html {
+//!         html! {
+//!             <Child ..self.cb.props() />
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! # Forwarding the callbacks of the parent
+//!
+//! A variant annotated with `#[external]` does not send its message to the component: its getter
+//! returns a callback that forwards to the callback set with `set_external_<variant>()`, usually one
+//! given by the parent in the properties. The callback of the getter stays the same when the parent
+//! gives a new one, so the cache remains the single source of all the callbacks given to the
+//! children:
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     OnClick(MouseEvent),
+//!     #[external]
+//!     OnSave(String),
+//! }
+//!
+//! // in `create()` and `changed()`
+//! self.cb.set_external_on_save(ctx.props().onsave.clone());
+//!
+//! // in `view()`
+//! html! { <Editor onsave={self.cb.on_save()} /> }
+//! ```
+//!
+//! Nothing is sent while no callback is set. The variant cannot have curried fields.
+//!
+//! # Passing the whole cache to a child component
+//!
+//! With `#[callbacks(handle)]` on the enum, a struct `MsgCallbacksHandle<C>` is generated. It is a
+//! cheap `Clone` and `PartialEq` handle on the cache that gives access to the same getters, curried
+//! ones included. You can use it instead of `MsgCallbacks<C>` in your component and store it in the
+//! `Properties` of a child. Two handles are equal when they share the same cache, so the child is
+//! not re-rendered because of it.
+//!
+//! ```rust
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Callbacks)]
+//! #[callbacks(handle)]
+//! enum Msg {
+//!     OnClick(#[curry] usize, MouseEvent),
+//! }
+//!
+//! #[derive(Debug, Properties, PartialEq)]
+//! struct ChildProps {
+//!     index: usize,
+//!     cb: MsgCallbacksHandle<App>,
+//! }
+//!
+//! #[function_component]
+//! fn Child(props: &ChildProps) -> Html {
+//!     html! {
+//!         <button onclick={props.cb.on_click(props.index)}>
+//!             { "Hello World!" }
+//!         </button>
+//!     }
+//! }
+//!
+//! #[derive(Debug)]
+//! struct App {
+//!     cb: MsgCallbacksHandle<Self>,
+//! }
+//!
+//! impl Component for App {
+//!     type Message = Msg;
+//!     type Properties = ();
+//!
+//!     fn create(ctx: &Context<Self>) -> Self {
+//!         Self {
+//!             cb: ctx.link().into(),
+//!         }
+//!     }
+//!
+//!     fn view(&self, _ctx: &Context<Self>) -> Html {
+//!         html! {
+//!             <>
+//!                 <Child index={0} cb={self.cb.clone()} />
+//!                 <Child index={1} cb={self.cb.clone()} />
+//!             </>
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! # View helpers
+//!
+//! The component type `C` of `MsgCallbacks<C>` is only needed to create the cache. `erase()` turns
+//! it into a `MsgCallbacks`, which can be stored in the component and given to functions that are
+//! not generic over the component. It is only generated for an enum with at least one curried
+//! variant, the cache of the others is built without the component type by
+//! `from_callback(ctx.link().callback(|msg| msg))`:
+//!
+//! ```rust,ignore
+//! fn toolbar(cb: &MsgCallbacks) -> Html {
+//!     html! {
+//!         <button onclick={cb.on_click()}>{ "Save" }</button>
+//!     }
+//! }
+//!
+//! impl Component for App {
+//!     // ...
+//!
+//!     fn create(ctx: &Context<Self>) -> Self {
+//!         Self {
+//!             cb: MsgCallbacks::from(ctx.link()).erase(),
+//!         }
+//!     }
+//!
+//!     fn view(&self, _ctx: &Context<Self>) -> Html {
+//!         toolbar(&self.cb)
+//!     }
+//! }
+//! ```
+//!
+//! # Filling the properties of a child component
+//!
+//! With the feature `fill-props` enabled, `#[derive(FillProps)]` on the `Properties` of a child
+//! component wires their callbacks by name. The cache is given with `#[fill_props(MsgCallbacks)]`.
+//! The fields of type `Callback<_>` or `Option<Callback<_>>` get the callback of the getter with the
+//! same name, the underscores aside: `onclick` and `on_click` both get `on_click()`. The other
+//! fields, and the ones annotated with `#[fill_props(skip)]`, get their default value. The method
+//! `fill_props()` of the cache builds the properties and the remaining fields can be set with the
+//! struct update syntax:
+//!
+//! ```rust,ignore
+//! use yew::prelude::*;
+//! use yew_callbacks::{Callbacks, FillProps};
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     OnClick(MouseEvent),
+//!     OnReset(Event),
+//! }
+//!
+//! #[derive(Debug, Properties, PartialEq, FillProps)]
+//! #[fill_props(MsgCallbacks)]
+//! struct ChildProps {
+//!     onclick: Callback<MouseEvent>,
+//!     #[prop_or_default]
+//!     onreset: Option<Callback<Event>>,
+//!     #[prop_or_default]
+//!     label: String,
+//! }
+//!
+//! #[function_component]
+//! fn Child(props: &ChildProps) -> Html {
+//!     html! {
+//!         <form onreset={props.onreset.clone()}>
+//!             <button onclick={props.onclick.clone()}>{ &props.label }</button>
+//!         </form>
+//!     }
+//! }
+//!
+//! #[derive(Debug)]
+//! struct App {
+//!     cb: MsgCallbacks<Self>,
+//! }
+//!
+//! impl Component for App {
+//!     type Message = Msg;
+//!     type Properties = ();
+//!
+//!     fn create(ctx: &Context<Self>) -> Self {
+//!         Self {
+//!             cb: ctx.link().into(),
+//!         }
+//!     }
+//!
+//!     fn view(&self, _ctx: &Context<Self>) -> Html {
+//!         html! {
+//!             <Child ..ChildProps {
+//!                 label: "Hello World!".to_string(),
+//!                 ..self.cb.fill_props()
+//!             } />
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! # Forms
+//!
+//! With the feature `forms` enabled, `#[derive(Form)]` on the model of a form generates the enums
+//! of its fields and of their edits (`SignupField` and `SignupEdit` for a `Signup`). The values are
+//! parsed with `FromStr` and a field can be checked by a function given with `#[validate(...)]`,
+//! which returns a `Result<(), E>` where `E` implements `Display`. The types of the fields must
+//! implement `Clone`, `Debug` and `PartialEq`.
+//!
+//! A variant of the enum annotated with `#[form(Signup)]` holds a `SignupEdit`: its getter takes
+//! the field and returns a `Callback<InputEvent>` that parses and validates the value of the
+//! `<input>`, `<select>` or `<textarea>`. With `#[form(Signup, change)]` it is a `Callback<Event>`
+//! for `onchange` instead. A variant annotated with `#[form(Signup, submit)]` holds the whole
+//! model: its callback is a `Callback<SubmitEvent>` that prevents the reload of the page and reads
+//! every field from the element of the form with the same `name`. The values that are refused are
+//! sent to the variant given with `error = ...`, which holds a `FormError`, and are dropped without
+//! it:
+//!
+//! ```rust,ignore
+//! use yew::prelude::*;
+//! use yew_callbacks::{Callbacks, Form, FormError};
+//!
+//! #[derive(Debug, Default, Form)]
+//! struct Signup {
+//!     #[validate(check_email)]
+//!     email: String,
+//!     age: u32,
+//! }
+//!
+//! fn check_email(email: &str) -> Result<(), &'static str> {
+//!     if email.contains('@') {
+//!         Ok(())
+//!     } else {
+//!         Err("not an email address")
+//!     }
+//! }
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[form(Signup, error = Invalid)]
+//!     Edit(SignupEdit),
+//!     #[form(Signup, submit, error = Invalid)]
+//!     Submit(Signup),
+//!     Invalid(FormError),
+//! }
+//!
+//! // in `update()`, `Msg::Edit(edit) => self.signup.apply(edit)`
+//! html! {
+//!     <form onsubmit={self.cb.submit()}>
+//!         <input name="email" oninput={self.cb.edit(SignupField::Email)} />
+//!         <input name="age" type="number" oninput={self.cb.edit(SignupField::Age)} />
+//!     </form>
+//! }
+//! ```
+//!
+//! # Controlled inputs
+//!
+//! With the feature `bind` enabled, every variant that receives a `String` or an `AttrValue` (and
+//! that is not curried) gets a `bind_<variant>(&current)` method. It returns an `InputBinding` with
+//! the current `value` to give to the element and its cached `oninput` callback, which reads the
+//! new value of the `<input>`, `<select>` or `<textarea>` and sends it with the getter of the
+//! variant:
+//!
+//! ```rust,ignore
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     SetName(AttrValue),
+//! }
+//!
+//! // in `view()`, with `name: AttrValue` in the component
+//! let name = self.cb.bind_set_name(&self.name);
+//! html! {
+//!     <input value={name.value} oninput={name.oninput} />
+//! }
+//! ```
+//!
+//! The same variants, and the ones that receive an `Event`, also get an `edit_<variant>()` method.
+//! It returns an `EditCallbacks` with a cached callback for each of `oninput`, `onchange` and
+//! `onblur`, all sending the variant, so a widget that reacts to more than one of them does not
+//! need a variant per attribute. The `oninput` callback is the one of `bind_<variant>()`:
+//!
+//! ```rust,ignore
+//! let edit = self.cb.edit_set_name();
+//! html! {
+//!     <input oninput={edit.oninput} onblur={edit.onblur} />
+//! }
+//! ```
+//!
+//! # Sharing the callbacks through a context
+//!
+//! With `#[callbacks(context)]` on the enum, a few more items are generated:
+//!
+//! * `MsgCallbacksHandle<C>`: the handle described above.
+//! * `MsgCallbacksProvider<C>`: a component that provides the handle to all its children through
+//!   Yew's context.
+//! * `use_msg_callbacks::<C>()`: a hook that retrieves the handle from the context.
+//!
+//! ```
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Callbacks)]
+//! #[callbacks(context)]
+//! enum Msg {
+//!     OnClick(MouseEvent),
+//! }
+//!
+//! #[function_component]
+//! fn DeepChild() -> Html {
+//!     let cb = use_msg_callbacks::<App>().expect("no callbacks provided");
+//!
+//!     html! {
+//!         <button onclick={cb.on_click()}>
+//!             { "Hello World!" }
+//!         </button>
+//!     }
+//! }
+//!
+//! #[derive(Debug)]
+//! struct App {
+//!     cb: MsgCallbacksHandle<Self>,
+//! }
+//!
+//! impl Component for App {
+//!     type Message = Msg;
+//!     type Properties = ();
+//!
+//!     fn create(ctx: &Context<Self>) -> Self {
+//!         Self {
+//!             cb: ctx.link().into(),
+//!         }
+//!     }
+//!
+//!     fn view(&self, _ctx: &Context<Self>) -> Html {
+//!         html! {
+//!             <MsgCallbacksProvider<Self> context={self.cb.clone()}>
+//!                 <DeepChild />
+//!             </MsgCallbacksProvider<Self>>
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! # Keeping the callbacks across remounts
+//!
+//! A component that is destroyed and created again, after a change of route or when a keyed list is
+//! reordered, builds its cache and all its curried callbacks again. With the feature `registry`
+//! enabled, the cache can be stored under a key with `store(key)` in `destroy()` and the next
+//! component takes it back with `MsgCallbacks::reclaim(link, &key)` in `create()`. The callbacks
+//! stay the same and send their messages to the new component, a new cache is built if there is
+//! none stored under the key:
+//!
+//! ```rust,ignore
+//! impl Component for Row {
+//!     type Message = Msg;
+//!     type Properties = RowProps;
+//!
+//!     fn create(ctx: &Context<Self>) -> Self {
+//!         Self {
+//!             cb: MsgCallbacks::reclaim(ctx.link().clone(), &ctx.props().id),
+//!         }
+//!     }
+//!
+//!     fn destroy(&mut self, ctx: &Context<Self>) {
+//!         std::mem::replace(&mut self.cb, MsgCallbacks::inert()).store(ctx.props().id);
+//!     }
+//!
+//!     // ...
+//! }
+//! ```
+//!
+//! A stored cache stays in memory, with its listeners, until it is reclaimed.
+//! `MsgCallbacks::<Row>::evict(&key)` drops the cache of a key that will not come back,
+//! `MsgCallbacks::<Row>::evict_all()` drops all the caches of the component and
+//! `yew_callbacks::clear_registry()` drops all of them.
+//!
+//! # Function components
+//!
+//! With `#[callbacks(hook)]` on the enum, a hook `use_msg_callbacks_with()` is generated. It takes
+//! a function that receives the messages and returns a `MsgCallbacksHandle` that stays the same
+//! during the whole life of the component. The function itself can change on every render, the
+//! callbacks will always use the latest one.
+//!
+//! ```
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Callbacks)]
+//! #[callbacks(hook)]
+//! enum Msg {
+//!     Increment(MouseEvent),
+//! }
+//!
+//! #[function_component]
+//! fn App() -> Html {
+//!     let counter = use_state(|| 0);
+//!     let cb = {
+//!         let counter = counter.clone();
+//!         use_msg_callbacks_with(move |msg| match msg {
+//!             Msg::Increment(_) => counter.set(*counter + 1),
+//!         })
+//!     };
+//!
+//!     html! {
+//!         <button onclick={cb.increment()}>
+//!             { format!("Clicked {} times", *counter) }
+//!         </button>
+//!     }
+//! }
+//! ```
+//!
+//! The cache itself can also be built from any `Callback<Msg>` using `MsgCallbacks::from_callback()`.
+//!
+//! ## Reducers
+//!
+//! The derive macro can also be used on the actions of a reducer. The cache is then built from the
+//! dispatcher of `use_reducer()` using `from_dispatcher()`. The dispatcher never changes so you can
+//! keep the cache with `use_memo()`.
+//!
+//! ```
+//! use std::rc::Rc;
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Action {
+//!     Increment(MouseEvent),
+//! }
+//!
+//! #[derive(Default, PartialEq)]
+//! struct Counter(usize);
+//!
+//! impl Reducible for Counter {
+//!     type Action = Action;
+//!
+//!     fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+//!         match action {
+//!             Action::Increment(_) => Self(self.0 + 1).into(),
+//!         }
+//!     }
+//! }
+//!
+//! #[function_component]
+//! fn App() -> Html {
+//!     let counter = use_reducer(Counter::default);
+//!     let cb = use_memo(
+//!         |dispatcher| ActionCallbacks::from_dispatcher(dispatcher.clone()),
+//!         counter.dispatcher(),
+//!     );
+//!
+//!     html! {
+//!         <button onclick={cb.increment()}>
+//!             { format!("Clicked {} times", counter.0) }
+//!         </button>
+//!     }
+//! }
+//! ```
+//!
+//! # Workers
+//!
+//! With the feature `agent` enabled, the cache can also be built from the `WorkerScope` of a worker
+//! (`gloo-worker` or `yew-agent`) whose `Message` implements `From<Msg>`.
+//!
+//! A component can also talk to a worker through a bridge. A variant annotated with
+//! `#[bridge(MyWorker, "/worker.js")]` makes the cache spawn the worker from the given script when it
+//! is created and hold the bridge. Every output of the worker is sent as that variant, the inputs are
+//! sent with `send_to_<variant>()` and the bridge is dropped with `stop_<variant>()` or when the cache
+//! is dropped. Nothing is spawned during server-side rendering.
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[bridge(Compressor, "/compressor.js")]
+//!     Compressed(Vec<u8>),
+//! }
+//!
+//! // ...
+//! self.cb.send_to_compressed(bytes);
+//! ```
+//!
+//! # Navigation
+//!
+//! With the feature `router` enabled, a variant can be annotated with `#[navigate(...)]` and a
+//! route of your `Routable`. The callback then pushes the route using the `Navigator` before
+//! emitting the message. Add `silent` (`#[navigate(Route::Home, silent)]`) to only push the route
+//! without emitting the message. A field annotated with `#[navigate]` (curried or not) is used as
+//! the route instead.
+//!
+//! ```rust,ignore
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//! use yew_router::prelude::*;
+//!
+//! #[derive(Debug, Clone, PartialEq, Eq, Hash, Routable)]
+//! enum Route {
+//!     #[at("/")]
+//!     Home,
+//!     #[at("/game/:id")]
+//!     Game { id: usize },
+//! }
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[navigate(Route::Home, silent)]
+//!     GoHome(MouseEvent),
+//!     OpenGame(#[curry] #[navigate] Route, MouseEvent),
+//! }
+//! ```
+//!
+//! The `Navigator` is retrieved from the scope of the component when the callback is emitted. When
+//! the cache is not built from a `Scope`, it must be provided with `set_navigator()`, for example
+//! with the result of `use_navigator()` in a function component.
+//!
+//! # Global listeners
+//!
+//! With the feature `listen` enabled, a variant can be annotated with `#[listen(window, "...")]` or
+//! `#[listen(document, "...")]` and the name of an event. The listener is registered on the window
+//! or the document when the cache is created and every event is forwarded as a message. The variant
+//! can have no field or a single field that receives the event (for example a `KeyboardEvent`).
+//!
+//! ```rust,ignore
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[listen(window, "resize")]
+//!     Resized,
+//!     #[listen(document, "keydown")]
+//!     KeyDown(KeyboardEvent),
+//! }
+//! ```
+//!
+//! The listener is removed when the cache is dropped or when `stop_resized()` is called. There is no
+//! window outside of the browser so nothing is registered during server-side rendering.
+//!
+//! With `#[listen(node_ref, "...")]`, the listener is registered on an element of the component
+//! instead. The element only exists once the component is rendered, so nothing is registered until
+//! `listen_<variant>()` is called with the `NodeRef` (usually in `rendered()`). It can be called after
+//! every render: the listener is kept as long as the element is the same, moved when the element
+//! changes and removed when the element is gone. This is handy to wait for the end of a CSS
+//! transition or animation.
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[listen(node_ref, "transitionend")]
+//!     FadedOut(TransitionEvent),
+//! }
+//!
+//! // in `Component::rendered()`
+//! self.cb.listen_faded_out(&self.panel);
+//! ```
+//!
+//! With the feature `custom-event` enabled, `#[listen(custom = "...", detail = ...)]` registers a
+//! listener for a `CustomEvent` (on the window unless `document` or `node_ref` is given first). Its
+//! `detail` is deserialized with `serde-wasm-bindgen` into the given type and sent as the message;
+//! the events whose detail cannot be deserialized are ignored. This is the easiest way to integrate
+//! web components and JavaScript widgets.
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[listen(custom = "my-widget:change", detail = Selection)]
+//!     Changed(Selection),
+//!     #[listen(node_ref, custom = "my-widget:select", detail = Vec<u32>)]
+//!     Selected(Vec<u32>),
+//! }
+//! ```
+//!
+//! With the feature `service-worker` enabled, `#[listen(service_worker, "message")]` registers the
+//! listener on the `ServiceWorkerContainer` of the page, which receives the messages posted by the
+//! service worker (a new version is available, a push notification arrived, ...). The `data` of the
+//! message is deserialized with `serde-wasm-bindgen` into the type of the field and the messages that
+//! cannot be deserialized are ignored.
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Deserialize)]
+//! #[serde(tag = "type")]
+//! enum WorkerMessage {
+//!     UpdateAvailable { version: String },
+//!     Push { title: String, body: String },
+//! }
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[listen(service_worker, "message")]
+//!     FromServiceWorker(WorkerMessage),
+//! }
+//! ```
+//!
+//! When a variant with a single `bool` field listens to `fullscreenchange`, it receives whether the
+//! document is in fullscreen mode instead of the event. The methods `request_fullscreen()` (with the
+//! `NodeRef` of the element to show) and `exit_fullscreen()` are then generated too. The variant is
+//! sent when the change is done, or right away with `false` if the request failed.
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[listen(document, "fullscreenchange")]
+//!     FullscreenChanged(bool),
+//! }
+//!
+//! // ...
+//! self.cb.request_fullscreen(&self.player);
+//! ```
+//!
+//! Some nodes cannot be reached by `html!`, for example the ones rendered by a third-party JavaScript
+//! library. A variant annotated with `#[attach("...")]` gets an `attach_<variant>()` method that
+//! registers its cached callback as a listener on any `EventTarget`, with the given
+//! `EventListenerOptions`. The returned `ListenerHandle` removes the listener when it is dropped.
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[attach("click")]
+//!     Clicked(MouseEvent),
+//! }
+//!
+//! // keep the handle as long as the listener is needed
+//! let handle = self.cb.attach_clicked(&node, EventListenerOptions::default());
+//! ```
+//!
+//! Yew registers some listeners (`wheel`, `touchmove`, ...) as passive, which means their handler
+//! cannot call `prevent_default()`. For the same `#[attach]` variants, `attach_<variant>_active()`
+//! registers the listener with `passive: false` on the element of a `NodeRef` instead. It returns
+//! `None` when the element is not rendered yet.
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[attach("wheel")]
+//!     Zoomed(WheelEvent),
+//! }
+//!
+//! // in `Component::rendered()`
+//! if first_render {
+//!     self.zoom_listener = self.cb.attach_zoomed_active(&self.canvas);
+//! }
+//! ```
+//!
+//! A variant without field can be annotated with `#[before_unload]`. The cache then registers a
+//! `beforeunload` listener on the window that asks the user to confirm before leaving the page, and
+//! sends the variant when it does. With `#[before_unload(when_dirty)]`, the confirmation is only asked
+//! when the page is marked as dirty with `set_dirty(true)`, which is the usual "unsaved changes"
+//! warning. Only one variant can be annotated.
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[before_unload(when_dirty)]
+//!     Leaving,
+//! }
+//!
+//! // ...
+//! self.cb.set_dirty(self.draft != self.saved);
+//! ```
+//!
+//! A variant with a single `bool` field can also be annotated with `#[connectivity]`. It listens to
+//! both the `online` and the `offline` events of the window and sends `true` or `false` accordingly.
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[connectivity]
+//!     Online(bool),
+//! }
+//! ```
+//!
+//! In the same way, `#[visibility]` listens to the `visibilitychange` event of the document and
+//! sends `true` when the page becomes visible and `false` when it is hidden. This is handy to pause
+//! polling while the user is on another tab.
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[visibility]
+//!     VisibilityChanged(bool),
+//! }
+//! ```
+//!
+//! Finally, `#[popstate]` listens to the `popstate` event of the window and sends the current location
+//! (path, query and fragment) as a `String`. With `#[popstate(parse = parse_page)]`, the location is
+//! given to your function first so the variant can receive a typed value instead.
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[popstate]
+//!     LocationChanged(String),
+//!     #[popstate(parse = parse_page)]
+//!     PageChanged(Page),
+//! }
+//!
+//! fn parse_page(location: &str) -> Page {
+//!     todo!()
+//! }
+//! ```
+//!
+//! # Media queries
+//!
+//! With the feature `listen` enabled, a variant with a single `bool` field can be annotated with
+//! `#[media_query("...")]`. The message is sent every time the media query starts or stops matching
+//! and `matches_mobile()` returns whether it matches right now.
+//!
+//! ```rust,ignore
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[media_query("(max-width: 600px)")]
+//!     Mobile(bool),
+//! }
+//! ```
+//!
+//! Like the global listeners, the media query is registered when the cache is created and removed
+//! when it is dropped.
+//!
+//! # Storage events
+//!
+//! With the feature `storage` enabled, a variant with a single field can be annotated with
+//! `#[storage("...")]` and a key of the local storage. The message is sent every time another tab
+//! writes that key, with the value deserialized from JSON (like `gloo-storage` stores it). Values that
+//! cannot be deserialized are ignored. A removed key is received as `null`, so use an `Option` if you
+//! want to know about it.
+//!
+//! ```rust,ignore
+//! use serde::Deserialize;
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Deserialize)]
+//! struct Entry {
+//!     description: String,
+//!     completed: bool,
+//! }
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[storage("yew.todomvc.self")]
+//!     EntriesChanged(Option<Vec<Entry>>),
+//! }
+//! ```
+//!
+//! Like the global listeners, `stop_entries_changed()` removes the listener before the cache is
+//! dropped.
+//!
+//! # Broadcast channels
+//!
+//! With the feature `broadcast` enabled, a variant with a single field can be annotated with
+//! `#[broadcast("...")]` and the name of a `BroadcastChannel`. The cache opens the channel when it
+//! is created and sends the message for every value received from the other tabs. The method
+//! `broadcast_session(value)` sends a value to the other tabs. The values are serialized in JSON.
+//!
+//! ```rust,ignore
+//! use serde::{Deserialize, Serialize};
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Serialize, Deserialize)]
+//! enum Sync {
+//!     LoggedOut,
+//!     Renamed(String),
+//! }
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[broadcast("session")]
+//!     Session(Sync),
+//! }
+//! ```
+//!
+//! A tab does not receive its own values. The channel is closed when the cache is dropped or when
+//! `stop_session()` is called.
+//!
+//! # WebSockets
+//!
+//! With the feature `net` enabled, a variant with a single field can be annotated with
+//! `#[websocket]`. The methods `connect_received(url)`, `send_received(value)` and `close_received()`
+//! are then generated. Every frame received on the socket is sent as a message: a `String` field
+//! receives the text frames and a `Vec<u8>` field receives the binary frames. With
+//! `#[websocket(json)]`, the frames are serialized and deserialized in JSON instead.
+//!
+//! The variants to send when the socket is opened, closed or fails can be given with `open`, `closed`
+//! and `error`. They must be unit variants.
+//!
+//! ```rust,ignore
+//! use serde::{Deserialize, Serialize};
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Serialize, Deserialize)]
+//! struct Chat {
+//!     author: String,
+//!     text: String,
+//! }
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[websocket(json, open = "Connected", closed = "Disconnected", error = "Failed")]
+//!     Received(Chat),
+//!     Connected,
+//!     Disconnected,
+//!     Failed,
+//! }
+//! ```
+//!
+//! Connecting again closes the previous socket. The socket is closed when the cache is dropped.
+//! Closing it yourself does not send the `closed` variant.
+//!
+//! # Server-sent events
+//!
+//! With the feature `net` enabled, variants with a single field can be annotated with
+//! `#[event_source("...")]` and the type of the server-sent event they receive. All these variants
+//! share a single `EventSource` opened with `connect_event_source(url)` and closed with
+//! `close_event_source()`. A `String` field receives the data as it is. With
+//! `#[event_source("...", json)]`, the data is deserialized from JSON and the events that cannot be
+//! deserialized are ignored.
+//!
+//! ```rust,ignore
+//! use serde::Deserialize;
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Deserialize)]
+//! struct Progress {
+//!     done: u32,
+//!     total: u32,
+//! }
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[event_source("progress", json)]
+//!     Progress(Progress),
+//!     #[event_source("message")]
+//!     Message(String),
+//! }
+//! ```
+//!
+//! Connecting again closes the previous event source. It is closed when the cache is dropped.
+//!
+//! # Clipboard
+//!
+//! With the feature `clipboard` enabled, a variant with a single field can be annotated with
+//! `#[clipboard(paste)]`. Its callback then receives the `paste` event itself and the variant receives
+//! the data of the clipboard: the text for a `String` field and the files for a `Vec<web_sys::File>`
+//! field.
+//!
+//! A variant with no field or a single `bool` field can be annotated with `#[clipboard(write)]`. The
+//! method `write_text_copied(text)` is then generated: it writes the text to the clipboard and sends
+//! the message once it is done. A unit variant is only sent when the text has been written, a `bool`
+//! field receives whether it succeeded.
+//!
+//! ```rust,ignore
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[clipboard(paste)]
+//!     Pasted(String),
+//!     #[clipboard(write)]
+//!     Copied,
+//! }
+//!
+//! // ...
+//! html! {
+//!     <input onpaste={self.cb.pasted()} />
+//! }
+//! ```
+//!
+//! # Drag and drop
+//!
+//! With the feature `drag` enabled, a variant with a single field can be annotated with
+//! `#[data_transfer]` (or `#[data_transfer("application/json")]` for another format than
+//! `text/plain`). Its callback then receives the `DragEvent` itself and the variant receives the data
+//! of the `DataTransfer`. `#[drop_files]` does the same for a `Vec<web_sys::File>` field with the
+//! dropped files. When the event is a `drop`, its default action is prevented so the browser does not
+//! open the files.
+//!
+//! The method `allow_drop()` is also generated. It gives a callback for `ondragover` that prevents
+//! the default action, which is required for the element to accept a drop.
+//!
+//! ```rust,ignore
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[drop_files]
+//!     Dropped(Vec<web_sys::File>),
+//! }
+//!
+//! // ...
+//! html! {
+//!     <div ondragover={self.cb.allow_drop()} ondrop={self.cb.dropped()} />
+//! }
+//! ```
+//!
+//! # Pointer capture
+//!
+//! With the feature `pointer` enabled, a variant with a `PointerEvent` field can be annotated with
+//! `#[capture]`. On `pointerdown`, its callback captures the pointer on the element the listener is
+//! attached to, so the element keeps receiving the pointer events even when the pointer leaves it.
+//! The capture is released on `pointerup` (or `pointercancel`). The captured pointers are tracked by
+//! the cache itself, so the pointer can be captured by one variant and released by another. This is
+//! what sliders and drag handles need.
+//!
+//! The method `has_pointer_capture()` tells if a pointer is currently captured.
+//!
+//! ```rust,ignore
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[capture]
+//!     Grab(PointerEvent),
+//!     #[capture]
+//!     Release(PointerEvent),
+//!     Slide(PointerEvent),
+//! }
+//!
+//! // ...
+//! html! {
+//!     <div
+//!         onpointerdown={self.cb.grab()}
+//!         onpointermove={self.cb.slide()}
+//!         onpointerup={self.cb.release()}
+//!     />
+//! }
+//! ```
+//!
+//! # Geolocation
+//!
+//! With the feature `geolocation` enabled, a variant with a single field can be annotated with
+//! `#[geolocation(watch)]` to receive the position updates of
+//! `navigator.geolocation.watchPosition()`. The field is either a
+//! `yew_callbacks::GeolocationPosition` (the errors are then ignored) or a
+//! `Result<GeolocationPosition, GeolocationError>`.
+//!
+//! Watching the position asks the user for the permission, so it does not start on its own: the methods
+//! `start_<variant>()`, `stop_<variant>()` and `is_running_<variant>()` are generated. The watch is
+//! cleared when the cache is dropped.
+//!
+//! ```rust,ignore
+//! use yew::prelude::*;
+//! use yew_callbacks::{Callbacks, GeolocationPosition};
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[geolocation(watch)]
+//!     Moved(GeolocationPosition),
+//! }
+//!
+//! // in `Component::update()`, once the user asked for it
+//! self.cb.start_moved();
+//! ```
+//!
+//! # JavaScript interop
+//!
+//! With the feature `js-interop` enabled, a variant annotated with `#[js]` gets a `<variant>_js()`
+//! method that returns its cached callback wrapped in a `js_sys::Function`. It can be given as an
+//! event handler to a JavaScript library (Chart.js, Leaflet, ...). The `Closure` behind it is cached
+//! too so the same function object is returned every time, and it is released when the cache is
+//! dropped. The variant can have no field or a single field that can be received from JavaScript
+//! (a number, a `String`, a `JsValue`, an event, ...).
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[js]
+//!     Zoomed(f64),
+//! }
+//!
+//! // ...
+//! map.set_on_zoom(&self.cb.zoomed_js());
+//! ```
+//!
+//! The variant can also have curried fields, then a function (and its `Closure`) is cached for every
+//! key. Each of them can be released early with `drop_<variant>_js()`, or leaked on purpose with
+//! `forget_<variant>_js()` when the JavaScript library keeps calling it after the component is gone.
+//! `clear_js()` releases all of them at once. With `#[js(forget)]`, the closures of the variant are
+//! leaked instead of released when the cache is dropped.
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[js(forget)]
+//!     MarkerClicked(#[curry] usize, MouseEvent),
+//! }
+//!
+//! // ...
+//! marker.on("click", &self.cb.marker_clicked_js(i));
+//! ```
+//!
+//! # Device sensors
+//!
+//! With the feature `sensors` enabled, a variant can be annotated with `#[device_orientation]` or
+//! `#[device_motion]`. The cache registers the `deviceorientation` or `devicemotion` listener on the
+//! window and sends its data as a plain `DeviceOrientation` (`alpha`, `beta` and `gamma` angles) or
+//! `DeviceMotion` (acceleration and rotation rate) struct. The listener is removed with
+//! `stop_<variant>()` or when the cache is dropped.
+//!
+//! On iOS, the events are only sent once the user allowed it. The method
+//! `request_permission_<variant>()` asks for it and gives the answer to its closure; it must be called
+//! while handling a user gesture like a click. The other browsers do not ask and always answer `true`.
+//!
+//! ```rust,ignore
+//! use yew::prelude::*;
+//! use yew_callbacks::{Callbacks, DeviceOrientation};
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[device_orientation]
+//!     Oriented(DeviceOrientation),
+//!     EnableSensors,
+//!     SensorsEnabled(bool),
+//! }
+//!
+//! // in `Component::update()`
+//! Msg::EnableSensors => {
+//!     let enabled = self.cb.sensors_enabled();
+//!     self.cb.request_permission_oriented(move |allowed| enabled.emit(allowed));
+//!     false
+//! }
+//! ```
+//!
+//! # Gamepads
+//!
+//! With the feature `gamepad` enabled, a variant with a single `GamepadInput` field can be annotated
+//! with `#[gamepad]`. The cache listens to the `gamepadconnected` and `gamepaddisconnected` events of
+//! the window and, while at least one gamepad is connected, polls the gamepads at every animation
+//! frame. Only the changes are sent: a button pressed or released (with its analog value) or an axis
+//! moved, along with the connections and disconnections. The browsers only report a gamepad once one
+//! of its buttons has been pressed. Everything is stopped with `stop_<variant>()` or when the cache is
+//! dropped.
+//!
+//! ```rust,ignore
+//! use yew::prelude::*;
+//! use yew_callbacks::{Callbacks, GamepadInput};
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[gamepad]
+//!     Gamepad(GamepadInput),
+//! }
+//!
+//! // in `Component::update()`
+//! Msg::Gamepad(GamepadInput::Button { button: 0, pressed: true, .. }) => {
+//!     self.player.jump();
+//!     true
+//! }
+//! ```
+//!
+//! # Scroll position
+//!
+//! With the feature `scroll` enabled, a variant can be annotated with `#[scroll(throttle = ...)]`. The
+//! cache registers a `scroll` listener on the window and sends the position as a `ScrollPosition`
+//! (`x` and `y` in pixels). The first event is sent right away, then at most once every `throttle`
+//! milliseconds, and the last position is always sent once the user stops scrolling.
+//!
+//! To follow a scrollable element instead, use `#[scroll(node_ref, throttle = ...)]` and call
+//! `listen_<variant>()` with its `NodeRef` once it is rendered. The listener is removed with
+//! `stop_<variant>()` or when the cache is dropped.
+//!
+//! ```rust,ignore
+//! use yew::prelude::*;
+//! use yew_callbacks::{Callbacks, ScrollPosition};
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[scroll(throttle = 100)]
+//!     Scrolled(ScrollPosition),
+//!     #[scroll(node_ref, throttle = 50)]
+//!     ListScrolled(ScrollPosition),
+//! }
+//!
+//! // in `Component::rendered()`
+//! if first_render {
+//!     self.cb.listen_list_scrolled(&self.list_ref);
+//! }
+//! ```
+//!
+//! # Intervals
+//!
+//! With the feature `timers` enabled, a variant can be annotated with `#[interval(ms = ...)]`. The
+//! cache then owns an interval that sends the message periodically. The variant can have no field or
+//! a single `u32` field that receives the number of ticks since the interval was started.
+//!
+//! ```rust,ignore
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[interval(ms = 1000)]
+//!     Tick,
+//!     #[interval(ms = 5000)]
+//!     Poll(u32),
+//! }
+//! ```
+//!
+//! The interval is started when the cache is created and stopped when it is dropped. The methods
+//! `start_tick()`, `stop_tick()` and `is_running_tick()` control it in the meantime. Starting an
+//! interval that is already running restarts it. Like the listeners, nothing runs during server-side
+//! rendering.
+//!
+//! # Timeouts
+//!
+//! With the feature `timers` enabled, a variant can also be annotated with `#[timeout]`. Two methods
+//! are generated: `schedule_dismiss(ms)` sends the message once after the delay and
+//! `cancel_dismiss()` cancels it. Scheduling it again replaces the pending timeout. When the variant
+//! has fields, their values are given after the delay (`schedule_undo(5000, index)`).
+//!
+//! ```rust,ignore
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[timeout]
+//!     Dismiss,
+//!     #[timeout]
+//!     Undo(usize),
+//! }
+//! ```
+//!
+//! The pending timeouts are cancelled when the cache is dropped.
+//!
+//! # Deferred messages
+//!
+//! With the feature `timers` enabled, a variant can be annotated with `#[deferred]`. Its callbacks
+//! then send the message from a timeout of 0 ms instead of during the event: the event handler
+//! returns right away and the heavy work of `update()` happens on the next tick, which avoids
+//! dropping frames while typing in an input.
+//!
+//! ```rust,ignore
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[deferred]
+//!     Search(String),
+//! }
+//! ```
+//!
+//! The messages are still sent in order. Outside of the browser, they are sent immediately.
+//!
+//! # Animation frames
+//!
+//! With the feature `render` enabled, a variant with a single `f64` field can be annotated with
+//! `#[raf]`. The methods `start_frame()` and `stop_frame()` are then generated to drive a
+//! `requestAnimationFrame` loop that sends the message with the timestamp at every frame.
+//!
+//! ```rust,ignore
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[raf]
+//!     Frame(f64),
+//! }
+//! ```
+//!
+//! The loop is owned by the cache: it is stopped when the cache is dropped and it can be stopped while
+//! handling the message. Nothing runs during server-side rendering.
+//!
+//! # Idle periods
+//!
+//! With the feature `idle` enabled, a variant with a single `f64` field can be annotated with
+//! `#[idle]` or `#[idle(timeout = ...)]`. The methods `start_<variant>()` and `stop_<variant>()` then
+//! drive a `requestIdleCallback` loop that sends the message when the browser is idle, with the time
+//! remaining in the idle period (in milliseconds). With a `timeout`, the message is sent after that
+//! many milliseconds even if the browser is never idle.
+//!
+//! This is the place for expensive work that can wait: start the loop when something changes, do a
+//! chunk of the work while handling the message and stop the loop once everything is done.
+//!
+//! ```rust,ignore
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[idle(timeout = 200)]
+//!     Recompute(f64),
+//! }
+//!
+//! // in `Component::update()`
+//! Msg::Recompute(time_remaining) => {
+//!     self.index.rebuild_some(time_remaining);
+//!     if self.index.is_done() {
+//!         self.cb.stop_recompute();
+//!     }
+//!     true
+//! }
+//! ```
+//!
+//! Like animation frames, the loop is owned by the cache and nothing runs during server-side
+//! rendering.
+//!
+//! # Intersection observers
+//!
+//! With the feature `observers` enabled, a variant with a curried key and a `bool` field can be
+//! annotated with `#[observe_intersection]` (or `#[observe_intersection(threshold = 0.5)]`). The
+//! message is sent with `true` when the element enters the viewport and `false` when it leaves it.
+//! The key tells which element it is about.
+//!
+//! ```rust,ignore
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     #[observe_intersection(threshold = 0.5)]
+//!     Visible(#[curry] usize, bool),
+//! }
+//! ```
+//!
+//! Call `observe_visible(index, &node_ref)` once the element is rendered (for example in
+//! `rendered()`) and `unobserve_visible(&index)` when you are not interested anymore. Observing an
+//! element again with the same key replaces the previous one. The observer is disconnected when the
+//! cache is dropped.
+//!
+//! # Prelude
+//!
+//! `use yew_callbacks::prelude::*;` imports the derive and the types of the enabled features
+//! (`ListenerHandle`, `ScrollPosition`, `GamepadInput`, ...) at once, next to
+//! `use yew::prelude::*;`.
+//!
+//! # Reentrancy
+//!
+//! The getters never hold a borrow on the cache while a callback is being created. The only code of
+//! yours that runs while the cache is borrowed is the `Hash` and `Eq` implementations of the curried
+//! arguments. This means you can safely call any getter from anywhere, including from a callback
+//! that is emitted synchronously while another getter is running.
+//!
+//! The getters are `#[track_caller]`: if one of them panics anyway (because a `Hash` implementation
+//! called a getter), the message names the variant and points at your call of the getter.
+//!
+//! # Expiring the curried callbacks
+//!
+//! With the feature `ttl` enabled, `#[curry(ttl = 300s)]` on one of the curried fields of a variant
+//! drops its callbacks that have not been returned by the getter for that long, in `ms`, `s`, `min`
+//! or `h`. The callbacks are not dropped on a timer: the expired ones are dropped when a new
+//! callback of the variant is created (at most once per `ttl`), or right away by the method
+//! `sweep()` of the cache, for example in `rendered()`:
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     OpenPanel(#[curry(ttl = 5min)] PanelId, MouseEvent),
+//! }
+//! ```
+//!
+//! A callback that is dropped is created again by the next call of the getter, it is a different
+//! callback than the one before.
+//!
+//! # Hashing the curried arguments
+//!
+//! The callbacks of a curried variant are kept in a small list as long as there are at most 8 of
+//! them: the curried arguments are compared with `Eq` and they are never hashed. Past this, the
+//! callbacks are moved into a `HashMap` keyed by the curried arguments. A getter hashes them once
+//! when the callback is cached, but twice when it is not. With the feature `hashbrown` enabled, the
+//! map of `hashbrown` is used instead and the hash is kept for the insertion, so every call hashes
+//! the arguments once. The other callbacks, including the ones of `by_name()` and of
+//! `<variant>_a11y()`, are all known in advance and are stored without any map. The map of
+//! `cached_reform()` is only created by its first call, there is none without curried variants, and
+//! the functions of `#[js]` are kept in a map by their curried arguments, even for a variant that
+//! is not curried.
+//!
+//! # Reducing the size of the code
+//!
+//! Every callback normally gets a closure of its own, which is compiled separately for each variant
+//! of each enum. With the feature `small-code` enabled, the callbacks that only build their message
+//! from what they receive share a single function per enum instead: it is given the index of the
+//! variant and the input as a `Box<dyn Any>`. This makes the wasm binary smaller in applications
+//! with many components, at the cost of an allocation and a downcast every time a callback is
+//! emitted. The curried variants and the ones using `#[capture]`, `#[deferred]` or `#[navigate]`
+//! keep their own closure.
+//!
+//! # Diagnostics
+//!
+//! The callbacks only keep their identity if the cache is kept by the component and the curried
+//! keys are the same from one render to the next. With the feature `diagnostics` enabled, debug
+//! builds warn in the console (or on the standard error outside of the browser) when:
+//!
+//! - a getter of a curried variant has created 1000 callbacks without ever returning one of them
+//!   again: its keys are probably different on every render;
+//! - a callback sends a message after its cache has been dropped: the cache is probably built again
+//!   in `view()` instead of being created once in `create()`;
+//! - the caches of a component type have been built 100 times more than the number of them that
+//!   were alive at once: one of them is probably built on every render;
+//! - a curried variant reaches 1000, 10 000 or 100 000 callbacks: the callbacks of the keys that
+//!   are not used anymore might never be dropped, see `sync_<variant>_keys()`. The thresholds can
+//!   be changed with `yew_callbacks::set_len_warnings()`.
+//!
+//! Nothing is reported in release builds.
+//!
+//! # Testing
+//!
+//! With the feature `test-util` enabled, every variant that receives an event gets a
+//! `simulate_<variant>()` method that emits a synthetic event through the cached callback, as if
+//! the user had interacted with the element. The curried arguments are passed as usual:
+//!
+//! ```rust,ignore
+//! use yew::prelude::*;
+//! use yew_callbacks::Callbacks;
+//!
+//! #[derive(Debug, Callbacks)]
+//! enum Msg {
+//!     OnClick(#[curry] usize, MouseEvent),
+//! }
+//!
+//! // in a `wasm-bindgen-test`, with `cb: MsgCallbacks<Test>`
+//! cb.simulate_on_click(1);
+//! ```
+//!
+//! The events are created with their default values (for example a `click` at `(0, 0)` without any
+//! modifier key) and have no target, so the `update()` function must not rely on them. Creating an
+//! event requires a browser: the helpers can only be used in tests running in `wasm32`.
+//!
+//! To exercise the component itself, `MsgCallbacks::<Test>::test_mount(props)` renders it in a new
+//! element of the document and returns a `TestMount` that dereferences to a cache sending its
+//! messages to the mounted component. The events can also be dispatched on the rendered elements,
+//! so the callbacks of `view()` are called exactly as they would be in the browser:
+//!
+//! ```rust,ignore
+//! use wasm_bindgen_test::*;
+//!
+//! wasm_bindgen_test_configure!(run_in_browser);
+//!
+//! #[wasm_bindgen_test]
+//! async fn click() {
+//!     let app = MsgCallbacks::<Test>::test_mount(());
+//!     app.dispatch("button", &MouseEvent::new("click").unwrap());
+//!     app.simulate_on_click(1);
+//!     app.settle().await;
+//!     assert_eq!(app.query("span").text_content().as_deref(), Some("2"));
+//! }
+//! ```
+//!
+//! `settle()` waits until the messages are handled and the component is rendered again. The
+//! component is destroyed and its element removed when the `TestMount` is dropped. This requires
+//! the feature `csr` of Yew.
+//!
+//! # Yew versions
+//!
+//! The generated code works with Yew 0.20 by default. Enable the feature `yew-0_21` (or `yew-next`
+//! for the development version of Yew) to generate code for a more recent version instead:
+//!
+//! ```toml
+//! [dependencies]
+//! yew-callbacks = { version = "0.2", features = ["yew-0_21"] }
+//! ```
+//!
+//! The feature `yew-0_20` does not change anything, it only makes the choice explicit. If more than
+//! one of them is enabled, for example by two crates of the same workspace, the oldest version is
+//! used. The feature `router` is only available with Yew 0.20.
+//!
+//! # Server-side rendering
+//!
+//! The generated struct is not `Send` nor `Sync`. This is not something this crate can fix: Yew's
+//! `Scope` and `Callback` are both built on `Rc`. There is no need for it either:
+//! `yew::ServerRenderer` can be used on multi-threaded runtimes as it renders every component tree
+//! on a single thread, so your components never need to be `Send`.
+//!
+//! When there is no component to send the messages to, for example to render a component in
+//! isolation for a storybook or in a test of the server-side rendering, `MsgCallbacks::inert()`
+//! builds a cache whose callbacks do nothing. The events they receive are not even converted, so
+//! nothing can panic when one of them is emitted, and the listeners of the enum are not registered.
+
+pub use yew_callbacks_macro::{define_callbacks, Callbacks};
+
+#[cfg(feature = "fill-props")]
+pub use yew_callbacks_macro::FillProps;
+
+#[cfg(feature = "forms")]
+pub use yew_callbacks_macro::Form;
+
+// yew-router 0.17 only works with Yew 0.20
+#[cfg(all(
+    feature = "router",
+    any(feature = "yew-0_21", feature = "yew-next"),
+    not(feature = "yew-0_20"),
+))]
+compile_error!("the feature `router` is only available with Yew 0.20");
+
+#[cfg(feature = "listen")]
+pub use gloo_events::EventListenerOptions;
+
+/// The derive and the types of the enabled features, for a single `use yew_callbacks::prelude::*;`.
+pub mod prelude {
+    pub use crate::{define_callbacks, Callbacks};
+
+    #[cfg(feature = "bind")]
+    pub use crate::{EditCallbacks, InputBinding};
+
+    #[cfg(feature = "fill-props")]
+    pub use crate::FillProps;
+
+    #[cfg(feature = "forms")]
+    pub use crate::{Form, FormError};
+
+    #[cfg(feature = "listen")]
+    pub use crate::{EventListenerOptions, ListenerHandle};
+
+    #[cfg(feature = "sensors")]
+    pub use crate::{Acceleration, DeviceMotion, DeviceOrientation, RotationRate};
+
+    #[cfg(feature = "scroll")]
+    pub use crate::ScrollPosition;
+
+    #[cfg(feature = "gamepad")]
+    pub use crate::GamepadInput;
+
+    #[cfg(feature = "test-util")]
+    pub use crate::TestMount;
+}
+
+/// The description of a getter of the cache, listed in its constant `CALLBACKS`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CallbackInfo {
+    /// The name of the variant, `OnClick`.
+    pub variant: &'static str,
+    /// The name of the getter, `on_click`.
+    pub getter: &'static str,
+    /// The types of the curried arguments of the getter, in order.
+    pub curried: &'static [&'static str],
+    /// The type received by the callback, `MouseEvent`.
+    pub input: &'static str,
+}
+
+impl CallbackInfo {
+    /// Whether the getter takes curried arguments.
+    pub fn is_curried(&self) -> bool {
+        !self.curried.is_empty()
+    }
+}
+
+/// `Properties` that can be built from the cache `T` with its method `fill_props()`.
+///
+/// Implemented by `#[derive(FillProps)]`: every field of type `Callback<_>` (or
+/// `Option<Callback<_>>`) gets the callback of the same name and the other fields get their
+/// default value.
+#[cfg(feature = "fill-props")]
+pub trait FillProps<T> {
+    /// Builds the properties with the callbacks of `callbacks`.
+    fn fill_props(callbacks: &T) -> Self;
+}
+
+/// The value and the callback of a controlled input, returned by the `bind_*()` methods.
+#[cfg(feature = "bind")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputBinding<V, C> {
+    /// The current value, for the attribute `value`.
+    pub value: V,
+    /// The cached callback, for the attribute `oninput`.
+    pub oninput: C,
+}
+
+/// The callbacks of a variant for `oninput`, `onchange` and `onblur`, returned by the `edit_*()`
+/// methods.
+#[cfg(feature = "bind")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EditCallbacks<I, C, B> {
+    /// For the attribute `oninput`.
+    pub oninput: I,
+    /// For the attribute `onchange`.
+    pub onchange: C,
+    /// For the attribute `onblur`.
+    pub onblur: B,
+}
+
+/// A model edited field by field by the `#[form]` variants of a cache.
+///
+/// Implemented by `#[derive(Form)]`, which also generates the enums of its fields and edits
+/// (`SignupField` and `SignupEdit` for a `Signup`).
+#[cfg(feature = "forms")]
+pub trait Form: Sized {
+    /// The names of the fields, the key of the cached callbacks.
+    type Field: Copy + Eq + std::hash::Hash + 'static;
+    /// A new value for one of the fields.
+    type Edit;
+
+    /// Parses and validates the value of the element of a field.
+    fn parse(field: Self::Field, value: &str) -> Result<Self::Edit, FormError>;
+
+    /// Sets the field of `edit` on the model.
+    fn apply(&mut self, edit: Self::Edit);
+
+    /// Builds the whole model from the values of the elements named after its fields.
+    fn from_values(values: impl Fn(&str) -> String) -> Result<Self, FormError>;
+}
+
+/// A value of a form that could not be parsed or that was refused by its `#[validate]` function.
+#[cfg(feature = "forms")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormError {
+    /// The name of the field, `email`.
+    pub field: &'static str,
+    /// The error of `FromStr` or of the validation function.
+    pub message: String,
+}
+
+#[cfg(feature = "forms")]
+impl std::fmt::Display for FormError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+#[cfg(feature = "forms")]
+impl std::error::Error for FormError {}
+
+/// A listener registered by one of the `attach_*()` methods.
+///
+/// The listener is removed from its target when the handle is dropped.
+#[cfg(feature = "listen")]
+#[derive(Debug)]
+#[must_use = "the listener is removed when the handle is dropped"]
+pub struct ListenerHandle(gloo_events::EventListener);
+
+#[cfg(feature = "listen")]
+impl ListenerHandle {
+    /// Keeps the listener registered for as long as its target exists.
+    pub fn forget(self) {
+        self.0.forget()
+    }
+}
+
+/// Drops all the caches stored with their method `store()` that have not been reclaimed.
+#[cfg(feature = "registry")]
+pub fn clear_registry() {
+    let caches = __private::REGISTRY.with(|registry| std::mem::take(&mut *registry.borrow_mut()));
+    drop(caches);
+}
+
+/// Sets the numbers of callbacks of a curried variant at which the feature `diagnostics` warns
+/// that they might never be dropped, 1000, 10 000 and 100 000 by default.
+#[cfg(feature = "diagnostics")]
+pub fn set_len_warnings(thresholds: impl IntoIterator<Item = usize>) {
+    let thresholds = thresholds.into_iter().collect();
+    __private::LEN_WARNINGS.with(|warnings| *warnings.borrow_mut() = thresholds);
+}
+
+/// The orientation of the device sent by the `#[device_orientation]` variants.
+///
+/// The angles are in degrees and are `None` when the device has no such sensor.
+#[cfg(feature = "sensors")]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DeviceOrientation {
+    pub alpha: Option<f64>,
+    pub beta: Option<f64>,
+    pub gamma: Option<f64>,
+    pub absolute: bool,
+}
+
+/// The motion of the device sent by the `#[device_motion]` variants.
+#[cfg(feature = "sensors")]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DeviceMotion {
+    pub acceleration: Acceleration,
+    pub acceleration_including_gravity: Acceleration,
+    pub rotation_rate: RotationRate,
+    /// Interval in milliseconds between two events.
+    pub interval: Option<f64>,
+}
+
+/// An acceleration in m/s².
+#[cfg(feature = "sensors")]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Acceleration {
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+    pub z: Option<f64>,
+}
+
+/// A rotation rate in degrees per second.
+#[cfg(feature = "sensors")]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RotationRate {
+    pub alpha: Option<f64>,
+    pub beta: Option<f64>,
+    pub gamma: Option<f64>,
+}
+
+/// A position sent by the `#[geolocation(watch)]` variants.
+///
+/// The latitude, the longitude and the heading are in degrees, the distances in meters and the
+/// speed in meters per second. The optional fields are `None` when the device does not provide
+/// them.
+#[cfg(feature = "geolocation")]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct GeolocationPosition {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub accuracy: f64,
+    pub altitude: Option<f64>,
+    pub altitude_accuracy: Option<f64>,
+    pub heading: Option<f64>,
+    pub speed: Option<f64>,
+    /// Milliseconds since the Unix epoch.
+    pub timestamp: f64,
+}
+
+/// An error received by the `#[geolocation(watch)]` variants whose field is a `Result`.
+#[cfg(feature = "geolocation")]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GeolocationError {
+    /// `1` when the permission was denied, `2` when the position is unavailable and `3` on a
+    /// timeout.
+    pub code: u16,
+    pub message: String,
+}
+
+/// The scroll position sent by the `#[scroll]` variants, in pixels.
+#[cfg(feature = "scroll")]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ScrollPosition {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// A change of a gamepad sent by the `#[gamepad]` variants.
+///
+/// `index` is the index of the gamepad in `navigator.getGamepads()`, the buttons and the axes are
+/// numbered in the same way as the `buttons` and `axes` of the
+/// [standard mapping](https://w3c.github.io/gamepad/#remapping).
+#[cfg(feature = "gamepad")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum GamepadInput {
+    Connected {
+        index: u32,
+        id: String,
+    },
+    Disconnected {
+        index: u32,
+    },
+    Button {
+        index: u32,
+        button: u32,
+        pressed: bool,
+        value: f64,
+    },
+    Axis {
+        index: u32,
+        axis: u32,
+        value: f64,
+    },
+}
+
+/// A component mounted in the document by the generated `test_mount()`.
+///
+/// It dereferences to a cache of callbacks that sends its messages to the mounted component. The
+/// component is destroyed and its root element is removed from the document when this is dropped.
+#[cfg(feature = "test-util")]
+#[must_use = "the component is destroyed when this is dropped"]
+pub struct TestMount<T> {
+    callbacks: T,
+    root: web_sys::Element,
+    destroy: Option<Box<dyn FnOnce()>>,
+}
+
+#[cfg(feature = "test-util")]
+impl<T> TestMount<T> {
+    /// The element the component is rendered in.
+    pub fn root(&self) -> &web_sys::Element {
+        &self.root
+    }
+
+    /// Returns the first element rendered by the component that matches the CSS `selector`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no element matches.
+    #[track_caller]
+    pub fn query(&self, selector: &str) -> web_sys::Element {
+        match self.root.query_selector(selector) {
+            Ok(Some(element)) => element,
+            _ => panic!("no element matches `{selector}`"),
+        }
+    }
+
+    /// Dispatches `event` on the first element that matches the CSS `selector`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no element matches.
+    #[track_caller]
+    pub fn dispatch(&self, selector: &str, event: &web_sys::Event) {
+        self.query(selector)
+            .dispatch_event(event)
+            .expect("could not dispatch the event");
+    }
+
+    /// Waits until the messages sent so far have been handled and the component is rendered again.
+    pub async fn settle(&self) {
+        gloo_timers::future::TimeoutFuture::new(0).await;
+    }
+}
+
+#[cfg(feature = "test-util")]
+impl<T> std::ops::Deref for TestMount<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.callbacks
+    }
+}
+
+#[cfg(feature = "test-util")]
+impl<T: std::fmt::Debug> std::fmt::Debug for TestMount<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TestMount")
+            .field("callbacks", &self.callbacks)
+            .field("root", &self.root)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "test-util")]
+impl<T> Drop for TestMount<T> {
+    fn drop(&mut self) {
+        if let Some(destroy) = self.destroy.take() {
+            destroy();
+        }
+        self.root.remove();
+    }
+}
+
+#[doc(hidden)]
+pub mod __private {
+    use std::cell::{OnceCell, RefCell};
+    use std::collections::HashSet;
+    use std::hash::Hash;
+
+    #[cfg(feature = "hashbrown")]
+    use hashbrown::HashMap;
+    #[cfg(not(feature = "hashbrown"))]
+    use std::collections::HashMap;
+
+    #[cfg(feature = "agent")]
+    pub use gloo_worker;
+    #[cfg(feature = "js-interop")]
+    pub use js_sys;
+    #[cfg(feature = "js-interop")]
+    pub use wasm_bindgen::closure::Closure;
+    pub use wasm_bindgen::JsCast;
+    #[cfg(any(feature = "clipboard", feature = "geolocation", feature = "listen"))]
+    pub use wasm_bindgen::JsValue;
+    #[cfg(any(feature = "delegate", feature = "listen", feature = "observers"))]
+    pub use web_sys;
+    #[cfg(feature = "router")]
+    pub use yew_router;
+
+    // the getters are called in `view()` and are almost always a hit: only the lookup is inlined,
+    // the creation of the callback is kept out of the way
+    #[inline]
+    pub fn get_or_init<V: Clone>(cell: &OnceCell<V>, make: impl FnOnce() -> V) -> V {
+        get_or_init_ref(cell, make).clone()
+    }
+
+    #[inline]
+    pub fn get_or_init_ref<V>(cell: &OnceCell<V>, make: impl FnOnce() -> V) -> &V {
+        match cell.get() {
+            Some(value) => value,
+            None => init(cell, make),
+        }
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn init<V>(cell: &OnceCell<V>, make: impl FnOnce() -> V) -> &V {
+        // `make` might have called the getter itself, the first callback created wins
+        let value = make();
+        cell.get_or_init(|| value)
+    }
+
+    // `by_name()` can only give the callbacks that receive an event: the method of `ByName<T>` is
+    // picked when `T` is one, the one of `&ByName<T>` otherwise (autoref specialization)
+    pub struct ByName<T>(std::marker::PhantomData<T>);
+
+    impl<T> ByName<T> {
+        #[allow(clippy::new_without_default)]
+        pub fn new() -> Self {
+            Self(std::marker::PhantomData)
+        }
+    }
+
+    pub trait ByNameEvent<T> {
+        fn cast<E: JsCast>(&self) -> Option<fn(E) -> Option<T>>;
+    }
+
+    // an event of another type is dropped
+    impl<T: JsCast> ByNameEvent<T> for ByName<T> {
+        fn cast<E: JsCast>(&self) -> Option<fn(E) -> Option<T>> {
+            Some(|event| event.dyn_into().ok())
+        }
+    }
+
+    pub trait ByNameOther<T> {
+        fn cast<E: JsCast>(&self) -> Option<fn(E) -> Option<T>>;
+    }
+
+    impl<T> ByNameOther<T> for &ByName<T> {
+        fn cast<E: JsCast>(&self) -> Option<fn(E) -> Option<T>> {
+            None
+        }
+    }
+
+    pub struct CurriedMap<K, V> {
+        entries: Entries<K, V>,
+        #[cfg(feature = "diagnostics")]
+        stats: KeyStats,
+        #[cfg(feature = "ttl")]
+        expiry: Expiry<K>,
+    }
+
+    // most curried variants only ever have a few callbacks (tabs, panels, ...): their keys are
+    // compared one by one and they are only moved into a `HashMap` when there are too many
+    enum Entries<K, V> {
+        Small(Vec<(K, V)>),
+        Large(HashMap<K, V>),
+    }
+
+    const SMALL_LEN: usize = 8;
+
+    // where a missing key goes, the hash of the key is kept when it goes into the `HashMap`
+    pub struct Missing(#[allow(dead_code)] Option<u64>);
+
+    impl<K, V> Default for CurriedMap<K, V> {
+        fn default() -> Self {
+            Self {
+                entries: Entries::Small(Vec::new()),
+                #[cfg(feature = "diagnostics")]
+                stats: KeyStats::default(),
+                #[cfg(feature = "ttl")]
+                expiry: Expiry {
+                    ttl: None,
+                    used: HashMap::default(),
+                    swept: 0.0,
+                },
+            }
+        }
+    }
+
+    impl<K: Eq + Hash, V> CurriedMap<K, V> {
+        // the timestamps of `ttl` are dropped along with their callbacks
+        fn retain(&mut self, mut keep: impl FnMut(&K) -> bool) {
+            self.entries.retain(&mut keep);
+            #[cfg(feature = "ttl")]
+            self.expiry.used.retain(|key, _| keep(key));
+        }
+    }
+
+    // with `#[curry(ttl = ...)]`, the callbacks that have not been returned by the getter for `ttl`
+    // milliseconds are dropped when a callback is created, at most once per `ttl`, or by `sweep()`
+    #[cfg(feature = "ttl")]
+    struct Expiry<K> {
+        ttl: Option<f64>,
+        used: HashMap<K, std::cell::Cell<f64>>,
+        swept: f64,
+    }
+
+    #[cfg(feature = "ttl")]
+    impl<K: Eq + Hash, V> CurriedMap<K, V> {
+        pub fn with_ttl(ttl: u64) -> Self {
+            let mut map = Self::default();
+            map.expiry.ttl = Some(ttl as f64);
+            map
+        }
+
+        fn touch(&self, key: &K) {
+            if self.expiry.ttl.is_some() {
+                if let Some(used) = self.expiry.used.get(key) {
+                    used.set(now());
+                }
+            }
+        }
+
+        fn inserted(&mut self, key: &K)
+        where
+            K: Clone,
+        {
+            if self.expiry.ttl.is_some() {
+                self.expiry
+                    .used
+                    .insert(key.clone(), std::cell::Cell::new(now()));
+            }
+        }
+
+        fn sweep(&mut self, force: bool) {
+            let ttl = match self.expiry.ttl {
+                Some(ttl) => ttl,
+                None => return,
+            };
+            let now = now();
+            if !force && now - self.expiry.swept < ttl {
+                return;
+            }
+            self.expiry.swept = now;
+            let used = &self.expiry.used;
+            // `Option::is_none_or()` needs Rust 1.82
+            #[allow(clippy::unnecessary_map_or)]
+            self.entries
+                .retain(|key| used.get(key).map_or(true, |used| now - used.get() < ttl));
+            self.expiry.used.retain(|_, used| now - used.get() < ttl);
+        }
+    }
+
+    // in milliseconds, only compared to the timestamps of the same map
+    #[cfg(feature = "ttl")]
+    fn now() -> f64 {
+        #[cfg(target_arch = "wasm32")]
+        {
+            js_sys::Date::now()
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0.0, |elapsed| elapsed.as_secs_f64() * 1000.0)
+        }
+    }
+
+    impl<K: Eq + Hash, V> Entries<K, V> {
+        fn len(&self) -> usize {
+            match self {
+                Self::Small(entries) => entries.len(),
+                Self::Large(map) => map.len(),
+            }
+        }
+
+        #[cfg(not(feature = "hashbrown"))]
+        fn lookup(&self, key: &K) -> Result<&V, Missing> {
+            match self {
+                Self::Small(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+                Self::Large(map) => map.get(key),
+            }
+            .ok_or(Missing(None))
+        }
+
+        // `BuildHasher::hash_one()` needs Rust 1.71
+        #[cfg(feature = "hashbrown")]
+        #[allow(clippy::manual_hash_one)]
+        fn lookup(&self, key: &K) -> Result<&V, Missing> {
+            use std::hash::{BuildHasher, Hasher};
+
+            match self {
+                Self::Small(entries) => entries
+                    .iter()
+                    .find(|(k, _)| k == key)
+                    .map(|(_, v)| v)
+                    .ok_or(Missing(None)),
+                Self::Large(map) => {
+                    let mut state = map.hasher().build_hasher();
+                    key.hash(&mut state);
+                    let hash = state.finish();
+                    map.raw_entry()
+                        .from_key_hashed_nocheck(hash, key)
+                        .map(|(_, v)| v)
+                        .ok_or(Missing(Some(hash)))
+                }
+            }
+        }
+
+        // the value already there wins, `make` might have inserted it in the meantime
+        fn insert(&mut self, missing: Missing, key: K, value: V) -> &V {
+            if let Self::Small(entries) = self {
+                if entries.len() >= SMALL_LEN && !entries.iter().any(|(k, _)| *k == key) {
+                    let entries = std::mem::take(entries);
+                    *self = Self::Large(entries.into_iter().collect());
+                }
+            }
+
+            match self {
+                Self::Small(entries) => match entries.iter().position(|(k, _)| *k == key) {
+                    Some(i) => &entries[i].1,
+                    None => {
+                        entries.push((key, value));
+                        &entries[entries.len() - 1].1
+                    }
+                },
+                #[cfg(not(feature = "hashbrown"))]
+                Self::Large(map) => {
+                    let _ = missing;
+                    map.entry(key).or_insert(value)
+                }
+                #[cfg(feature = "hashbrown")]
+                Self::Large(map) => {
+                    use hashbrown::hash_map::RawEntryMut;
+
+                    let entry = match missing {
+                        Missing(Some(hash)) => {
+                            map.raw_entry_mut().from_key_hashed_nocheck(hash, &key)
+                        }
+                        Missing(None) => map.raw_entry_mut().from_key(&key),
+                    };
+                    match entry {
+                        RawEntryMut::Occupied(entry) => entry.into_mut(),
+                        RawEntryMut::Vacant(entry) => entry.insert(key, value).1,
+                    }
+                }
+            }
+        }
+
+        fn retain(&mut self, mut keep: impl FnMut(&K) -> bool) {
+            match self {
+                Self::Small(entries) => entries.retain(|(key, _)| keep(key)),
+                Self::Large(map) => map.retain(|key, _| keep(key)),
+            }
+        }
+    }
+
+    // `name` is the path of the variant (`Msg::OnClick`), it is only used in the panic messages; the
+    // getters are `#[track_caller]` so the panics point at the call of the getter
+    #[inline]
+    #[track_caller]
+    pub fn get_or_insert<K: Eq + Hash + Clone, V: Clone>(
+        map: &RefCell<CurriedMap<K, V>>,
+        name: &'static str,
+        key: K,
+        make: impl FnOnce(K) -> V,
+    ) -> V {
+        let missing = match map.try_borrow() {
+            Ok(map) => match map.entries.lookup(&key) {
+                Ok(value) => {
+                    #[cfg(feature = "diagnostics")]
+                    map.stats.hit();
+                    #[cfg(feature = "ttl")]
+                    map.touch(&key);
+                    return value.clone();
+                }
+                Err(missing) => missing,
+            },
+            Err(_) => already_borrowed(name),
+        };
+        insert_missing(map, name, missing, key, make)
+    }
+
+    #[cold]
+    #[inline(never)]
+    #[track_caller]
+    fn insert_missing<K: Eq + Hash + Clone, V: Clone>(
+        map: &RefCell<CurriedMap<K, V>>,
+        name: &'static str,
+        missing: Missing,
+        key: K,
+        make: impl FnOnce(K) -> V,
+    ) -> V {
+        let value = make(key.clone());
+        match map.try_borrow_mut() {
+            Ok(mut map) => {
+                #[cfg(feature = "ttl")]
+                {
+                    map.sweep(false);
+                    map.inserted(&key);
+                }
+                let value = map.entries.insert(missing, key, value).clone();
+                #[cfg(feature = "diagnostics")]
+                {
+                    let len = map.entries.len();
+                    map.stats.miss(name);
+                    map.stats.grown(name, len);
+                }
+                value
+            }
+            Err(_) => already_borrowed(name),
+        }
+    }
+
+    // the cache is borrowed once for all the keys, `make` only creates the callbacks and does not
+    // call the getters
+    #[track_caller]
+    pub fn get_or_insert_all<K: Eq + Hash + Clone, V: Clone>(
+        map: &RefCell<CurriedMap<K, V>>,
+        name: &'static str,
+        keys: impl IntoIterator<Item = K>,
+        mut make: impl FnMut(K) -> V,
+    ) -> std::vec::IntoIter<(K, V)> {
+        let mut map = match map.try_borrow_mut() {
+            Ok(map) => map,
+            Err(_) => already_borrowed(name),
+        };
+        #[cfg(feature = "ttl")]
+        map.sweep(false);
+        keys.into_iter()
+            .map(|key| {
+                let value = match map.entries.lookup(&key) {
+                    Ok(value) => {
+                        #[cfg(feature = "ttl")]
+                        map.touch(&key);
+                        value.clone()
+                    }
+                    Err(missing) => {
+                        let value = make(key.clone());
+                        #[cfg(feature = "ttl")]
+                        map.inserted(&key);
+                        let value = map.entries.insert(missing, key.clone(), value).clone();
+                        #[cfg(feature = "diagnostics")]
+                        {
+                            let len = map.entries.len();
+                            map.stats.grown(name, len);
+                        }
+                        value
+                    }
+                };
+                (key, value)
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    // the value is kept along with the callback it was made from and it is made again when the
+    // getter returns another callback (`#[guard]`, a curried callback dropped and created again),
+    // the map itself is only created by the first call
+    #[track_caller]
+    pub fn get_or_replace<K: Eq + Hash, C: PartialEq + Clone, V: Clone>(
+        map: &OnceCell<RefCell<CurriedMap<K, (C, V)>>>,
+        name: &'static str,
+        key: K,
+        source: C,
+        make: impl FnOnce(C) -> V,
+    ) -> V {
+        let map = get_or_init_ref(map, Default::default);
+        let stale = match map.try_borrow() {
+            Ok(map) => match map.entries.lookup(&key) {
+                Ok((cached, value)) if *cached == source => return value.clone(),
+                Ok(_) => true,
+                Err(_) => false,
+            },
+            Err(_) => already_borrowed(name),
+        };
+        let value = make(source.clone());
+        match map.try_borrow_mut() {
+            Ok(mut map) => {
+                if stale {
+                    map.retain(|other| *other != key);
+                }
+                map.entries
+                    .insert(Missing(None), key, (source, value.clone()));
+            }
+            Err(_) => already_borrowed(name),
+        }
+        value
+    }
+
+    // the same without a key: a single value, replaced when the getter returns another callback
+    #[track_caller]
+    pub fn get_or_replace_one<C: PartialEq + Clone, V: Clone>(
+        cell: &OnceCell<RefCell<(C, V)>>,
+        name: &'static str,
+        source: C,
+        make: impl FnOnce(C) -> V,
+    ) -> V {
+        let cached = match cell.get() {
+            Some(cached) => cached,
+            None => {
+                let made = RefCell::new((source.clone(), make(source)));
+                return get_or_init_ref(cell, || made).borrow().1.clone();
+            }
+        };
+        match cached.try_borrow() {
+            Ok(cached) if cached.0 == source => return cached.1.clone(),
+            Ok(_) => {}
+            Err(_) => already_borrowed(name),
+        }
+        let value = make(source.clone());
+        match cached.try_borrow_mut() {
+            Ok(mut cached) => *cached = (source, value.clone()),
+            Err(_) => already_borrowed(name),
+        }
+        value
+    }
+
+    // the callbacks of the keys that are not in `current` are dropped
+    #[track_caller]
+    pub fn retain_keys<'a, K: Eq + Hash + 'a, V>(
+        map: &RefCell<CurriedMap<K, V>>,
+        name: &'static str,
+        current: impl IntoIterator<Item = &'a K>,
+    ) {
+        let current = current.into_iter().collect::<HashSet<_>>();
+        match map.try_borrow_mut() {
+            Ok(mut map) => map.retain(|key| current.contains(key)),
+            Err(_) => already_borrowed(name),
+        }
+    }
+
+    // `#[curry(ttl = ...)]` drops the callbacks that have not been used for `ttl` right away
+    #[cfg(feature = "ttl")]
+    #[track_caller]
+    pub fn sweep<K: Eq + Hash, V>(map: &RefCell<CurriedMap<K, V>>, name: &'static str) {
+        match map.try_borrow_mut() {
+            Ok(mut map) => map.sweep(true),
+            Err(_) => already_borrowed(name),
+        }
+    }
+
+    // the callbacks of the keys rejected by `keep` are dropped, the others are left as they are
+    #[track_caller]
+    pub fn retain_where<K: Eq + Hash, V>(
+        map: &RefCell<CurriedMap<K, V>>,
+        name: &'static str,
+        keep: impl FnMut(&K) -> bool,
+    ) {
+        match map.try_borrow_mut() {
+            Ok(mut map) => map.retain(keep),
+            Err(_) => already_borrowed(name),
+        }
+    }
+
+    type ReformSites =
+        HashMap<(&'static std::panic::Location<'static>, std::any::TypeId), Box<dyn std::any::Any>>;
+
+    // the callbacks of `cached_reform()` are kept by call site, and by the types of the key and of
+    // the callback in case the call site is in a generic function
+    #[derive(Default)]
+    pub struct Reforms {
+        sites: RefCell<ReformSites>,
+        // the number of callbacks of all the sites, for `Debug`
+        len: std::cell::Cell<usize>,
+    }
+
+    impl Reforms {
+        // `make` runs without borrowing the sites, it can use the cache and its getters
+        #[track_caller]
+        pub fn get_or_insert<K: Eq + Hash + 'static, V: Clone + 'static>(
+            &self,
+            name: &'static str,
+            location: &'static std::panic::Location<'static>,
+            key: K,
+            make: impl FnOnce() -> V,
+        ) -> V {
+            let site = (location, std::any::TypeId::of::<(K, V)>());
+            match self.sites.try_borrow() {
+                Ok(sites) => {
+                    let cached = sites
+                        .get(&site)
+                        .and_then(|values| values.downcast_ref::<HashMap<K, V>>())
+                        .and_then(|values| values.get(&key));
+                    if let Some(value) = cached {
+                        return value.clone();
+                    }
+                }
+                Err(_) => already_borrowed(name),
+            }
+            let value = make();
+            match self.sites.try_borrow_mut() {
+                Ok(mut sites) => {
+                    if let Some(values) = sites
+                        .entry(site)
+                        .or_insert_with(|| Box::new(HashMap::<K, V>::default()))
+                        .downcast_mut::<HashMap<K, V>>()
+                    {
+                        if values.insert(key, value.clone()).is_none() {
+                            self.len.set(self.len.get() + 1);
+                        }
+                    }
+                }
+                Err(_) => already_borrowed(name),
+            }
+            value
+        }
+
+        #[track_caller]
+        pub fn clear(&self, name: &'static str) {
+            match self.sites.try_borrow_mut() {
+                Ok(mut sites) => sites.clear(),
+                Err(_) => already_borrowed(name),
+            }
+            self.len.set(0);
+        }
+
+        // only shown once a callback is kept, the other caches print the same as before
+        pub fn debug(&self, debug: &mut std::fmt::DebugStruct) {
+            match self.sites.try_borrow() {
+                Ok(_) if self.len.get() == 0 => {}
+                Ok(_) => {
+                    debug.field("cached_reform", &Cached::Curried(Some(self.len.get())));
+                }
+                Err(_) => {
+                    debug.field("cached_reform", &Cached::Curried(None));
+                }
+            }
+        }
+    }
+
+    // `#[guard(path)]` asks `path` whether a variant is allowed with the context given to
+    // `set_guard_context()`, nothing is allowed while there is no context of the right type
+    #[derive(Clone, Default)]
+    pub struct GuardContext(std::rc::Rc<RefCell<Option<Box<dyn std::any::Any>>>>);
+
+    impl GuardContext {
+        pub fn set<T: 'static>(&self, context: T) {
+            *self.0.borrow_mut() = Some(Box::new(context));
+        }
+
+        pub fn allows<T: 'static>(&self, guard: impl FnOnce(&T) -> bool) -> bool {
+            self.0
+                .borrow()
+                .as_ref()
+                .and_then(|context| context.downcast_ref::<T>())
+                .is_some_and(guard)
+        }
+    }
+
+    // what the `Debug` implementation of the cache shows for every variant
+    pub enum Cached {
+        No,
+        Yes,
+        Curried(Option<usize>),
+    }
+
+    impl Cached {
+        pub fn once<V>(cell: &OnceCell<V>) -> Self {
+            if cell.get().is_some() {
+                Self::Yes
+            } else {
+                Self::No
+            }
+        }
+
+        pub fn curried<K: Eq + Hash, V>(map: &RefCell<CurriedMap<K, V>>) -> Self {
+            Self::Curried(map.try_borrow().ok().map(|map| map.entries.len()))
+        }
+    }
+
+    impl std::fmt::Debug for Cached {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Self::No => f.write_str("not cached"),
+                Self::Yes => f.write_str("cached"),
+                Self::Curried(Some(len)) => write!(f, "{len} cached"),
+                Self::Curried(None) => f.write_str("borrowed"),
+            }
+        }
+    }
+
+    // the curried keys that are never found again are probably different on every render, for
+    // example a closure or a value that is built again; warm-up and `_keyed()` are not counted
+    #[cfg(feature = "diagnostics")]
+    #[derive(Default)]
+    struct KeyStats {
+        hits: std::cell::Cell<usize>,
+        misses: usize,
+        // the largest threshold of `LEN_WARNINGS` already reported
+        warned_len: usize,
+    }
+
+    #[cfg(feature = "diagnostics")]
+    const UNSTABLE_KEYS: usize = 1000;
+
+    #[cfg(feature = "diagnostics")]
+    thread_local! {
+        pub(crate) static LEN_WARNINGS: RefCell<Vec<usize>> =
+            RefCell::new(vec![1_000, 10_000, 100_000]);
+    }
+
+    #[cfg(feature = "diagnostics")]
+    impl KeyStats {
+        fn hit(&self) {
+            self.hits.set(self.hits.get().saturating_add(1));
+        }
+
+        fn miss(&mut self, name: &str) {
+            self.misses = self.misses.saturating_add(1);
+            if self.misses == UNSTABLE_KEYS && self.hits.get() == 0 {
+                warn(format_args!(
+                    "the getter of `{name}` created {UNSTABLE_KEYS} callbacks and never returned \
+                    one of them again, its curried keys are probably different on every render"
+                ));
+            }
+        }
+
+        // the callbacks of the keys that are gone are only dropped by `sync_<variant>_keys()`, a
+        // map that keeps growing is reported every time it reaches a new threshold
+        fn grown(&mut self, name: &str, len: usize) {
+            if !cfg!(debug_assertions) || len <= self.warned_len {
+                return;
+            }
+
+            let reached = LEN_WARNINGS.with(|thresholds| thresholds.borrow().contains(&len));
+            if reached {
+                self.warned_len = len;
+                warn(format_args!(
+                    "`{name}` has {len} callbacks cached, the callbacks of the keys that are not \
+                    used anymore might never be dropped"
+                ));
+            }
+        }
+    }
+
+    // the callbacks of a cache that has been dropped keep sending their messages: when it
+    // happens, the cache is probably built again in `view()` instead of being kept by the component
+    #[cfg(feature = "diagnostics")]
+    #[derive(Debug, Default)]
+    pub struct Alive {
+        alive: std::rc::Rc<()>,
+        // the component whose scope built the cache, see `built_by()`
+        component: std::cell::Cell<Option<std::any::TypeId>>,
+    }
+
+    #[cfg(feature = "diagnostics")]
+    impl Alive {
+        pub fn watch<M: 'static>(
+            &self,
+            name: &'static str,
+            emit: impl Fn(M) + 'static,
+        ) -> impl Fn(M) {
+            let alive = std::rc::Rc::downgrade(&self.alive);
+            let warned = std::cell::Cell::new(false);
+
+            move |msg| {
+                if alive.strong_count() == 0 && !warned.replace(true) {
+                    warn(format_args!(
+                        "a callback of a `{name}` that has been dropped sent a message, the cache \
+                        is probably built again on every render instead of being kept by the \
+                        component"
+                    ));
+                }
+                emit(msg)
+            }
+        }
+    }
+
+    // there is no way to tell the instances of a component apart: a component type whose caches
+    // keep being built while very few of them are alive at once probably builds one on every render
+    #[cfg(feature = "diagnostics")]
+    #[derive(Default)]
+    struct Instances {
+        built: usize,
+        alive: usize,
+        max_alive: usize,
+    }
+
+    #[cfg(feature = "diagnostics")]
+    const REBUILDS: usize = 100;
+
+    #[cfg(feature = "diagnostics")]
+    thread_local! {
+        static INSTANCES: RefCell<HashMap<std::any::TypeId, Instances>> =
+            RefCell::new(HashMap::new());
+    }
+
+    #[cfg(feature = "diagnostics")]
+    impl Alive {
+        pub fn built_by<C: 'static>(&self, name: &'static str) {
+            if !cfg!(debug_assertions) {
+                return;
+            }
+
+            let component = std::any::TypeId::of::<C>();
+            self.component.set(Some(component));
+            let rebuilt = INSTANCES.with(|instances| {
+                let mut instances = instances.borrow_mut();
+                let instances = instances.entry(component).or_default();
+                instances.built += 1;
+                instances.alive += 1;
+                instances.max_alive = instances.max_alive.max(instances.alive);
+                (instances.built == REBUILDS * instances.max_alive).then_some(instances.max_alive)
+            });
+            if let Some(max_alive) = rebuilt {
+                warn(format_args!(
+                    "`{name}` has been built {} times for `{}` while at most {max_alive} of them \
+                    were alive at once, it is probably built again in `view()` or `update()` \
+                    instead of once in `create()`",
+                    REBUILDS * max_alive,
+                    std::any::type_name::<C>(),
+                ));
+            }
+        }
+    }
+
+    #[cfg(feature = "diagnostics")]
+    impl Drop for Alive {
+        fn drop(&mut self) {
+            if let Some(component) = self.component.get() {
+                // the thread is maybe being torn down
+                let _ = INSTANCES.try_with(|instances| {
+                    if let Some(instances) = instances.borrow_mut().get_mut(&component) {
+                        instances.alive -= 1;
+                    }
+                });
+            }
+        }
+    }
+
+    // the misuses are only reported in debug builds
+    #[cfg(feature = "diagnostics")]
+    pub fn warn(message: std::fmt::Arguments) {
+        #[cfg(all(debug_assertions, target_arch = "wasm32"))]
+        web_sys::console::warn_1(&format!("yew-callbacks: {message}").into());
+
+        #[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
+        eprintln!("yew-callbacks: {message}");
+
+        #[cfg(not(debug_assertions))]
+        let _ = message;
+    }
+
+    #[cold]
+    #[track_caller]
+    fn already_borrowed(name: &str) -> ! {
+        panic!(
+            "the callbacks of `{name}` are already borrowed: the `Hash` or `Eq` implementation of a \
+            curried argument cannot call a getter of the cache"
+        )
+    }
+
+    #[cfg(feature = "router")]
+    type GetNavigator = std::rc::Rc<dyn Fn() -> Option<yew_router::navigator::Navigator>>;
+
+    #[cfg(feature = "router")]
+    #[derive(Clone, Default)]
+    pub struct NavigatorSlot(std::rc::Rc<RefCell<Option<GetNavigator>>>);
+
+    #[cfg(feature = "router")]
+    impl NavigatorSlot {
+        pub fn set(&self, get: impl Fn() -> Option<yew_router::navigator::Navigator> + 'static) {
+            *self.0.borrow_mut() = Some(std::rc::Rc::new(get));
+        }
+
+        pub fn push<R: yew_router::Routable>(&self, route: &R) {
+            let get = self.0.borrow().clone();
+            if let Some(navigator) = get.and_then(|get| get()) {
+                navigator.push(route);
+            }
+        }
+    }
+
+    #[cfg(feature = "router")]
+    impl std::fmt::Debug for NavigatorSlot {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("NavigatorSlot")
+                .field("is_set", &self.0.borrow().is_some())
+                .finish()
+        }
+    }
+
+    #[cfg(feature = "listen")]
+    pub fn attach<E: JsCast>(
+        target: &web_sys::EventTarget,
+        event_type: &'static str,
+        options: gloo_events::EventListenerOptions,
+        emit: impl Fn(E) + 'static,
+    ) -> super::ListenerHandle {
+        let listener = gloo_events::EventListener::new_with_options(
+            target,
+            event_type,
+            options,
+            move |event| emit(event.clone().unchecked_into()),
+        );
+
+        super::ListenerHandle(listener)
+    }
+
+    #[cfg(feature = "listen")]
+    #[derive(Debug, Clone)]
+    pub enum ListenTarget {
+        Window,
+        Document,
+        Element(web_sys::Element),
+        #[cfg(feature = "service-worker")]
+        ServiceWorker,
+    }
+
+    #[cfg(feature = "listen")]
+    #[derive(Debug, Default)]
+    pub struct Listener(RefCell<Option<gloo_events::EventListener>>);
+
+    #[cfg(feature = "listen")]
+    impl Listener {
+        pub fn start<E: JsCast>(
+            &self,
+            target: ListenTarget,
+            event_type: &'static str,
+            emit: impl Fn(E) + 'static,
+        ) {
+            // there is no window to listen to outside of the browser (server-side rendering)
+            #[cfg(not(target_arch = "wasm32"))]
+            let _ = (target, event_type, emit);
+
+            #[cfg(target_arch = "wasm32")]
+            {
+                let target: Option<web_sys::EventTarget> = match target {
+                    ListenTarget::Window => web_sys::window().map(Into::into),
+                    ListenTarget::Document => web_sys::window()
+                        .and_then(|window| window.document())
+                        .map(Into::into),
+                    ListenTarget::Element(element) => Some(element.into()),
+                    #[cfg(feature = "service-worker")]
+                    ListenTarget::ServiceWorker => {
+                        web_sys::window().map(|window| window.navigator().service_worker().into())
+                    }
+                };
+
+                if let Some(target) = target {
+                    let listener =
+                        gloo_events::EventListener::new(&target, event_type, move |event| {
+                            emit(event.clone().unchecked_into())
+                        });
+                    *self.0.borrow_mut() = Some(listener);
+                }
+            }
+        }
+
+        pub fn stop(&self) {
+            let listener = self.0.borrow_mut().take();
+            drop(listener);
+        }
+
+        pub fn is_on(&self, element: &web_sys::Element) -> bool {
+            self.0
+                .borrow()
+                .as_ref()
+                .is_some_and(|listener| listener.target() == element.as_ref())
+        }
+    }
+
+    #[cfg(feature = "timers")]
+    #[derive(Debug, Default)]
+    pub struct IntervalSlot(RefCell<Option<gloo_timers::callback::Interval>>);
+
+    #[cfg(feature = "timers")]
+    impl IntervalSlot {
+        pub fn start(&self, millis: u32, tick: impl Fn(u32) + 'static) {
+            // there are no timers outside of the browser (server-side rendering)
+            #[cfg(not(target_arch = "wasm32"))]
+            let _ = (millis, tick);
+
+            #[cfg(target_arch = "wasm32")]
+            {
+                let count = std::cell::Cell::new(0_u32);
+                let interval = gloo_timers::callback::Interval::new(millis, move || {
+                    count.set(count.get().wrapping_add(1));
+                    tick(count.get());
+                });
+                let previous = self.0.borrow_mut().replace(interval);
+                drop(previous);
+            }
+        }
+
+        pub fn stop(&self) {
+            let interval = self.0.borrow_mut().take();
+            drop(interval);
+        }
+
+        pub fn is_running(&self) -> bool {
+            self.0.borrow().is_some()
+        }
+    }
+
+    #[cfg(feature = "timers")]
+    #[derive(Debug, Default)]
+    pub struct TimeoutSlot(RefCell<Option<gloo_timers::callback::Timeout>>);
+
+    #[cfg(feature = "timers")]
+    impl TimeoutSlot {
+        pub fn schedule(&self, millis: u32, fire: impl FnOnce() + 'static) {
+            // there are no timers outside of the browser (server-side rendering)
+            #[cfg(not(target_arch = "wasm32"))]
+            let _ = (millis, fire);
+
+            #[cfg(target_arch = "wasm32")]
+            {
+                let timeout = gloo_timers::callback::Timeout::new(millis, fire);
+                let previous = self.0.borrow_mut().replace(timeout);
+                drop(previous);
+            }
+        }
+
+        pub fn cancel(&self) {
+            let timeout = self.0.borrow_mut().take();
+            drop(timeout);
+        }
+    }
+
+    // `#[deferred]` sends the message on the next tick, after the event has been handled by the
+    // browser
+    #[cfg(feature = "timers")]
+    pub fn defer<M: 'static>(emit: impl Fn(M) + 'static) -> impl Fn(M) {
+        let emit = std::rc::Rc::new(emit);
+
+        move |msg| {
+            // there are no timers outside of the browser (server-side rendering, tests)
+            #[cfg(not(target_arch = "wasm32"))]
+            emit(msg);
+
+            #[cfg(target_arch = "wasm32")]
+            {
+                let emit = emit.clone();
+                gloo_timers::callback::Timeout::new(0, move || emit(msg)).forget();
+            }
+        }
+    }
+
+    // `#[delegate]` reads the key from the closest element that has the attribute, nothing is sent
+    // when the event did not happen in an item or when the key cannot be parsed
+    #[cfg(feature = "delegate")]
+    pub fn delegated_key<K: std::str::FromStr>(
+        event: &web_sys::Event,
+        attribute: &str,
+    ) -> Option<K> {
+        event
+            .target()?
+            .dyn_into::<web_sys::Element>()
+            .ok()?
+            .closest(&format!("[{attribute}]"))
+            .ok()??
+            .get_attribute(attribute)?
+            .parse()
+            .ok()
+    }
+
+    #[cfg(feature = "render")]
+    type SharedAnimationFrame = std::rc::Rc<RefCell<Option<gloo_render::AnimationFrame>>>;
+
+    #[cfg(feature = "render")]
+    #[derive(Debug, Default)]
+    pub struct AnimationFrameSlot(SharedAnimationFrame);
+
+    #[cfg(feature = "render")]
+    impl AnimationFrameSlot {
+        pub fn start(&self, frame: impl Fn(f64) + 'static) {
+            // there are no animation frames outside of the browser (server-side rendering)
+            #[cfg(not(target_arch = "wasm32"))]
+            let _ = frame;
+
+            #[cfg(target_arch = "wasm32")]
+            {
+                // the closure only holds a weak reference to the slot, otherwise the animation
+                // frame would keep itself alive
+                fn request(
+                    slot: std::rc::Weak<RefCell<Option<gloo_render::AnimationFrame>>>,
+                    frame: std::rc::Rc<dyn Fn(f64)>,
+                ) {
+                    let weak = slot.clone();
+                    let animation_frame = gloo_render::request_animation_frame(move |timestamp| {
+                        frame(timestamp);
+
+                        // the loop might have been stopped while handling the frame
+                        let running = weak
+                            .upgrade()
+                            .map(|slot| slot.borrow().is_some())
+                            .unwrap_or(false);
+                        if running {
+                            request(weak, frame);
+                        }
+                    });
+
+                    if let Some(slot) = slot.upgrade() {
+                        let previous = slot.borrow_mut().replace(animation_frame);
+                        drop(previous);
+                    }
+                }
+
+                request(std::rc::Rc::downgrade(&self.0), std::rc::Rc::new(frame));
+            }
+        }
+
+        pub fn stop(&self) {
+            let animation_frame = self.0.borrow_mut().take();
+            drop(animation_frame);
+        }
+
+        pub fn is_running(&self) -> bool {
+            self.0.borrow().is_some()
+        }
+    }
+
+    #[cfg(feature = "idle")]
+    type IdleCallback = (
+        u32,
+        wasm_bindgen::closure::Closure<dyn FnMut(web_sys::IdleDeadline)>,
+    );
+
+    #[cfg(feature = "idle")]
+    #[derive(Default)]
+    pub struct IdleSlot(std::rc::Rc<RefCell<Option<IdleCallback>>>);
+
+    #[cfg(feature = "idle")]
+    impl IdleSlot {
+        pub fn start(&self, timeout: Option<u32>, emit: impl Fn(f64) + 'static) {
+            self.stop();
+
+            // there are no idle periods outside of the browser (server-side rendering)
+            #[cfg(not(target_arch = "wasm32"))]
+            let _ = (timeout, emit);
+
+            #[cfg(target_arch = "wasm32")]
+            {
+                fn request(callback: &js_sys::Function, timeout: Option<u32>) -> Option<u32> {
+                    let options = web_sys::IdleRequestOptions::new();
+                    if let Some(timeout) = timeout {
+                        options.set_timeout(timeout);
+                    }
+                    web_sys::window()?
+                        .request_idle_callback_with_options(callback, &options)
+                        .ok()
+                }
+
+                // the closure only holds a weak reference to the slot, otherwise it would keep
+                // itself alive; the same closure is requested again for every idle period
+                let weak = std::rc::Rc::downgrade(&self.0);
+                let closure = wasm_bindgen::closure::Closure::<dyn FnMut(_)>::new(
+                    move |deadline: web_sys::IdleDeadline| {
+                        emit(deadline.time_remaining());
+
+                        // the loop might have been stopped while handling the idle period
+                        if let Some(slot) = weak.upgrade() {
+                            if let Some((id, closure)) = slot.borrow_mut().as_mut() {
+                                if let Some(next) =
+                                    request(closure.as_ref().unchecked_ref(), timeout)
+                                {
+                                    *id = next;
+                                }
+                            }
+                        }
+                    },
+                );
+
+                if let Some(id) = request(closure.as_ref().unchecked_ref(), timeout) {
+                    *self.0.borrow_mut() = Some((id, closure));
+                }
+            }
+        }
+
+        pub fn stop(&self) {
+            let idle_callback = self.0.borrow_mut().take();
+            if let Some((id, closure)) = idle_callback {
+                if let Some(window) = web_sys::window() {
+                    window.cancel_idle_callback(id);
+                }
+                drop(closure);
+            }
+        }
+
+        pub fn is_running(&self) -> bool {
+            self.0.borrow().is_some()
+        }
+    }
+
+    #[cfg(feature = "idle")]
+    impl Drop for IdleSlot {
+        fn drop(&mut self) {
+            self.stop();
+        }
+    }
+
+    #[cfg(feature = "idle")]
+    impl std::fmt::Debug for IdleSlot {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("IdleSlot")
+                .field("request_id", &self.0.borrow().as_ref().map(|(id, _)| *id))
+                .finish()
+        }
+    }
+
+    #[cfg(feature = "observers")]
+    type IntersectionEntries<K> =
+        std::rc::Rc<RefCell<Vec<(K, web_sys::Element, std::rc::Rc<dyn Fn(bool)>)>>>;
+
+    #[cfg(feature = "observers")]
+    type IntersectionObserver = (
+        web_sys::IntersectionObserver,
+        wasm_bindgen::closure::Closure<dyn FnMut(js_sys::Array)>,
+    );
+
+    #[cfg(feature = "observers")]
+    pub struct IntersectionObserverSlot<K> {
+        entries: IntersectionEntries<K>,
+        observer: OnceCell<IntersectionObserver>,
+    }
+
+    #[cfg(feature = "observers")]
+    impl<K> Default for IntersectionObserverSlot<K> {
+        fn default() -> Self {
+            Self {
+                entries: Default::default(),
+                observer: Default::default(),
+            }
+        }
+    }
+
+    #[cfg(feature = "observers")]
+    impl<K: PartialEq + 'static> IntersectionObserverSlot<K> {
+        pub fn observe(
+            &self,
+            threshold: f64,
+            key: K,
+            element: web_sys::Element,
+            emit: impl Fn(bool) + 'static,
+        ) {
+            let (observer, _) = self.observer.get_or_init(|| {
+                // the closure only holds a weak reference to the entries, otherwise the observer
+                // would keep itself alive
+                let entries = std::rc::Rc::downgrade(&self.entries);
+                let closure = wasm_bindgen::closure::Closure::<dyn FnMut(js_sys::Array)>::new(
+                    move |changes: js_sys::Array| {
+                        let entries = match entries.upgrade() {
+                            Some(entries) => entries,
+                            None => return,
+                        };
+
+                        for change in changes.iter() {
+                            let change =
+                                change.unchecked_into::<web_sys::IntersectionObserverEntry>();
+                            let target = change.target();
+                            let emit = entries
+                                .borrow()
+                                .iter()
+                                .find(|(_, element, _)| *element == target)
+                                .map(|(_, _, emit)| emit.clone());
+
+                            if let Some(emit) = emit {
+                                emit(change.is_intersecting());
+                            }
+                        }
+                    },
+                );
+                let init = web_sys::IntersectionObserverInit::new();
+                init.set_threshold(&wasm_bindgen::JsValue::from_f64(threshold));
+                let observer = web_sys::IntersectionObserver::new_with_options(
+                    closure.as_ref().unchecked_ref(),
+                    &init,
+                )
+                .expect("invalid intersection threshold");
+
+                (observer, closure)
+            });
+
+            self.unobserve(&key);
+            observer.observe(&element);
+            self.entries
+                .borrow_mut()
+                .push((key, element, std::rc::Rc::new(emit)));
+        }
+
+        pub fn unobserve(&self, key: &K) {
+            let entry = {
+                let mut entries = self.entries.borrow_mut();
+                entries
+                    .iter()
+                    .position(|(x, _, _)| x == key)
+                    .map(|i| entries.remove(i))
+            };
+
+            if let (Some((_, element, _)), Some((observer, _))) = (entry, self.observer.get()) {
+                observer.unobserve(&element);
+            }
+        }
+    }
+
+    #[cfg(feature = "observers")]
+    impl<K> Drop for IntersectionObserverSlot<K> {
+        fn drop(&mut self) {
+            if let Some((observer, _)) = self.observer.get() {
+                observer.disconnect();
+            }
+        }
+    }
+
+    #[cfg(feature = "observers")]
+    impl<K> std::fmt::Debug for IntersectionObserverSlot<K> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("IntersectionObserverSlot")
+                .field("observed", &self.entries.borrow().len())
+                .finish()
+        }
+    }
+
+    #[cfg(feature = "listen")]
+    #[derive(Debug, Default)]
+    pub struct MediaQuerySlot(
+        RefCell<Option<(web_sys::MediaQueryList, gloo_events::EventListener)>>,
+    );
+
+    #[cfg(feature = "listen")]
+    impl MediaQuerySlot {
+        pub fn start(&self, query: &str, emit: impl Fn(bool) + 'static) {
+            // there is no window to query outside of the browser (server-side rendering)
+            #[cfg(not(target_arch = "wasm32"))]
+            let _ = (query, emit);
+
+            #[cfg(target_arch = "wasm32")]
+            {
+                let list = web_sys::window()
+                    .and_then(|window| window.match_media(query).ok())
+                    .flatten();
+
+                if let Some(list) = list {
+                    let listener = gloo_events::EventListener::new(&list, "change", move |event| {
+                        emit(
+                            event
+                                .unchecked_ref::<web_sys::MediaQueryListEvent>()
+                                .matches(),
+                        )
+                    });
+                    *self.0.borrow_mut() = Some((list, listener));
+                }
+            }
+        }
+
+        pub fn matches(&self) -> bool {
+            self.0
+                .borrow()
+                .as_ref()
+                .map(|(list, _)| list.matches())
+                .unwrap_or(false)
+        }
+    }
+
+    #[cfg(feature = "listen")]
+    #[derive(Debug, Default)]
+    pub struct ConnectivitySlot {
+        online: Listener,
+        offline: Listener,
+    }
+
+    #[cfg(feature = "listen")]
+    impl ConnectivitySlot {
+        pub fn start(&self, emit: impl Fn(bool) + 'static) {
+            let emit = std::rc::Rc::new(emit);
+            let online = emit.clone();
+            self.online
+                .start(ListenTarget::Window, "online", move |_: JsValue| {
+                    online(true)
+                });
+            self.offline
+                .start(ListenTarget::Window, "offline", move |_: JsValue| {
+                    emit(false)
+                });
+        }
+
+        pub fn stop(&self) {
+            self.online.stop();
+            self.offline.stop();
+        }
+    }
+
+    #[cfg(feature = "listen")]
+    #[derive(Debug, Default)]
+    pub struct VisibilitySlot(Listener);
+
+    #[cfg(feature = "listen")]
+    impl VisibilitySlot {
+        pub fn start(&self, emit: impl Fn(bool) + 'static) {
+            self.0.start(
+                ListenTarget::Document,
+                "visibilitychange",
+                move |_: JsValue| {
+                    let visible = web_sys::window()
+                        .and_then(|window| window.document())
+                        .map(|document| !document.hidden())
+                        .unwrap_or(true);
+                    emit(visible)
+                },
+            );
+        }
+
+        pub fn stop(&self) {
+            self.0.stop();
+        }
+    }
+
+    #[cfg(feature = "listen")]
+    #[derive(Debug, Default)]
+    pub struct PopStateSlot(Listener);
+
+    #[cfg(feature = "listen")]
+    impl PopStateSlot {
+        pub fn start(&self, emit: impl Fn(String) + 'static) {
+            self.0
+                .start(ListenTarget::Window, "popstate", move |_: JsValue| {
+                    let location = web_sys::window().map(|window| window.location());
+                    let location = location
+                        .map(|location| {
+                            let pathname = location.pathname().unwrap_or_default();
+                            let search = location.search().unwrap_or_default();
+                            let hash = location.hash().unwrap_or_default();
+                            format!("{pathname}{search}{hash}")
+                        })
+                        .unwrap_or_default();
+                    emit(location)
+                });
+        }
+
+        pub fn stop(&self) {
+            self.0.stop();
+        }
+    }
+
+    #[cfg(feature = "gamepad")]
+    #[derive(Debug, Default)]
+    struct GamepadState {
+        buttons: Vec<(bool, f64)>,
+        axes: Vec<f64>,
+    }
+
+    #[cfg(feature = "gamepad")]
+    impl GamepadState {
+        fn new(gamepad: &web_sys::Gamepad) -> Self {
+            Self {
+                buttons: gamepad
+                    .buttons()
+                    .iter()
+                    .map(|button| {
+                        let button = button.unchecked_into::<web_sys::GamepadButton>();
+                        (button.pressed(), button.value())
+                    })
+                    .collect(),
+                axes: gamepad
+                    .axes()
+                    .iter()
+                    .map(|axis| axis.as_f64().unwrap_or_default())
+                    .collect(),
+            }
+        }
+
+        fn changes(&self, index: u32, previous: &Self) -> Vec<super::GamepadInput> {
+            let buttons = self.buttons.iter().enumerate().filter_map(|(i, button)| {
+                let (pressed, value) = *button;
+                (previous.buttons.get(i) != Some(button)).then_some(super::GamepadInput::Button {
+                    index,
+                    button: i as u32,
+                    pressed,
+                    value,
+                })
+            });
+            let axes = self.axes.iter().enumerate().filter_map(|(i, value)| {
+                (previous.axes.get(i) != Some(value)).then_some(super::GamepadInput::Axis {
+                    index,
+                    axis: i as u32,
+                    value: *value,
+                })
+            });
+
+            buttons.chain(axes).collect()
+        }
+    }
+
+    #[cfg(feature = "gamepad")]
+    type GamepadStates = std::rc::Rc<RefCell<HashMap<u32, GamepadState>>>;
+
+    #[cfg(feature = "gamepad")]
+    #[derive(Debug, Default)]
+    pub struct GamepadSlot {
+        connected: Listener,
+        disconnected: Listener,
+        polling: std::rc::Rc<AnimationFrameSlot>,
+    }
+
+    #[cfg(feature = "gamepad")]
+    impl GamepadSlot {
+        pub fn start(&self, emit: impl Fn(super::GamepadInput) + 'static) {
+            let emit = std::rc::Rc::new(emit);
+            let states = GamepadStates::default();
+            // the gamepads are only polled while at least one of them is connected
+            let poll: std::rc::Rc<dyn Fn(f64)> = {
+                let emit = emit.clone();
+                let states = states.clone();
+                std::rc::Rc::new(move |_| poll_gamepads(&states, &*emit))
+            };
+
+            {
+                let emit = emit.clone();
+                let states = states.clone();
+                let polling = self.polling.clone();
+                self.connected.start(
+                    ListenTarget::Window,
+                    "gamepadconnected",
+                    move |event: web_sys::GamepadEvent| {
+                        if let Some(gamepad) = event.gamepad() {
+                            let index = gamepad.index();
+                            states
+                                .borrow_mut()
+                                .insert(index, GamepadState::new(&gamepad));
+                            if !polling.is_running() {
+                                let poll = poll.clone();
+                                polling.start(move |timestamp| poll(timestamp));
+                            }
+                            emit(super::GamepadInput::Connected {
+                                index,
+                                id: gamepad.id(),
+                            });
+                        }
+                    },
+                );
+            }
+
+            let polling = self.polling.clone();
+            self.disconnected.start(
+                ListenTarget::Window,
+                "gamepaddisconnected",
+                move |event: web_sys::GamepadEvent| {
+                    if let Some(gamepad) = event.gamepad() {
+                        let index = gamepad.index();
+                        let is_empty = {
+                            let mut states = states.borrow_mut();
+                            states.remove(&index);
+                            states.is_empty()
+                        };
+                        if is_empty {
+                            polling.stop();
+                        }
+                        emit(super::GamepadInput::Disconnected { index });
+                    }
+                },
+            );
+        }
+
+        pub fn stop(&self) {
+            self.connected.stop();
+            self.disconnected.stop();
+            self.polling.stop();
+        }
+    }
+
+    // the state of the gamepads is a snapshot, only the differences with the previous one are sent
+    #[cfg(feature = "gamepad")]
+    fn poll_gamepads(states: &GamepadStates, emit: &dyn Fn(super::GamepadInput)) {
+        let gamepads =
+            match web_sys::window().and_then(|window| window.navigator().get_gamepads().ok()) {
+                Some(gamepads) => gamepads,
+                None => return,
+            };
+        let mut changes = Vec::new();
+
+        {
+            let mut states = states.borrow_mut();
+            for gamepad in gamepads.iter() {
+                // the disconnected gamepads are `null`
+                let gamepad = match gamepad.dyn_into::<web_sys::Gamepad>() {
+                    Ok(gamepad) => gamepad,
+                    Err(_) => continue,
+                };
+                if let Some(state) = states.get_mut(&gamepad.index()) {
+                    let current = GamepadState::new(&gamepad);
+                    changes.extend(current.changes(gamepad.index(), state));
+                    *state = current;
+                }
+            }
+        }
+
+        for change in changes {
+            emit(change);
+        }
+    }
+
+    #[cfg(feature = "scroll")]
+    #[derive(Debug, Default)]
+    pub struct ScrollSlot {
+        listener: Listener,
+        trailing: std::rc::Rc<TimeoutSlot>,
+    }
+
+    #[cfg(feature = "scroll")]
+    impl ScrollSlot {
+        pub fn start(
+            &self,
+            target: ListenTarget,
+            millis: u32,
+            emit: impl Fn(super::ScrollPosition) + 'static,
+        ) {
+            let emit = {
+                let target = target.clone();
+                std::rc::Rc::new(move || emit(scroll_position(&target)))
+            };
+            let last = std::rc::Rc::new(std::cell::Cell::new(f64::NEG_INFINITY));
+            let pending = std::rc::Rc::new(std::cell::Cell::new(false));
+            let trailing = self.trailing.clone();
+
+            // the first event is sent right away, then the position is sent at most once per
+            // period and the last position is always sent at the end
+            self.listener.start(target, "scroll", move |_: JsValue| {
+                let elapsed = js_sys::Date::now() - last.get();
+
+                if elapsed >= f64::from(millis) {
+                    trailing.cancel();
+                    pending.set(false);
+                    last.set(js_sys::Date::now());
+                    emit();
+                } else if !pending.get() {
+                    pending.set(true);
+                    let emit = emit.clone();
+                    let last = last.clone();
+                    let pending = pending.clone();
+                    trailing.schedule((f64::from(millis) - elapsed) as u32, move || {
+                        pending.set(false);
+                        last.set(js_sys::Date::now());
+                        emit();
+                    });
+                }
+            });
+        }
+
+        pub fn stop(&self) {
+            self.listener.stop();
+            self.trailing.cancel();
+        }
+    }
+
+    #[cfg(feature = "scroll")]
+    fn scroll_position(target: &ListenTarget) -> super::ScrollPosition {
+        match target {
+            ListenTarget::Element(element) => super::ScrollPosition {
+                x: f64::from(element.scroll_left()),
+                y: f64::from(element.scroll_top()),
+            },
+            _ => web_sys::window()
+                .map(|window| super::ScrollPosition {
+                    x: window.scroll_x().unwrap_or_default(),
+                    y: window.scroll_y().unwrap_or_default(),
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    #[cfg(feature = "listen")]
+    pub fn is_fullscreen() -> bool {
+        web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.fullscreen_element())
+            .is_some()
+    }
+
+    #[cfg(feature = "listen")]
+    pub fn request_fullscreen(element: Option<web_sys::Element>) -> bool {
+        element
+            .map(|element| element.request_fullscreen().is_ok())
+            .unwrap_or(false)
+    }
+
+    #[cfg(feature = "listen")]
+    pub fn exit_fullscreen() {
+        // there is no document outside of the browser (server-side rendering)
+        #[cfg(target_arch = "wasm32")]
+        {
+            if let Some(document) = web_sys::window().and_then(|window| window.document()) {
+                document.exit_fullscreen();
+            }
+        }
+    }
+
+    #[cfg(feature = "listen")]
+    #[derive(Debug, Default)]
+    pub struct BeforeUnloadSlot {
+        listener: RefCell<Option<gloo_events::EventListener>>,
+        dirty: std::rc::Rc<std::cell::Cell<bool>>,
+    }
+
+    #[cfg(feature = "listen")]
+    impl BeforeUnloadSlot {
+        pub fn start(&self, when_dirty: bool, emit: impl Fn() + 'static) {
+            // there is no window to listen to outside of the browser (server-side rendering)
+            #[cfg(not(target_arch = "wasm32"))]
+            let _ = (when_dirty, emit);
+
+            #[cfg(target_arch = "wasm32")]
+            {
+                if let Some(window) = web_sys::window() {
+                    let dirty = self.dirty.clone();
+                    // listeners are passive by default and could not prevent the unload
+                    let listener = gloo_events::EventListener::new_with_options(
+                        &window,
+                        "beforeunload",
+                        gloo_events::EventListenerOptions::enable_prevent_default(),
+                        move |event| {
+                            if when_dirty && !dirty.get() {
+                                return;
+                            }
+                            event.prevent_default();
+                            // some browsers only ask for a confirmation when a return value is set
+                            event
+                                .unchecked_ref::<web_sys::BeforeUnloadEvent>()
+                                .set_return_value("");
+                            emit();
+                        },
+                    );
+                    *self.listener.borrow_mut() = Some(listener);
+                }
+            }
+        }
+
+        pub fn stop(&self) {
+            let listener = self.listener.borrow_mut().take();
+            drop(listener);
+        }
+
+        pub fn set_dirty(&self, dirty: bool) {
+            self.dirty.set(dirty);
+        }
+
+        pub fn is_dirty(&self) -> bool {
+            self.dirty.get()
+        }
+    }
+
+    #[cfg(feature = "storage")]
+    #[derive(Debug, Default)]
+    pub struct StorageSlot(Listener);
+
+    #[cfg(feature = "storage")]
+    impl StorageSlot {
+        pub fn start<T: serde::de::DeserializeOwned>(
+            &self,
+            key: &'static str,
+            emit: impl Fn(T) + 'static,
+        ) {
+            self.0.start(
+                ListenTarget::Window,
+                "storage",
+                move |event: web_sys::StorageEvent| {
+                    if event.key().as_deref() != Some(key) {
+                        return;
+                    }
+
+                    // a removed key is seen as `null` so it can be received in an `Option`
+                    let value = event.new_value();
+                    if let Ok(value) = serde_json::from_str(value.as_deref().unwrap_or("null")) {
+                        emit(value);
+                    }
+                },
+            );
+        }
+
+        pub fn stop(&self) {
+            self.0.stop();
+        }
+    }
+
+    #[cfg(feature = "broadcast")]
+    #[derive(Debug, Default)]
+    pub struct BroadcastSlot(
+        RefCell<Option<(web_sys::BroadcastChannel, gloo_events::EventListener)>>,
+    );
+
+    #[cfg(feature = "broadcast")]
+    impl BroadcastSlot {
+        pub fn start<T: serde::de::DeserializeOwned>(
+            &self,
+            name: &str,
+            emit: impl Fn(T) + 'static,
+        ) {
+            // there are no channels outside of the browser (server-side rendering)
+            #[cfg(not(target_arch = "wasm32"))]
+            let _ = (name, emit);
+
+            #[cfg(target_arch = "wasm32")]
+            if let Ok(channel) = web_sys::BroadcastChannel::new(name) {
+                let listener = gloo_events::EventListener::new(&channel, "message", move |event| {
+                    let data = event.unchecked_ref::<web_sys::MessageEvent>().data();
+                    if let Some(Ok(value)) = data.as_string().map(|x| serde_json::from_str(&x)) {
+                        emit(value);
+                    }
+                });
+                self.stop();
+                *self.0.borrow_mut() = Some((channel, listener));
+            }
+        }
+
+        pub fn post<T: serde::Serialize>(&self, value: &T) {
+            if let Some((channel, _)) = self.0.borrow().as_ref() {
+                if let Ok(data) = serde_json::to_string(value) {
+                    let _ = channel.post_message(&wasm_bindgen::JsValue::from_str(&data));
+                }
+            }
+        }
+
+        pub fn stop(&self) {
+            let channel = self.0.borrow_mut().take();
+            if let Some((channel, listener)) = channel {
+                drop(listener);
+                channel.close();
+            }
+        }
+    }
+
+    #[cfg(feature = "broadcast")]
+    impl Drop for BroadcastSlot {
+        fn drop(&mut self) {
+            self.stop();
+        }
+    }
+
+    #[cfg(feature = "net")]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum Frame {
+        Text(String),
+        Bytes(Vec<u8>),
+    }
+
+    #[cfg(feature = "net")]
+    pub trait FromFrame: Sized {
+        fn from_frame(frame: Frame) -> Option<Self>;
+    }
 
-use heck::ToSnakeCase;
-use proc_macro2::{Ident, Span, TokenStream};
-use proc_macro_error::abort_call_site;
-use quote::quote;
+    #[cfg(feature = "net")]
+    impl FromFrame for String {
+        fn from_frame(frame: Frame) -> Option<Self> {
+            match frame {
+                Frame::Text(text) => Some(text),
+                Frame::Bytes(_) => None,
+            }
+        }
+    }
 
-#[proc_macro_derive(Callbacks, attributes(curry))]
-pub fn main(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    #[cfg(feature = "net")]
+    impl FromFrame for Vec<u8> {
+        fn from_frame(frame: Frame) -> Option<Self> {
+            match frame {
+                Frame::Text(_) => None,
+                Frame::Bytes(bytes) => Some(bytes),
+            }
+        }
+    }
 
-    derive_callbacks(&input).into()
-}
+    #[cfg(feature = "net")]
+    pub trait IntoFrame {
+        fn into_frame(self) -> Frame;
+    }
 
-fn derive_callbacks(input: &syn::DeriveInput) -> TokenStream {
-    let enum_name = &input.ident;
-    let vis = &input.vis;
-    let e = match &input.data {
-        syn::Data::Enum(e) => e,
-        _ => abort_call_site!("`#[derive(Callbacks)]` only supports enums"),
-    };
-
-    let name = Ident::new(&format!("{enum_name}Callbacks"), Span::call_site());
-
-    let field_names = e
-        .variants
-        .iter()
-        .map(|variant| {
-            Ident::new(
-                &format!("callback_{}", variant.ident.to_string().to_snake_case()),
-                Span::call_site(),
-            )
-        })
-        .collect::<Vec<_>>();
+    #[cfg(feature = "net")]
+    impl IntoFrame for String {
+        fn into_frame(self) -> Frame {
+            Frame::Text(self)
+        }
+    }
 
-    let inits = field_names
-        .iter()
-        .map(|field_name| {
-            quote! {
-                #field_name: Default::default(),
-            }
-        })
-        .collect::<Vec<_>>();
-
-    let curried_tys = e
-        .variants
-        .iter()
-        .map(|variant| match &variant.fields {
-            syn::Fields::Unit => None,
-            syn::Fields::Unnamed(syn::FieldsUnnamed {
-                unnamed: fields, ..
-            })
-            | syn::Fields::Named(syn::FieldsNamed { named: fields, .. }) => {
-                let tys = fields
-                    .iter()
-                    .filter(|field| is_curried(field))
-                    .map(|field| &field.ty)
-                    .collect::<Vec<_>>();
-
-                if tys.is_empty() {
-                    None
-                } else {
-                    Some(quote! {
-                        (#(#tys),*)
-                    })
-                }
-            }
-        })
-        .collect::<Vec<_>>();
-
-    let tys = e
-        .variants
-        .iter()
-        .map(|variant| match &variant.fields {
-            syn::Fields::Unit => {
-                quote! {
-                    ()
+    #[cfg(feature = "net")]
+    impl IntoFrame for Vec<u8> {
+        fn into_frame(self) -> Frame {
+            Frame::Bytes(self)
+        }
+    }
+
+    #[cfg(feature = "custom-event")]
+    pub fn decode_detail<T: serde::de::DeserializeOwned>(event: &JsValue) -> Option<T> {
+        let detail = event.unchecked_ref::<web_sys::CustomEvent>().detail();
+        serde_wasm_bindgen::from_value(detail).ok()
+    }
+
+    #[cfg(feature = "service-worker")]
+    pub fn decode_message<T: serde::de::DeserializeOwned>(event: &JsValue) -> Option<T> {
+        let data = event.unchecked_ref::<web_sys::MessageEvent>().data();
+        serde_wasm_bindgen::from_value(data).ok()
+    }
+
+    #[cfg(feature = "net")]
+    pub fn decode_json<T: serde::de::DeserializeOwned>(frame: Frame) -> Option<T> {
+        match frame {
+            Frame::Text(text) => serde_json::from_str(&text).ok(),
+            Frame::Bytes(bytes) => serde_json::from_slice(&bytes).ok(),
+        }
+    }
+
+    #[cfg(feature = "net")]
+    pub fn encode_json<T: serde::Serialize>(value: &T) -> Option<Frame> {
+        serde_json::to_string(value).ok().map(Frame::Text)
+    }
+
+    #[cfg(feature = "net")]
+    pub struct SocketHandlers {
+        pub message: Box<dyn Fn(Frame)>,
+        pub open: Option<Box<dyn Fn()>>,
+        pub error: Option<Box<dyn Fn()>>,
+        pub closed: Option<Box<dyn Fn()>>,
+    }
+
+    #[cfg(feature = "net")]
+    #[derive(Debug, Default)]
+    pub struct WebSocketSlot(
+        RefCell<Option<(web_sys::WebSocket, Vec<gloo_events::EventListener>)>>,
+    );
+
+    #[cfg(feature = "net")]
+    impl WebSocketSlot {
+        pub fn connect(&self, url: &str, handlers: SocketHandlers) {
+            self.close();
+
+            // there are no sockets outside of the browser (server-side rendering)
+            #[cfg(not(target_arch = "wasm32"))]
+            let _ = (url, handlers);
+
+            #[cfg(target_arch = "wasm32")]
+            {
+                let SocketHandlers {
+                    message,
+                    open,
+                    error,
+                    closed,
+                } = handlers;
+                let socket = match web_sys::WebSocket::new(url) {
+                    Ok(socket) => socket,
+                    Err(_) => {
+                        if let Some(error) = error {
+                            error();
+                        }
+                        return;
+                    }
+                };
+                socket.set_binary_type(web_sys::BinaryType::Arraybuffer);
+
+                let mut listeners = vec![gloo_events::EventListener::new(
+                    &socket,
+                    "message",
+                    move |event| {
+                        let data = event.unchecked_ref::<web_sys::MessageEvent>().data();
+                        let frame = match data.as_string() {
+                            Some(text) => Frame::Text(text),
+                            None => Frame::Bytes(js_sys::Uint8Array::new(&data).to_vec()),
+                        };
+                        message(frame);
+                    },
+                )];
+                for (event_type, handler) in [("open", open), ("error", error), ("close", closed)] {
+                    if let Some(handler) = handler {
+                        listeners.push(gloo_events::EventListener::new(
+                            &socket,
+                            event_type,
+                            move |_| handler(),
+                        ));
+                    }
                 }
+
+                *self.0.borrow_mut() = Some((socket, listeners));
             }
-            syn::Fields::Unnamed(syn::FieldsUnnamed {
-                unnamed: fields, ..
-            })
-            | syn::Fields::Named(syn::FieldsNamed { named: fields, .. }) => {
-                let tys = fields
-                    .iter()
-                    .filter(|field| !is_curried(field))
-                    .map(|field| &field.ty)
-                    .collect::<Vec<_>>();
+        }
 
-                quote! {
-                    (#(#tys),*)
-                }
+        pub fn send(&self, frame: Option<Frame>) {
+            if let (Some((socket, _)), Some(frame)) = (self.0.borrow().as_ref(), frame) {
+                let _ = match frame {
+                    Frame::Text(text) => socket.send_with_str(&text),
+                    Frame::Bytes(bytes) => socket.send_with_u8_array(&bytes),
+                };
             }
-        })
-        .collect::<Vec<_>>();
-
-    let callbacks = field_names
-        .iter()
-        .zip(tys.iter())
-        .zip(curried_tys.iter())
-        .map(|((field_name, ty), curried_ty)| {
-            if let Some(curried_ty) = curried_ty {
-                quote! {
-                    #field_name: ::std::cell::RefCell<
-                        ::std::collections::HashMap<#curried_ty, ::yew::callback::Callback<#ty>>
-                    >,
-                }
-            } else {
-                quote! {
-                    #field_name: ::std::cell::RefCell<Option<::yew::callback::Callback<#ty>>>,
-                }
+        }
+
+        pub fn close(&self) {
+            let socket = self.0.borrow_mut().take();
+            if let Some((socket, listeners)) = socket {
+                // the listeners are removed first so closing it ourselves is not reported
+                drop(listeners);
+                let _ = socket.close();
             }
-        })
-        .collect::<Vec<_>>();
-
-    let constructors = e
-        .variants
-        .iter()
-        .zip(tys.iter())
-        .zip(field_names.iter())
-        .zip(curried_tys.iter())
-        .map(|(((variant, ty), field_name), curried_ty)| {
-            let name = &variant.ident;
-            let fn_name = Ident::new(&name.to_string().to_snake_case(), Span::call_site());
-
-            match &variant.fields {
-                syn::Fields::Unit => {
-                    quote! {
-                        fn #fn_name(&self) -> ::yew::callback::Callback<#ty> {
-                            if self.#field_name.borrow().is_none() {
-                                self.#field_name.replace(
-                                    Some(self.link.callback(|_| #enum_name::#name))
-                                );
+        }
+    }
+
+    #[cfg(feature = "net")]
+    impl Drop for WebSocketSlot {
+        fn drop(&mut self) {
+            self.close();
+        }
+    }
+
+    // the name of an event of the source and the handler of its data
+    #[cfg(feature = "net")]
+    type EventSourceHandlers = Vec<(&'static str, Box<dyn Fn(String)>)>;
+
+    #[cfg(feature = "net")]
+    #[derive(Debug, Default)]
+    pub struct EventSourceSlot(
+        RefCell<Option<(web_sys::EventSource, Vec<gloo_events::EventListener>)>>,
+    );
+
+    #[cfg(feature = "net")]
+    impl EventSourceSlot {
+        pub fn connect(&self, url: &str, handlers: EventSourceHandlers) {
+            self.close();
+
+            // there are no event sources outside of the browser (server-side rendering)
+            #[cfg(not(target_arch = "wasm32"))]
+            let _ = (url, handlers);
+
+            #[cfg(target_arch = "wasm32")]
+            if let Ok(source) = web_sys::EventSource::new(url) {
+                let listeners = handlers
+                    .into_iter()
+                    .map(|(event_type, handler)| {
+                        gloo_events::EventListener::new(&source, event_type, move |event| {
+                            let data = event.unchecked_ref::<web_sys::MessageEvent>().data();
+                            if let Some(data) = data.as_string() {
+                                handler(data);
                             }
-                            self.#field_name.borrow().clone().unwrap()
-                        }
-                    }
-                }
-                syn::Fields::Unnamed(syn::FieldsUnnamed {
-                    unnamed: fields, ..
-                })
-                | syn::Fields::Named(syn::FieldsNamed { named: fields, .. }) => {
-                    let is_named = fields.iter().any(|field| field.ident.is_some());
-                    let idents = fields
-                        .iter()
-                        .enumerate()
-                        .map(|(i, field)| {
-                            field.ident.clone().unwrap_or_else(|| {
-                                Ident::new(&format!("arg_{i}"), Span::call_site())
-                            })
                         })
-                        .collect::<Vec<_>>();
-
-                    if curried_ty.is_some() {
-                        let args = fields
-                            .iter()
-                            .zip(idents.iter())
-                            .filter_map(|(field, ident)| is_curried(field).then_some(ident))
-                            .collect::<Vec<_>>();
-                        let args_sig = fields
-                            .iter()
-                            .zip(idents.iter())
-                            .filter(|(field, _)| is_curried(field))
-                            .map(|(field, ident)| {
-                                let ty = &field.ty;
-
-                                quote! {
-                                    #ident: #ty
-                                }
-                            })
-                            .collect::<Vec<_>>();
-                        let ins = fields
-                            .iter()
-                            .zip(idents.iter())
-                            .filter_map(|(field, ident)| (!is_curried(field)).then_some(ident))
-                            .collect::<Vec<_>>();
-                        let keys = args
-                            .iter()
-                            .map(|arg| {
-                                quote! {
-                                    let #arg = #arg.clone();
-                                }
-                            })
-                            .collect::<Vec<_>>();
-                        let constructor = if is_named {
-                            let cloned_args = fields
-                                .iter()
-                                .zip(idents.iter())
-                                .map(|(field, ident)| {
-                                    if is_curried(field) {
-                                        quote! {
-                                            #ident: #ident.clone()
-                                        }
-                                    } else {
-                                        quote! {
-                                            #ident
-                                        }
-                                    }
-                                })
-                                .collect::<Vec<_>>();
-
-                            quote! {
-                                #enum_name::#name { #(#cloned_args),* }
-                            }
-                        } else {
-                            let cloned_args = fields
-                                .iter()
-                                .zip(idents.iter())
-                                .map(|(field, ident)| {
-                                    if is_curried(field) {
-                                        quote! {
-                                            #ident.clone()
-                                        }
-                                    } else {
-                                        quote! {
-                                            #ident
-                                        }
-                                    }
-                                })
-                                .collect::<Vec<_>>();
-
-                            quote! {
-                                #enum_name::#name(#(#cloned_args),*)
-                            }
-                        };
+                    })
+                    .collect();
 
-                        quote! {
-                            #vis fn #fn_name(&self #(, #args_sig )* )
-                                -> ::yew::callback::Callback<#ty>
-                            {
-                                self.#field_name
-                                    .borrow_mut()
-                                    .entry((#(#args),*))
-                                    .or_insert_with_key(|(#(#args),*)| {
-                                        #(#keys)*
-                                        self.link.callback(move |(#(#ins),*)| #constructor)
-                                    })
-                                    .clone()
-                            }
-                        }
-                    } else {
-                        let constructor = if is_named {
-                            quote! {
-                                #enum_name::#name { #(#idents),* }
-                            }
-                        } else {
-                            quote! {
-                                #enum_name::#name(#(#idents),*)
-                            }
-                        };
+                *self.0.borrow_mut() = Some((source, listeners));
+            }
+        }
 
-                        quote! {
-                            #vis fn #fn_name(&self) -> ::yew::callback::Callback<#ty> {
-                                if self.#field_name.borrow().is_none() {
-                                    self.#field_name.replace(Some(self
-                                        .link
-                                        .callback(|(#(#idents),*)| #constructor)
-                                    ));
-                                }
-                                self.#field_name.borrow().clone().unwrap()
-                            }
+        pub fn close(&self) {
+            let source = self.0.borrow_mut().take();
+            if let Some((source, listeners)) = source {
+                drop(listeners);
+                source.close();
+            }
+        }
+    }
+
+    #[cfg(feature = "net")]
+    impl Drop for EventSourceSlot {
+        fn drop(&mut self) {
+            self.close();
+        }
+    }
+
+    #[cfg(any(feature = "clipboard", feature = "drag"))]
+    pub trait FromDataTransfer: Sized {
+        fn from_data_transfer(data: Option<web_sys::DataTransfer>, format: &str) -> Self;
+    }
+
+    #[cfg(any(feature = "clipboard", feature = "drag"))]
+    impl FromDataTransfer for String {
+        fn from_data_transfer(data: Option<web_sys::DataTransfer>, format: &str) -> Self {
+            data.and_then(|data| data.get_data(format).ok())
+                .unwrap_or_default()
+        }
+    }
+
+    #[cfg(any(feature = "clipboard", feature = "drag"))]
+    impl FromDataTransfer for Vec<web_sys::File> {
+        fn from_data_transfer(data: Option<web_sys::DataTransfer>, _format: &str) -> Self {
+            let files = data.and_then(|data| data.files());
+            files
+                .map(|files| (0..files.length()).filter_map(|i| files.get(i)).collect())
+                .unwrap_or_default()
+        }
+    }
+
+    #[cfg(feature = "drag")]
+    pub fn drag_data(event: &web_sys::DragEvent) -> Option<web_sys::DataTransfer> {
+        // the browser opens the dropped files if the default action is not prevented
+        if event.type_() == "drop" {
+            event.prevent_default();
+        }
+
+        event.data_transfer()
+    }
+
+    #[cfg(feature = "forms")]
+    pub fn parse_form_value<T>(field: &'static str, value: &str) -> Result<T, crate::FormError>
+    where
+        T: std::str::FromStr,
+        T::Err: std::fmt::Display,
+    {
+        value.parse().map_err(|err: T::Err| crate::FormError {
+            field,
+            message: err.to_string(),
+        })
+    }
+
+    #[cfg(feature = "forms")]
+    pub fn validate_form_value<E: std::fmt::Display>(
+        field: &'static str,
+        result: Result<(), E>,
+    ) -> Result<(), crate::FormError> {
+        result.map_err(|err| crate::FormError {
+            field,
+            message: err.to_string(),
+        })
+    }
+
+    // the value of the `<input>`, `<select>` or `<textarea>` that sent the event
+    #[cfg(any(feature = "bind", feature = "forms"))]
+    pub fn element_value(event: &web_sys::Event) -> String {
+        let target = match event.target() {
+            Some(target) => target,
+            None => return String::new(),
+        };
+
+        if let Some(input) = target.dyn_ref::<web_sys::HtmlInputElement>() {
+            input.value()
+        } else if let Some(select) = target.dyn_ref::<web_sys::HtmlSelectElement>() {
+            select.value()
+        } else if let Some(textarea) = target.dyn_ref::<web_sys::HtmlTextAreaElement>() {
+            textarea.value()
+        } else {
+            String::new()
+        }
+    }
+
+    // the values of the named elements of the submitted `<form>`, the page is not reloaded
+    #[cfg(feature = "forms")]
+    pub fn form_values(event: &web_sys::Event) -> impl Fn(&str) -> String {
+        event.prevent_default();
+        let data = event
+            .target()
+            .and_then(|target| target.dyn_into::<web_sys::HtmlFormElement>().ok())
+            .and_then(|form| web_sys::FormData::new_with_form(&form).ok());
+
+        move |name| {
+            data.as_ref()
+                .and_then(|data| data.get(name).as_string())
+                .unwrap_or_default()
+        }
+    }
+
+    // `ClipboardEvent` and `Clipboard` are unstable APIs in `web-sys`
+    #[cfg(feature = "clipboard")]
+    pub fn clipboard_data(event: &web_sys::Event) -> Option<web_sys::DataTransfer> {
+        js_sys::Reflect::get(event, &JsValue::from_str("clipboardData"))
+            .ok()
+            .and_then(|data| data.dyn_into().ok())
+    }
+
+    #[cfg(feature = "clipboard")]
+    pub fn write_clipboard(text: String, done: impl FnOnce(bool) + 'static) {
+        // there is no clipboard outside of the browser (server-side rendering)
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = (text, done);
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            let promise = web_sys::window().and_then(|window| {
+                let clipboard =
+                    js_sys::Reflect::get(&window.navigator(), &JsValue::from_str("clipboard"))
+                        .ok()?;
+                let write_text = js_sys::Reflect::get(&clipboard, &JsValue::from_str("writeText"))
+                    .ok()?
+                    .dyn_into::<js_sys::Function>()
+                    .ok()?;
+                write_text
+                    .call1(&clipboard, &JsValue::from_str(&text))
+                    .ok()?
+                    .dyn_into::<js_sys::Promise>()
+                    .ok()
+            });
+
+            match promise {
+                Some(promise) => wasm_bindgen_futures::spawn_local(async move {
+                    done(wasm_bindgen_futures::JsFuture::from(promise).await.is_ok());
+                }),
+                None => done(false),
+            }
+        }
+    }
+
+    // shared by all the callbacks of the cache so a pointer captured on `pointerdown` by a variant
+    // is released on `pointerup` by another one
+    #[cfg(feature = "pointer")]
+    #[derive(Debug, Default, Clone)]
+    pub struct PointerCapture(std::rc::Rc<RefCell<Vec<i32>>>);
+
+    #[cfg(feature = "pointer")]
+    impl PointerCapture {
+        pub fn track(&self, event: &web_sys::PointerEvent) {
+            let pointer_id = event.pointer_id();
+            let element = event
+                .current_target()
+                .and_then(|target| target.dyn_into::<web_sys::Element>().ok());
+            let mut pointers = self.0.borrow_mut();
+
+            match event.type_().as_str() {
+                "pointerdown" => {
+                    let captured = element
+                        .map(|element| element.set_pointer_capture(pointer_id).is_ok())
+                        .unwrap_or(false);
+                    if captured && !pointers.contains(&pointer_id) {
+                        pointers.push(pointer_id);
+                    }
+                }
+                "pointerup" | "pointercancel" | "lostpointercapture" => {
+                    if let Some(i) = pointers.iter().position(|x| *x == pointer_id) {
+                        pointers.remove(i);
+                        if let Some(element) = element {
+                            // the capture may have been released already by the browser
+                            let _ = element.release_pointer_capture(pointer_id);
                         }
                     }
                 }
+                _ => {}
             }
-        })
-        .collect::<Vec<_>>();
+        }
+
+        pub fn is_capturing(&self) -> bool {
+            !self.0.borrow().is_empty()
+        }
+    }
+
+    #[cfg(feature = "geolocation")]
+    type PositionResult = Result<super::GeolocationPosition, super::GeolocationError>;
+
+    #[cfg(feature = "geolocation")]
+    type PositionWatch = (
+        i32,
+        wasm_bindgen::closure::Closure<dyn FnMut(JsValue)>,
+        wasm_bindgen::closure::Closure<dyn FnMut(JsValue)>,
+    );
+
+    // the `GeolocationPosition` of web-sys is still an unstable API, the objects are read as they
+    // are
+    #[cfg(feature = "geolocation")]
+    fn read_f64(object: &JsValue, key: &str) -> Option<f64> {
+        js_sys::Reflect::get(object, &JsValue::from_str(key))
+            .ok()?
+            .as_f64()
+    }
+
+    #[cfg(feature = "geolocation")]
+    pub fn geolocation_position(position: &JsValue) -> super::GeolocationPosition {
+        let coords = js_sys::Reflect::get(position, &JsValue::from_str("coords"))
+            .unwrap_or(JsValue::UNDEFINED);
+
+        super::GeolocationPosition {
+            latitude: read_f64(&coords, "latitude").unwrap_or_default(),
+            longitude: read_f64(&coords, "longitude").unwrap_or_default(),
+            accuracy: read_f64(&coords, "accuracy").unwrap_or_default(),
+            altitude: read_f64(&coords, "altitude"),
+            altitude_accuracy: read_f64(&coords, "altitudeAccuracy"),
+            heading: read_f64(&coords, "heading"),
+            speed: read_f64(&coords, "speed"),
+            timestamp: read_f64(position, "timestamp").unwrap_or_default(),
+        }
+    }
 
-    quote! {
-        #[derive(Debug)]
-        #vis struct #name<C: ::yew::html::BaseComponent> {
-            link: ::yew::html::Scope<C>,
-            #(#callbacks)*
+    #[cfg(feature = "geolocation")]
+    pub fn geolocation_error(error: &JsValue) -> super::GeolocationError {
+        super::GeolocationError {
+            code: read_f64(error, "code").unwrap_or_default() as u16,
+            message: js_sys::Reflect::get(error, &JsValue::from_str("message"))
+                .ok()
+                .and_then(|message| message.as_string())
+                .unwrap_or_default(),
+        }
+    }
+
+    #[cfg(feature = "geolocation")]
+    #[derive(Default)]
+    pub struct GeolocationSlot(RefCell<Option<PositionWatch>>);
+
+    #[cfg(feature = "geolocation")]
+    impl GeolocationSlot {
+        pub fn watch(&self, emit: impl Fn(PositionResult) + 'static) {
+            self.clear();
+
+            // there is no geolocation outside of the browser (server-side rendering)
+            #[cfg(not(target_arch = "wasm32"))]
+            let _ = emit;
+
+            #[cfg(target_arch = "wasm32")]
+            {
+                let geolocation = match web_sys::window()
+                    .and_then(|window| window.navigator().geolocation().ok())
+                {
+                    Some(geolocation) => geolocation,
+                    None => return,
+                };
+                let emit = std::rc::Rc::new(emit);
+                let on_position = {
+                    let emit = emit.clone();
+                    wasm_bindgen::closure::Closure::<dyn FnMut(_)>::new(move |position: JsValue| {
+                        emit(Ok(geolocation_position(&position)))
+                    })
+                };
+                let on_error =
+                    wasm_bindgen::closure::Closure::<dyn FnMut(_)>::new(move |error: JsValue| {
+                        emit(Err(geolocation_error(&error)))
+                    });
+                let id = geolocation.watch_position_with_error_callback(
+                    on_position.as_ref().unchecked_ref(),
+                    Some(on_error.as_ref().unchecked_ref()),
+                );
+
+                if let Ok(id) = id {
+                    *self.0.borrow_mut() = Some((id, on_position, on_error));
+                }
+            }
         }
 
-        impl<C: ::yew::html::BaseComponent<Message = #enum_name>> #name<C> {
-            #vis fn new(link: ::yew::html::Scope<C>) -> Self {
-                Self {
-                    link,
-                    #(#inits)*
+        pub fn clear(&self) {
+            // the closures must outlive the watch
+            if let Some((id, on_position, on_error)) = self.0.borrow_mut().take() {
+                if let Some(geolocation) =
+                    web_sys::window().and_then(|window| window.navigator().geolocation().ok())
+                {
+                    geolocation.clear_watch(id);
                 }
+                drop((on_position, on_error));
             }
+        }
+
+        pub fn is_watching(&self) -> bool {
+            self.0.borrow().is_some()
+        }
+    }
+
+    #[cfg(feature = "geolocation")]
+    impl Drop for GeolocationSlot {
+        fn drop(&mut self) {
+            self.clear();
+        }
+    }
+
+    #[cfg(feature = "geolocation")]
+    impl std::fmt::Debug for GeolocationSlot {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("GeolocationSlot")
+                .field("watch_id", &self.0.borrow().as_ref().map(|(id, _, _)| *id))
+                .finish()
+        }
+    }
+
+    // the field of a `#[geolocation(watch)]` variant: errors are skipped unless it is a `Result`
+    #[cfg(feature = "geolocation")]
+    pub trait FromPosition: Sized {
+        fn from_position(position: PositionResult) -> Option<Self>;
+    }
+
+    #[cfg(feature = "geolocation")]
+    impl FromPosition for super::GeolocationPosition {
+        fn from_position(position: PositionResult) -> Option<Self> {
+            position.ok()
+        }
+    }
+
+    #[cfg(feature = "geolocation")]
+    impl FromPosition for PositionResult {
+        fn from_position(position: PositionResult) -> Option<Self> {
+            Some(position)
+        }
+    }
+
+    #[cfg(feature = "js-interop")]
+    trait AnyClosure {
+        fn forget(self: Box<Self>);
+    }
+
+    #[cfg(feature = "js-interop")]
+    impl<F: ?Sized + wasm_bindgen::closure::WasmClosure> AnyClosure
+        for wasm_bindgen::closure::Closure<F>
+    {
+        fn forget(self: Box<Self>) {
+            (*self).forget()
+        }
+    }
+
+    // a function and the closure it calls, by curried arguments
+    #[cfg(feature = "js-interop")]
+    type JsClosures<K> = HashMap<K, (js_sys::Function, Box<dyn AnyClosure>)>;
+
+    // the closures are kept alive by the cache: a function is invalidated when its closure is
+    // dropped, unless the closure was forgotten (leaked) first
+    #[cfg(feature = "js-interop")]
+    pub struct JsFunctions<K> {
+        closures: RefCell<JsClosures<K>>,
+        forget_on_drop: bool,
+    }
 
-            #(#constructors)*
+    #[cfg(feature = "js-interop")]
+    impl<K: Eq + Hash + Clone> JsFunctions<K> {
+        pub fn new(forget_on_drop: bool) -> Self {
+            Self {
+                closures: Default::default(),
+                forget_on_drop,
+            }
         }
 
-        impl<C: ::yew::html::BaseComponent<Message = #enum_name>> From<::yew::html::Scope<C>>
-            for #name<C>
+        pub fn get_or_insert<F>(
+            &self,
+            key: K,
+            make: impl FnOnce(K) -> wasm_bindgen::closure::Closure<F>,
+        ) -> js_sys::Function
+        where
+            F: ?Sized + wasm_bindgen::closure::WasmClosure + 'static,
         {
-            fn from(link: ::yew::html::Scope<C>) -> Self {
-                Self::new(link)
+            if let Some((function, _)) = self.closures.borrow().get(&key) {
+                return function.clone();
+            }
+            let closure = make(key.clone());
+            let function = closure.as_ref().unchecked_ref::<js_sys::Function>().clone();
+            self.closures
+                .borrow_mut()
+                .entry(key)
+                .or_insert((function, Box::new(closure)))
+                .0
+                .clone()
+        }
+
+        pub fn forget(&self, key: &K) {
+            let closure = self.closures.borrow_mut().remove(key);
+            if let Some((_, closure)) = closure {
+                closure.forget();
+            }
+        }
+
+        pub fn remove(&self, key: &K) {
+            let closure = self.closures.borrow_mut().remove(key);
+            drop(closure);
+        }
+
+        pub fn clear(&self) {
+            let closures = std::mem::take(&mut *self.closures.borrow_mut());
+            drop(closures);
+        }
+    }
+
+    #[cfg(feature = "js-interop")]
+    impl<K> Drop for JsFunctions<K> {
+        fn drop(&mut self) {
+            if self.forget_on_drop {
+                for (_, (_, closure)) in self.closures.get_mut().drain() {
+                    closure.forget();
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "js-interop")]
+    impl<K> std::fmt::Debug for JsFunctions<K> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("JsFunctions")
+                .field("closures", &self.closures.borrow().len())
+                .field("forget_on_drop", &self.forget_on_drop)
+                .finish()
+        }
+    }
+
+    #[cfg(feature = "agent")]
+    pub struct BridgeSlot<W: gloo_worker::Worker>(RefCell<Option<gloo_worker::WorkerBridge<W>>>);
+
+    #[cfg(feature = "agent")]
+    impl<W: gloo_worker::Worker> Default for BridgeSlot<W> {
+        fn default() -> Self {
+            Self(Default::default())
+        }
+    }
+
+    #[cfg(feature = "agent")]
+    impl<W: gloo_worker::Worker> BridgeSlot<W> {
+        pub fn start(&self, spawn: impl FnOnce() -> gloo_worker::WorkerBridge<W>) {
+            // workers can only be spawned in the browser (server-side rendering)
+            #[cfg(not(target_arch = "wasm32"))]
+            let _ = spawn;
+
+            #[cfg(target_arch = "wasm32")]
+            {
+                let bridge = spawn();
+                let previous = self.0.borrow_mut().replace(bridge);
+                drop(previous);
+            }
+        }
+
+        pub fn send(&self, input: W::Input) {
+            if let Some(bridge) = self.0.borrow().as_ref() {
+                bridge.send(input);
             }
         }
 
-        impl<C: ::yew::html::BaseComponent<Message = #enum_name>> From<&::yew::html::Scope<C>>
-            for #name<C>
+        pub fn stop(&self) {
+            let bridge = self.0.borrow_mut().take();
+            drop(bridge);
+        }
+    }
+
+    #[cfg(feature = "agent")]
+    impl<W: gloo_worker::Worker> std::fmt::Debug for BridgeSlot<W> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("BridgeSlot")
+                .field("running", &self.0.borrow().is_some())
+                .finish()
+        }
+    }
+
+    #[cfg(feature = "sensors")]
+    pub fn device_orientation(event: &JsValue) -> super::DeviceOrientation {
+        let event = event.unchecked_ref::<web_sys::DeviceOrientationEvent>();
+
+        super::DeviceOrientation {
+            alpha: event.alpha(),
+            beta: event.beta(),
+            gamma: event.gamma(),
+            absolute: event.absolute(),
+        }
+    }
+
+    #[cfg(feature = "sensors")]
+    pub fn device_motion(event: &JsValue) -> super::DeviceMotion {
+        let event = event.unchecked_ref::<web_sys::DeviceMotionEvent>();
+        let acceleration = |acceleration: Option<web_sys::DeviceAcceleration>| {
+            acceleration
+                .map(|acceleration| super::Acceleration {
+                    x: acceleration.x(),
+                    y: acceleration.y(),
+                    z: acceleration.z(),
+                })
+                .unwrap_or_default()
+        };
+
+        super::DeviceMotion {
+            acceleration: acceleration(event.acceleration()),
+            acceleration_including_gravity: acceleration(event.acceleration_including_gravity()),
+            rotation_rate: event
+                .rotation_rate()
+                .map(|rate| super::RotationRate {
+                    alpha: rate.alpha(),
+                    beta: rate.beta(),
+                    gamma: rate.gamma(),
+                })
+                .unwrap_or_default(),
+            interval: event.interval(),
+        }
+    }
+
+    // iOS only sends the events once the user allowed it, the request must be made while handling
+    // a user gesture; the other browsers do not have `requestPermission()` and always allow it
+    #[cfg(feature = "sensors")]
+    pub fn request_sensor_permission(interface: &'static str, done: impl FnOnce(bool) + 'static) {
+        // there are no sensors outside of the browser (server-side rendering)
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = (interface, done);
+
+        #[cfg(target_arch = "wasm32")]
         {
-            fn from(link: &::yew::html::Scope<C>) -> Self {
-                Self::new(link.to_owned())
+            let request = web_sys::window().and_then(|window| {
+                let interface =
+                    js_sys::Reflect::get(&window, &JsValue::from_str(interface)).ok()?;
+                let request =
+                    js_sys::Reflect::get(&interface, &JsValue::from_str("requestPermission"))
+                        .ok()?
+                        .dyn_into::<js_sys::Function>()
+                        .ok()?;
+                Some((interface, request))
+            });
+            let (interface, request) = match request {
+                Some(request) => request,
+                None => return done(true),
+            };
+
+            match request
+                .call0(&interface)
+                .ok()
+                .and_then(|promise| promise.dyn_into::<js_sys::Promise>().ok())
+            {
+                Some(promise) => wasm_bindgen_futures::spawn_local(async move {
+                    let state = wasm_bindgen_futures::JsFuture::from(promise).await;
+                    let state = state.ok().and_then(|state| state.as_string());
+                    done(state.as_deref() == Some("granted"));
+                }),
+                None => done(false),
             }
         }
     }
-}
 
-fn is_curried(field: &syn::Field) -> bool {
-    field
-        .attrs
-        .iter()
-        .any(|x| x.path.get_ident().map(|x| x == "curry").unwrap_or(false))
+    // the events are created without a target, they are not dispatched to any element
+    #[cfg(feature = "test-util")]
+    pub trait Synthetic {
+        fn synthetic() -> Self;
+    }
+
+    #[cfg(feature = "test-util")]
+    macro_rules! synthetic {
+        ($($ty:ident => $type_:literal,)*) => {
+            $(
+                impl Synthetic for web_sys::$ty {
+                    #[track_caller]
+                    fn synthetic() -> Self {
+                        web_sys::$ty::new($type_)
+                            .expect(concat!("could not create a synthetic `", $type_, "` event"))
+                    }
+                }
+            )*
+        };
+    }
+
+    #[cfg(feature = "test-util")]
+    synthetic! {
+        Event => "change",
+        AnimationEvent => "animationend",
+        DragEvent => "drop",
+        FocusEvent => "focus",
+        InputEvent => "input",
+        KeyboardEvent => "keydown",
+        MouseEvent => "click",
+        PointerEvent => "pointerdown",
+        SubmitEvent => "submit",
+        TouchEvent => "touchstart",
+        TransitionEvent => "transitionend",
+        WheelEvent => "wheel",
+    }
+
+    #[cfg(feature = "test-util")]
+    impl<A: Synthetic, B: Synthetic> Synthetic for (A, B) {
+        fn synthetic() -> Self {
+            (A::synthetic(), B::synthetic())
+        }
+    }
+
+    #[cfg(feature = "test-util")]
+    impl<A: Synthetic, B: Synthetic, C: Synthetic> Synthetic for (A, B, C) {
+        fn synthetic() -> Self {
+            (A::synthetic(), B::synthetic(), C::synthetic())
+        }
+    }
+
+    // the caches stored by `store()`, by type of cache and then by key; a type of cache only ever
+    // has one type of key
+    #[cfg(feature = "registry")]
+    thread_local! {
+        pub(crate) static REGISTRY: RefCell<HashMap<std::any::TypeId, Box<dyn std::any::Any>>> =
+            RefCell::new(HashMap::new());
+    }
+
+    #[cfg(feature = "registry")]
+    #[track_caller]
+    fn with_registry<T: 'static, K: Eq + Hash + 'static, R>(
+        f: impl FnOnce(&mut HashMap<K, T>) -> R,
+    ) -> R {
+        REGISTRY.with(|registry| {
+            let mut registry = registry.borrow_mut();
+            let caches = registry
+                .entry(std::any::TypeId::of::<T>())
+                .or_insert_with(|| Box::new(HashMap::<K, T>::new()));
+            match caches.downcast_mut::<HashMap<K, T>>() {
+                Some(caches) => f(caches),
+                None => panic!(
+                    "the caches of `{}` are already stored with keys of another type than `{}`",
+                    std::any::type_name::<T>(),
+                    std::any::type_name::<K>(),
+                ),
+            }
+        })
+    }
+
+    // the cache replaced by `store()` is dropped once the registry is not borrowed anymore, its
+    // listeners might be unregistered
+    #[cfg(feature = "registry")]
+    #[track_caller]
+    pub fn registry_store<T: 'static, K: Eq + Hash + 'static>(key: K, cache: T) {
+        drop(with_registry(|caches| caches.insert(key, cache)));
+    }
+
+    #[cfg(feature = "registry")]
+    #[track_caller]
+    pub fn registry_take<T: 'static, K: Eq + Hash + 'static>(key: &K) -> Option<T> {
+        with_registry(|caches: &mut HashMap<K, T>| caches.remove(key))
+    }
+
+    #[cfg(feature = "registry")]
+    pub fn registry_evict_all<T: 'static>() {
+        let caches =
+            REGISTRY.with(|registry| registry.borrow_mut().remove(&std::any::TypeId::of::<T>()));
+        drop(caches);
+    }
+
+    // every mount gets its own root so the tests do not see the elements of each other
+    #[cfg(feature = "test-util")]
+    pub fn test_mount<T>(
+        mount: impl FnOnce(web_sys::Element) -> (T, Box<dyn FnOnce()>),
+    ) -> super::TestMount<T> {
+        let document = web_sys::window()
+            .and_then(|window| window.document())
+            .expect("the components can only be mounted in a browser");
+        let body = document.body().expect("the document has no body");
+        let root = document
+            .create_element("div")
+            .expect("could not create the root");
+        body.append_child(&root).expect("could not insert the root");
+        let (callbacks, destroy) = mount(root.clone());
+
+        super::TestMount {
+            callbacks,
+            root,
+            destroy: Some(destroy),
+        }
+    }
 }
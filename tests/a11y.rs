@@ -0,0 +1,63 @@
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    Open,
+    Select(#[curry] usize),
+    OnCell {
+        #[curry]
+        row: usize,
+        #[curry]
+        col: Option<usize>,
+    },
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        let (onclick, onkeydown): (Callback<MouseEvent>, Callback<KeyboardEvent>) =
+            self.cb.open_a11y();
+        assert_eq!((onclick, onkeydown), self.cb.open_a11y());
+
+        assert_eq!(self.cb.select_a11y(1), self.cb.select_a11y(1));
+        assert_ne!(self.cb.select_a11y(1).0, self.cb.select_a11y(2).0);
+        assert_ne!(self.cb.select_a11y(1).1, self.cb.select_a11y(2).1);
+        assert_eq!(self.cb.on_cell_a11y(1, 2), self.cb.on_cell_a11y(1, Some(2)));
+        assert_ne!(self.cb.on_cell_a11y(1, 2), self.cb.on_cell_a11y(1, None));
+
+        // the callbacks are created again with the callback of the getter
+        let select = self.cb.select_a11y(1);
+        self.cb.sync_select_keys([]);
+        assert_ne!(select, self.cb.select_a11y(1));
+
+        html! {
+            <>
+                { for (0..3).map(|i| {
+                    let (onclick, onkeydown) = self.cb.select_a11y(i);
+                    html! { <div role="button" tabindex="0" {onclick} {onkeydown}>{ i }</div> }
+                }) }
+            </>
+        }
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "agent")]
+
+use gloo::worker::{HandlerId, Worker, WorkerScope};
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    Tick,
+    Received(#[curry] HandlerId, String),
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Worker for Test {
+    type Message = Msg;
+    type Input = String;
+    type Output = String;
+
+    fn create(scope: &WorkerScope<Self>) -> Self {
+        Self { cb: scope.into() }
+    }
+
+    fn update(&mut self, _scope: &WorkerScope<Self>, _msg: Self::Message) {
+        let cb1: Callback<()> = self.cb.tick();
+        let cb2: Callback<()> = self.cb.tick();
+        assert_eq!(cb1, cb2);
+    }
+
+    fn received(&mut self, _scope: &WorkerScope<Self>, msg: Self::Input, id: HandlerId) {
+        let cb1: Callback<String> = self.cb.received(id);
+        let cb2: Callback<String> = self.cb.received(id);
+        assert_eq!(cb1, cb2);
+        cb1.emit(msg);
+    }
+}
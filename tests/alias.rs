@@ -0,0 +1,101 @@
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Debug, PartialEq)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl From<MouseEvent> for Point {
+    fn from(event: MouseEvent) -> Self {
+        Self {
+            x: event.client_x(),
+            y: event.client_y(),
+        }
+    }
+}
+
+impl From<PointerEvent> for Point {
+    fn from(event: PointerEvent) -> Self {
+        Self {
+            x: event.client_x(),
+            y: event.client_y(),
+        }
+    }
+}
+
+fn touch_point(_event: TouchEvent) -> Point {
+    Point { x: 0, y: 0 }
+}
+
+fn key_step(event: KeyboardEvent) -> i32 {
+    match event.key().as_str() {
+        "ArrowUp" => -1,
+        "ArrowDown" => 1,
+        _ => 0,
+    }
+}
+
+#[derive(Debug, PartialEq, Callbacks)]
+pub enum Msg {
+    #[input(MouseEvent)]
+    #[alias(TouchEvent, map = touch_point)]
+    #[alias(PointerEvent)]
+    OnDrag(Point),
+    #[alias(KeyboardEvent, map = key_step)]
+    OnStep {
+        step: i32,
+        #[skip]
+        smooth: bool,
+    },
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        let cb1: Callback<MouseEvent> = self.cb.on_drag();
+        let cb2: Callback<TouchEvent> = self.cb.on_drag_touch();
+        let cb3: Callback<TouchEvent> = self.cb.on_drag_touch();
+        assert_eq!(cb2, cb3);
+        let cb4: Callback<PointerEvent> = self.cb.on_drag_pointer();
+        assert_eq!(cb4, self.cb.on_drag_pointer());
+
+        let step: Callback<i32> = self.cb.on_step();
+        let step_keyboard: Callback<KeyboardEvent> = self.cb.on_step_keyboard();
+        assert_eq!(step_keyboard, self.cb.on_step_keyboard());
+
+        let inert = MsgCallbacks::<Self>::inert();
+        assert_eq!(inert.on_drag_touch(), inert.on_drag_touch());
+
+        html! {
+            <div
+                onmousemove={cb1}
+                ontouchmove={cb2}
+                onpointermove={cb4}
+                onkeydown={step_keyboard}
+                onclick={step.reform(|_| 1)}
+            />
+        }
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
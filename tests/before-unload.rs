@@ -0,0 +1,48 @@
+#![cfg(feature = "listen")]
+
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[before_unload(when_dirty)]
+    Leaving,
+    Edited(String),
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<()> = self.cb.leaving();
+        let cb2: Callback<()> = self.cb.leaving();
+        assert_eq!(cb1, cb2);
+
+        assert!(!self.cb.is_dirty());
+        self.cb.set_dirty(true);
+        assert!(self.cb.is_dirty());
+        self.cb.set_dirty(false);
+        self.cb.stop_leaving();
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "bind")]
+
+use yew::prelude::*;
+use yew_callbacks::{Callbacks, EditCallbacks, InputBinding};
+
+#[derive(Callbacks)]
+pub enum Msg {
+    SetName(AttrValue),
+    SetNote(String),
+    SetTag(#[curry] usize, String),
+    OnEdit(Event),
+    OnClick(MouseEvent),
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+    name: AttrValue,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+            name: AttrValue::from("Ferris"),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        let InputBinding { value, oninput } = self.cb.bind_set_name(&self.name);
+        assert_eq!(value, self.name);
+        assert_eq!(oninput, self.cb.bind_set_name(&self.name).oninput);
+        // the callback of the binding is not the getter itself, it receives the event
+        let _: Callback<AttrValue> = self.cb.set_name();
+
+        let note = self.cb.bind_set_note(&AttrValue::from("hello"));
+        assert_eq!(note.value, AttrValue::from("hello"));
+        assert_ne!(note.oninput, oninput);
+
+        // the binding and the edits share the callback of `oninput`
+        let EditCallbacks {
+            oninput: edit_oninput,
+            onchange,
+            onblur,
+        } = self.cb.edit_set_name();
+        assert_eq!(edit_oninput, oninput);
+        assert_eq!(onchange, self.cb.edit_set_name().onchange);
+        assert_eq!(onblur, self.cb.edit_set_name().onblur);
+        assert_ne!(onchange, self.cb.edit_set_note().onchange);
+
+        let edit = self.cb.edit_on_edit();
+        assert_eq!(edit, self.cb.edit_on_edit());
+
+        let inert = MsgCallbacks::<Self>::inert();
+        assert_eq!(
+            inert.bind_set_name(&self.name).oninput,
+            inert.bind_set_name(&self.name).oninput,
+        );
+
+        html! {
+            <>
+                <input value={value} oninput={oninput} onchange={onchange} onblur={onblur} />
+                <textarea oninput={edit.oninput} onblur={edit.onblur} />
+            </>
+        }
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
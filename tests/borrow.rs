@@ -0,0 +1,54 @@
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    OnClick,
+    OnInput(InputEvent),
+    OnStuff { kb_event: KeyboardEvent },
+}
+
+#[derive(Callbacks)]
+#[callbacks(eager)]
+pub enum EagerMsg {
+    OnClick,
+    OnInput(InputEvent),
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+    eager: EagerMsgCallbacks,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+            eager: EagerMsgCallbacks::inert(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        // the borrowed callbacks are the cached ones, before and after they are cloned
+        let cb1: &Callback<()> = self.cb.on_click_ref();
+        assert_eq!(*cb1, self.cb.on_click());
+        assert_eq!(self.cb.on_input(), *self.cb.on_input_ref());
+        assert_eq!(*self.cb.on_stuff_ref(), self.cb.on_stuff());
+
+        assert_eq!(*self.eager.on_click_ref(), self.eager.on_click());
+        assert_eq!(*self.eager.on_input_ref(), self.eager.on_input());
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,100 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Debug, PartialEq, Callbacks)]
+pub enum Msg {
+    OnSelect(#[curry] usize, String),
+    OnMove {
+        #[curry]
+        from: usize,
+        #[curry]
+        to: Option<usize>,
+    },
+}
+
+#[derive(PartialEq, Properties)]
+struct ItemProps {
+    onselect: MsgCallbacksBoundOnSelect,
+}
+
+#[function_component]
+fn Item(props: &ItemProps) -> Html {
+    html! {
+        <span>{ props.onselect.keys() }</span>
+    }
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        // the handles are equal when they have the same keys
+        let bound1: MsgCallbacksBoundOnSelect = self.cb.handle_on_select(1);
+        let bound2: MsgCallbacksBoundOnSelect = self.cb.handle_on_select(1);
+        let bound3: MsgCallbacksBoundOnSelect = self.cb.handle_on_select(2);
+        assert_eq!(bound1, bound2);
+        assert_eq!(bound1, bound2.clone());
+        assert_ne!(bound1, bound3);
+        assert_eq!(*bound3.keys(), 2);
+        assert_eq!(
+            self.cb.handle_on_move(1, None),
+            self.cb.handle_on_move(1, None)
+        );
+        assert_ne!(
+            self.cb.handle_on_move(1, 2),
+            self.cb.handle_on_move(1, None)
+        );
+
+        // and different when they come from different caches
+        let other = MsgCallbacks::<Self>::inert();
+        assert_ne!(other.handle_on_select(1), bound1);
+
+        let messages = Rc::new(RefCell::new(Vec::new()));
+        let cb: MsgCallbacks = MsgCallbacks::from_callback(Callback::from({
+            let messages = messages.clone();
+            move |msg| messages.borrow_mut().push(msg)
+        }));
+        cb.handle_on_select(3).emit("a".to_string());
+        cb.handle_on_select(4).to_callback().emit("b".to_string());
+        cb.handle_on_move(5, 6).emit(());
+        assert_eq!(
+            *messages.borrow(),
+            [
+                Msg::OnSelect(3, "a".to_string()),
+                Msg::OnSelect(4, "b".to_string()),
+                Msg::OnMove {
+                    from: 5,
+                    to: Some(6),
+                },
+            ],
+        );
+
+        html! {
+            <>
+                <Item onselect={bound1} />
+                <Item onselect={bound3} />
+            </>
+        }
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "agent")]
+
+use gloo::worker::{HandlerId, Worker, WorkerScope};
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+pub struct Echo;
+
+impl Worker for Echo {
+    type Message = ();
+    type Input = String;
+    type Output = String;
+
+    fn create(_scope: &WorkerScope<Self>) -> Self {
+        Self
+    }
+
+    fn update(&mut self, _scope: &WorkerScope<Self>, _msg: Self::Message) {}
+
+    fn received(&mut self, scope: &WorkerScope<Self>, msg: Self::Input, id: HandlerId) {
+        scope.respond(id, msg);
+    }
+}
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[bridge(Echo, "/echo.js")]
+    Echoed(String),
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<String> = self.cb.echoed();
+        let cb2: Callback<String> = self.cb.echoed();
+        assert_eq!(cb1, cb2);
+
+        // the worker is not spawned outside of the browser
+        self.cb.send_to_echoed("hello".to_string());
+        self.cb.stop_echoed();
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
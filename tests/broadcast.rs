@@ -0,0 +1,53 @@
+#![cfg(feature = "broadcast")]
+
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Sync {
+    LoggedOut,
+    Renamed(String),
+}
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[broadcast("session")]
+    Session(Sync),
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<Sync> = self.cb.session();
+        let cb2: Callback<Sync> = self.cb.session();
+        assert_eq!(cb1, cb2);
+
+        // there is no channel outside of the browser so nothing is sent
+        self.cb.broadcast_session(Sync::LoggedOut);
+        self.cb.stop_session();
+        self.cb.broadcast_session(Sync::Renamed("bob".to_string()));
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
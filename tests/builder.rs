@@ -0,0 +1,74 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Debug, PartialEq, Callbacks)]
+pub enum Msg {
+    #[callbacks(builder)]
+    OnCell {
+        #[curry]
+        sheet: usize,
+        #[curry]
+        row: usize,
+        #[curry]
+        col: Option<usize>,
+        value: String,
+    },
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        // the keys can be given in any order, the callback is the same as the positional getter
+        let cb1: Callback<String> = self.cb.on_cell_builder().sheet(0).row(1).col(2).get();
+        let cb2: Callback<String> = self.cb.on_cell_builder().col(2).row(1).sheet(0).get();
+        assert_eq!(cb1, cb2);
+        assert_eq!(cb1, self.cb.on_cell(0, 1, 2));
+        let cb3: Callback<String> = self.cb.on_cell_builder().sheet(0).row(1).col(None).get();
+        assert_ne!(cb1, cb3);
+
+        let messages = Rc::new(RefCell::new(Vec::new()));
+        let cb: MsgCallbacks = MsgCallbacks::from_callback(Callback::from({
+            let messages = messages.clone();
+            move |msg| messages.borrow_mut().push(msg)
+        }));
+        cb.on_cell_builder()
+            .row(4)
+            .sheet(3)
+            .col(5)
+            .get()
+            .emit("a".to_string());
+        assert_eq!(
+            *messages.borrow(),
+            [Msg::OnCell {
+                sheet: 3,
+                row: 4,
+                col: Some(5),
+                value: "a".to_string(),
+            }],
+        );
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,74 @@
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+#[callbacks(by_name)]
+pub enum Msg {
+    OnClick(MouseEvent),
+    OnReset,
+    OnKeyPress(#[curry] usize, KeyboardEvent),
+    OnInput {
+        event: InputEvent,
+    },
+    SetName(String),
+    #[input(MouseEvent)]
+    Clicked(Point),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl From<MouseEvent> for Point {
+    fn from(event: MouseEvent) -> Self {
+        Self {
+            x: event.client_x(),
+            y: event.client_y(),
+        }
+    }
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        let cb1: Callback<Event> = self.cb.by_name("on_click").unwrap();
+        let cb2: Callback<Event> = self.cb.by_name("on_click").unwrap();
+        let cb3: Callback<Event> = self.cb.by_name("on_reset").unwrap();
+        let cb4: Callback<Event> = self.cb.by_name("on_input").unwrap();
+        assert_eq!(cb1, cb2);
+        assert_ne!(cb1, cb3);
+        assert_ne!(cb1, cb4);
+        assert_eq!(cb3, self.cb.by_name("on_reset").unwrap());
+        assert_eq!(cb4, self.cb.by_name("on_input").unwrap());
+
+        assert!(self.cb.by_name("on_key_press").is_none());
+        // only the callbacks that receive an event are available
+        assert!(self.cb.by_name("set_name").is_none());
+        assert!(self.cb.by_name("clicked").is_some());
+        assert!(self.cb.by_name("OnClick").is_none());
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,86 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+// `cached_reform()` is only generated when a variant is curried
+#[derive(Debug, PartialEq, Callbacks)]
+pub enum Msg {
+    OnSelect(usize),
+    OnRemove(#[curry] usize),
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Test {
+    fn select(&self, i: usize) -> Callback<MouseEvent> {
+        self.cb
+            .cached_reform(i, || self.cb.on_select().reform(move |_| i))
+    }
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        // nothing is kept before the first call
+        self.cb.clear_reforms();
+        assert_eq!(
+            format!("{:?}", self.cb),
+            "MsgCallbacks { OnSelect: not cached, OnRemove: 0 cached, .. }",
+        );
+
+        // the same line and key give the same callback
+        assert_eq!(self.select(1), self.select(1));
+        assert_ne!(self.select(1), self.select(2));
+
+        // but not another line, even with the same key
+        let cb1: Callback<MouseEvent> = self.select(1);
+        let cb2: Callback<MouseEvent> = self
+            .cb
+            .cached_reform(1, || self.cb.on_select().reform(|_| 1));
+        assert_ne!(cb1, cb2);
+
+        // the callbacks are kept until they are cleared
+        assert_eq!(
+            format!("{:?}", self.cb),
+            "MsgCallbacks { OnSelect: cached, OnRemove: 0 cached, cached_reform: 3 cached, .. }",
+        );
+        self.cb.clear_reforms();
+        assert_ne!(self.select(1), cb1);
+        assert_eq!(
+            format!("{:?}", self.cb),
+            "MsgCallbacks { OnSelect: cached, OnRemove: 0 cached, cached_reform: 1 cached, .. }",
+        );
+
+        let messages = Rc::new(RefCell::new(Vec::new()));
+        let cb: MsgCallbacks = MsgCallbacks::from_callback(Callback::from({
+            let messages = messages.clone();
+            move |msg| messages.borrow_mut().push(msg)
+        }));
+        for _ in 0..2 {
+            cb.cached_reform((), || cb.on_select().reform(|value: String| value.len()))
+                .emit("abc".to_string());
+        }
+        assert_eq!(*messages.borrow(), [Msg::OnSelect(3), Msg::OnSelect(3)]);
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,53 @@
+use yew::prelude::*;
+// the macro does not clash with the attribute `#[callbacks(...)]` of the derive
+use yew_callbacks::prelude::*;
+
+define_callbacks! {
+    #[derive(Debug)]
+    #[callbacks(kind)]
+    pub enum Msg {
+        OnClick(#[curry] usize, MouseEvent),
+        OnInput(String) = |event: InputEvent| {
+            event.target_unchecked_into::<web_sys::HtmlInputElement>().value()
+        },
+        #[callbacks(skip)]
+        Loaded(String),
+        OnReset(Event),
+    }
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let _: Callback<InputEvent> = self.cb.on_input();
+        assert_eq!(self.cb.on_input(), self.cb.on_input());
+        assert_eq!(Msg::Loaded(String::new()).kind(), MsgKind::Loaded);
+
+        html! {
+            <form onreset={self.cb.on_reset()}>
+                <input oninput={self.cb.on_input()} />
+                <button onclick={self.cb.on_click(0)} />
+            </form>
+        }
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
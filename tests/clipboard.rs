@@ -0,0 +1,57 @@
+#![cfg(feature = "clipboard")]
+
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[clipboard(paste)]
+    Pasted(String),
+    #[clipboard(paste)]
+    PastedFiles { files: Vec<web_sys::File> },
+    #[clipboard(write)]
+    Copied,
+    #[clipboard(write)]
+    CopyDone(bool),
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<Event> = self.cb.pasted();
+        let cb2: Callback<Event> = self.cb.pasted();
+        assert_eq!(cb1, cb2);
+
+        let cb3: Callback<Event> = self.cb.pasted_files();
+        let cb4: Callback<Event> = self.cb.pasted_files();
+        assert_eq!(cb3, cb4);
+
+        // there is no clipboard outside of the browser
+        self.cb.write_text_copied("hello");
+        self.cb.write_text_copy_done(String::from("world"));
+
+        html! {
+            <input onpaste={self.cb.pasted()} />
+        }
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "listen")]
+
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[connectivity]
+    Online(bool),
+    #[listen(window, "offline")]
+    Offline,
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<bool> = self.cb.online();
+        let cb2: Callback<bool> = self.cb.online();
+        assert_eq!(cb1, cb2);
+
+        self.cb.stop_online();
+        self.cb.stop_offline();
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
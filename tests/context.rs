@@ -0,0 +1,61 @@
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+#[callbacks(context)]
+pub enum Msg {
+    OnClick(MouseEvent),
+    OnKeyPress(#[curry] usize, KeyboardEvent),
+}
+
+struct Test {
+    cb: MsgCallbacksHandle<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        let cb1: Callback<MouseEvent> = self.cb.on_click();
+        let cb2: Callback<MouseEvent> = self.cb.clone().on_click();
+        assert_eq!(cb1, cb2);
+        assert!(self.cb == self.cb.clone());
+
+        html! {
+            <MsgCallbacksProvider<Self> context={self.cb.clone()}>
+                <div>
+                    <Child />
+                </div>
+            </MsgCallbacksProvider<Self>>
+        }
+    }
+}
+
+#[function_component]
+fn Child() -> Html {
+    let cb = use_msg_callbacks::<Test>().expect("context is provided");
+
+    let cb1: Callback<KeyboardEvent> = cb.on_key_press(0);
+    let cb2: Callback<KeyboardEvent> = cb.on_key_press(0);
+    assert_eq!(cb1, cb2);
+
+    html! {
+        <input onkeypress={cb1} />
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let html = renderer.render().await;
+        assert!(html.contains("<input"), "child was not rendered: {html}");
+    });
+}
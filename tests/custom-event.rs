@@ -0,0 +1,64 @@
+#![cfg(feature = "custom-event")]
+
+use serde::Deserialize;
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Debug, PartialEq, Deserialize)]
+pub struct Selection {
+    pub value: String,
+}
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[listen(custom = "my-widget:change", detail = Selection)]
+    Changed(Selection),
+    #[listen(document, custom = "my-widget:ready")]
+    Ready,
+    #[listen(node_ref, custom = "my-widget:select", detail = Vec<u32>)]
+    Selected(Vec<u32>),
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+    widget: NodeRef,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+            widget: NodeRef::default(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<Selection> = self.cb.changed();
+        let cb2: Callback<Selection> = self.cb.changed();
+        assert_eq!(cb1, cb2);
+
+        let cb3: Callback<()> = self.cb.ready();
+        let cb4: Callback<()> = self.cb.ready();
+        assert_eq!(cb3, cb4);
+
+        self.cb.listen_selected(&self.widget);
+        self.cb.stop_selected();
+        self.cb.stop_changed();
+        self.cb.stop_ready();
+
+        html! {
+            <div ref={self.widget.clone()} />
+        }
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
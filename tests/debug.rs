@@ -0,0 +1,51 @@
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    OnClick,
+    OnInput(InputEvent),
+    OnSelect(#[curry] usize, MouseEvent),
+}
+
+// the component does not implement `Debug`
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        assert_eq!(
+            format!("{:?}", self.cb),
+            "MsgCallbacks { OnClick: not cached, OnInput: not cached, OnSelect: 0 cached, .. }",
+        );
+
+        let _ = self.cb.on_click();
+        let _ = self.cb.on_select(1);
+        let _ = self.cb.on_select(2);
+        let _ = self.cb.on_select(2);
+        assert_eq!(
+            format!("{:?}", self.cb),
+            "MsgCallbacks { OnClick: cached, OnInput: not cached, OnSelect: 2 cached, .. }",
+        );
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,63 @@
+#![cfg(feature = "timers")]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Debug, PartialEq, Callbacks)]
+pub enum Msg {
+    #[deferred]
+    Search(String),
+    #[deferred]
+    Select(#[curry] usize, MouseEvent),
+    Clear,
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        let cb1: Callback<String> = self.cb.search();
+        let cb2: Callback<String> = self.cb.search();
+        assert_eq!(cb1, cb2);
+
+        let cb1: Callback<MouseEvent> = self.cb.select(1);
+        let cb2: Callback<MouseEvent> = self.cb.select(1);
+        assert_eq!(cb1, cb2);
+
+        let messages = Rc::new(RefCell::new(Vec::new()));
+        let cb: MsgCallbacks = MsgCallbacks::from_callback(Callback::from({
+            let messages = messages.clone();
+            move |msg| messages.borrow_mut().push(msg)
+        }));
+        // there are no timers outside of the browser, the messages are sent right away
+        cb.search().emit("rust".to_string());
+        cb.clear().emit(());
+        assert_eq!(
+            *messages.borrow(),
+            [Msg::Search("rust".to_string()), Msg::Clear],
+        );
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
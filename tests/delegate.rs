@@ -0,0 +1,56 @@
+#![cfg(feature = "delegate")]
+
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Debug, Callbacks)]
+pub enum Msg {
+    #[delegate("data-row")]
+    SelectRow(#[curry] usize, MouseEvent),
+    #[delegate("data-id")]
+    Remove {
+        #[curry]
+        id: String,
+        event: MouseEvent,
+    },
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<MouseEvent> = self.cb.select_row_delegated();
+        let cb2: Callback<MouseEvent> = self.cb.remove_delegated();
+
+        assert_eq!(cb1, self.cb.select_row_delegated());
+        assert_eq!(cb2, self.cb.remove_delegated());
+        assert_ne!(cb1, self.cb.select_row(0));
+
+        html! {
+            <table onclick={cb1}>
+                { for (0..3).map(|i| html! {
+                    <tr data-row={i.to_string()}>{ i }</tr>
+                }) }
+            </table>
+        }
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "diagnostics")]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Debug, PartialEq, Callbacks)]
+pub enum Msg {
+    OnClick,
+    OnSelect(#[curry] usize, String),
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        assert_eq!(self.cb.on_click(), self.cb.on_click());
+        assert_eq!(self.cb.on_select(1), self.cb.on_select(1));
+
+        let messages = Rc::new(RefCell::new(Vec::new()));
+        let cb: MsgCallbacks = MsgCallbacks::from_callback(Callback::from({
+            let messages = messages.clone();
+            move |msg| messages.borrow_mut().push(msg)
+        }));
+
+        // the keys that are never found again are only reported, and so are the thresholds
+        yew_callbacks::set_len_warnings([10, 100]);
+        for i in 0..2000 {
+            let _ = cb.on_select(i);
+        }
+
+        // and so are the messages sent after the cache has been dropped
+        let onclick = cb.on_click();
+        let onselect = cb.on_select(3);
+        drop(cb);
+        onclick.emit(());
+        onselect.emit("a".to_string());
+        onclick.emit(());
+        assert_eq!(
+            *messages.borrow(),
+            [
+                Msg::OnClick,
+                Msg::OnSelect(3, "a".to_string()),
+                Msg::OnClick
+            ],
+        );
+
+        // a cache built on every render is only reported too
+        for _ in 0..200 {
+            let cb = MsgCallbacks::from(ctx.link());
+            assert_ne!(cb.on_click(), self.cb.on_click());
+        }
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,50 @@
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+const BASE: u8 = 10;
+
+#[derive(Callbacks)]
+#[callbacks(kind)]
+#[repr(u8)]
+pub enum Msg {
+    OnClick(MouseEvent) = 1,
+    OnReset = 2,
+    OnSubmit = BASE + 1,
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        assert_eq!(MsgKind::OnClick as u8, 1);
+        assert_eq!(MsgKind::OnReset as u8, 2);
+        assert_eq!(MsgKind::OnSubmit as u8, 11);
+        assert_eq!(Msg::OnSubmit.kind(), MsgKind::OnSubmit);
+
+        let _: Callback<()> = self.cb.on_reset();
+        let _: Callback<()> = self.cb.on_submit();
+
+        html! {
+            <button onclick={self.cb.on_click()} />
+        }
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
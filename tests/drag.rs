@@ -0,0 +1,61 @@
+#![cfg(feature = "drag")]
+
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[data_transfer]
+    DragStarted(String),
+    #[data_transfer("application/json")]
+    DroppedJson { json: String },
+    #[drop_files]
+    DroppedFiles(Vec<web_sys::File>),
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<DragEvent> = self.cb.drag_started();
+        let cb2: Callback<DragEvent> = self.cb.drag_started();
+        assert_eq!(cb1, cb2);
+
+        let cb3: Callback<DragEvent> = self.cb.dropped_files();
+        let cb4: Callback<DragEvent> = self.cb.dropped_files();
+        assert_eq!(cb3, cb4);
+
+        let cb5: Callback<DragEvent> = self.cb.allow_drop();
+        let cb6: Callback<DragEvent> = self.cb.allow_drop();
+        assert_eq!(cb5, cb6);
+
+        html! {
+            <div
+                ondragover={self.cb.allow_drop()}
+                ondrop={self.cb.dropped_files()}
+            >
+                <span draggable="true" ondragstart={self.cb.drag_started()} />
+                <span ondrop={self.cb.dropped_json()} />
+            </div>
+        }
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
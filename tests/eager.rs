@@ -0,0 +1,56 @@
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+#[callbacks(eager)]
+pub enum Msg {
+    OnClick,
+    OnInput(InputEvent),
+    OnKeyPress(#[curry] usize, KeyboardEvent),
+    OnStuff { kb_event: KeyboardEvent },
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        let cb1: Callback<()> = self.cb.on_click();
+        let cb2: Callback<()> = self.cb.on_click();
+        assert_eq!(cb1, cb2);
+
+        let cb1: Callback<InputEvent> = self.cb.on_input();
+        let cb2: Callback<InputEvent> = self.cb.on_input();
+        assert_eq!(cb1, cb2);
+
+        let cb1: Callback<KeyboardEvent> = self.cb.on_key_press(0);
+        let cb2: Callback<KeyboardEvent> = self.cb.on_key_press(0);
+        let cb3: Callback<KeyboardEvent> = self.cb.on_key_press(1);
+        assert_eq!(cb1, cb2);
+        assert_ne!(cb1, cb3);
+
+        let cb1: Callback<KeyboardEvent> = self.cb.on_stuff();
+        let cb2: Callback<KeyboardEvent> = self.cb.on_stuff();
+        assert_eq!(cb1, cb2);
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,48 @@
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    OnClick(MouseEvent),
+    OnSelect(#[curry] usize, MouseEvent),
+}
+
+// a view helper shared by components of different types
+fn buttons(cb: &MsgCallbacks, count: usize) -> Html {
+    html! {
+        <>
+            <button onclick={cb.on_click()} />
+            { for (0..count).map(|i| html! { <button onclick={cb.on_select(i)} /> }) }
+        </>
+    }
+}
+
+struct Test {
+    cb: MsgCallbacks,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let cb = MsgCallbacks::from(ctx.link());
+        let on_click = cb.on_click();
+        let cb = cb.erase();
+        assert_eq!(cb.on_click(), on_click);
+
+        Self { cb }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        buttons(&self.cb, 3)
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "net")]
+
+use serde::Deserialize;
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Debug, Deserialize)]
+pub struct Progress {
+    pub done: u32,
+    pub total: u32,
+}
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[event_source("progress", json)]
+    Progress(Progress),
+    #[event_source("message")]
+    Message(String),
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<Progress> = self.cb.progress();
+        let cb2: Callback<Progress> = self.cb.progress();
+        assert_eq!(cb1, cb2);
+
+        let cb3: Callback<String> = self.cb.message();
+        let cb4: Callback<String> = self.cb.message();
+        assert_eq!(cb3, cb4);
+
+        // there are no event sources outside of the browser
+        self.cb.connect_event_source("/events");
+        self.cb.close_event_source();
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
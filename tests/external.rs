@@ -0,0 +1,82 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Debug, PartialEq, Callbacks)]
+pub enum Msg {
+    OnClick,
+    #[external]
+    OnSave(String),
+    #[external]
+    OnMove {
+        x: i32,
+        y: i32,
+    },
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        let saved = Rc::new(RefCell::new(Vec::new()));
+        let messages = Rc::new(RefCell::new(Vec::new()));
+        let cb: MsgCallbacks = MsgCallbacks::from_callback(Callback::from({
+            let messages = messages.clone();
+            move |msg| messages.borrow_mut().push(msg)
+        }));
+
+        // nothing is sent before the parent gives its callback
+        let onsave: Callback<String> = cb.on_save();
+        onsave.emit("lost".to_string());
+
+        cb.set_external_on_save(Callback::from({
+            let saved = saved.clone();
+            move |text: String| saved.borrow_mut().push(text)
+        }));
+        onsave.emit("a".to_string());
+        // the parent gives a new callback, the one of the getter stays the same
+        cb.set_external_on_save(Callback::from({
+            let saved = saved.clone();
+            move |text: String| saved.borrow_mut().push(text.to_uppercase())
+        }));
+        assert_eq!(onsave, cb.on_save());
+        onsave.emit("b".to_string());
+        cb.set_external_on_save(None::<Callback<String>>);
+        onsave.emit("lost".to_string());
+
+        cb.set_external_on_move(Callback::from({
+            let saved = saved.clone();
+            move |(x, y): (i32, i32)| saved.borrow_mut().push(format!("{x},{y}"))
+        }));
+        cb.on_move().emit((1, 2));
+        cb.on_click().emit(());
+
+        assert_eq!(*saved.borrow(), ["a", "B", "1,2"]);
+        assert_eq!(*messages.borrow(), [Msg::OnClick]);
+
+        // the getter of the cache of the component is cached as well
+        assert_eq!(self.cb.on_save(), self.cb.on_save());
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
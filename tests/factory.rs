@@ -0,0 +1,107 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Debug, PartialEq, Callbacks)]
+pub enum Msg {
+    OnSelect(#[curry] usize, String),
+    OnMove {
+        #[curry]
+        from: usize,
+        #[curry]
+        to: Option<usize>,
+    },
+}
+
+#[derive(PartialEq, Properties)]
+struct ListProps {
+    onselect: MsgCallbacksOnSelectFactory,
+}
+
+#[function_component]
+fn List(props: &ListProps) -> Html {
+    html! {
+        for (10..13).map(|i| {
+            assert_eq!(props.onselect.get(i), props.onselect.get(i));
+            html! { <span>{ i }</span> }
+        })
+    }
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        // the factories of a cache are equal and share their callbacks
+        let factory1: MsgCallbacksOnSelectFactory = self.cb.on_select_factory();
+        let factory2: MsgCallbacksOnSelectFactory = self.cb.on_select_factory();
+        assert_eq!(factory1, factory2);
+        assert_eq!(factory1, factory2.clone());
+        assert_eq!(factory1.get(1), factory2.get(1));
+        assert_ne!(factory1.get(1), factory2.get(2));
+        assert_eq!(
+            self.cb
+                .on_move_factory()
+                .get(MsgCallbacksOnMoveKey { from: 1, to: None }),
+            self.cb
+                .on_move_factory()
+                .get(MsgCallbacksOnMoveKey { from: 1, to: None }),
+        );
+
+        // the callbacks of the keys that are gone are created again
+        let cb1 = factory1.get(1);
+        factory1.retain(&[2]);
+        assert_ne!(cb1, factory1.get(1));
+
+        // and different when they come from different caches
+        let other = MsgCallbacks::<Self>::inert();
+        assert_ne!(other.on_select_factory(), factory1);
+
+        let messages = Rc::new(RefCell::new(Vec::new()));
+        let cb: MsgCallbacks = MsgCallbacks::from_callback(Callback::from({
+            let messages = messages.clone();
+            move |msg| messages.borrow_mut().push(msg)
+        }));
+        cb.on_select_factory().get(3).emit("a".to_string());
+        cb.on_move_factory()
+            .get(MsgCallbacksOnMoveKey {
+                from: 5,
+                to: Some(6),
+            })
+            .emit(());
+        assert_eq!(
+            *messages.borrow(),
+            [
+                Msg::OnSelect(3, "a".to_string()),
+                Msg::OnMove {
+                    from: 5,
+                    to: Some(6),
+                },
+            ],
+        );
+
+        html! {
+            <List onselect={factory1} />
+        }
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
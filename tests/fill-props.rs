@@ -0,0 +1,75 @@
+#![cfg(feature = "fill-props")]
+
+use yew::prelude::*;
+use yew_callbacks::{Callbacks, FillProps};
+
+#[derive(Callbacks)]
+pub enum Msg {
+    OnClick(MouseEvent),
+    OnKeyPress(KeyboardEvent),
+    OnReset(Event),
+    OnSelect(#[curry] usize, MouseEvent),
+}
+
+#[derive(Properties, PartialEq, FillProps)]
+#[fill_props(MsgCallbacks)]
+struct ChildProps {
+    onclick: Callback<MouseEvent>,
+    on_key_press: Callback<KeyboardEvent>,
+    #[prop_or_default]
+    onreset: Option<Callback<Event>>,
+    #[prop_or_default]
+    #[fill_props(skip)]
+    onblur: Option<Callback<FocusEvent>>,
+    #[prop_or_default]
+    label: String,
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        let props: ChildProps = self.cb.fill_props();
+        assert_eq!(props.onclick, self.cb.on_click());
+        assert_eq!(props.on_key_press, self.cb.on_key_press());
+        assert_eq!(props.onreset, Some(self.cb.on_reset()));
+        assert_eq!(props.onblur, None);
+        assert_eq!(props.label, "");
+
+        html! {
+            <Child ..ChildProps {
+                label: "Hello World!".to_string(),
+                ..self.cb.fill_props()
+            } />
+        }
+    }
+}
+
+#[function_component]
+fn Child(props: &ChildProps) -> Html {
+    html! {
+        <form onreset={props.onreset.clone()}>
+            <input onkeypress={props.on_key_press.clone()} />
+            <button onclick={props.onclick.clone()}>{ &props.label }</button>
+        </form>
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "forms")]
+
+use std::collections::HashMap;
+use yew::prelude::*;
+use yew_callbacks::{Callbacks, Form, FormError};
+
+#[derive(Debug, Default, PartialEq, Form)]
+pub struct Signup {
+    #[validate(check_email)]
+    email: String,
+    age: u32,
+}
+
+fn check_email(email: &str) -> Result<(), &'static str> {
+    if email.contains('@') {
+        Ok(())
+    } else {
+        Err("not an email address")
+    }
+}
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[form(Signup, error = Invalid)]
+    Edit(SignupEdit),
+    #[form(Signup, change)]
+    Change {
+        edit: SignupEdit,
+    },
+    #[form(Signup, submit, error = Invalid)]
+    Submit(Signup),
+    Invalid(FormError),
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        let cb1: Callback<InputEvent> = self.cb.edit(SignupField::Email);
+        let cb2: Callback<InputEvent> = self.cb.edit(SignupField::Email);
+        let cb3: Callback<InputEvent> = self.cb.edit(SignupField::Age);
+        assert_eq!(cb1, cb2);
+        assert_ne!(cb1, cb3);
+
+        let cb1: Callback<Event> = self.cb.change(SignupField::Age);
+        let cb2: Callback<Event> = self.cb.change(SignupField::Age);
+        assert_eq!(cb1, cb2);
+
+        let cb1: Callback<SubmitEvent> = self.cb.submit();
+        let cb2: Callback<SubmitEvent> = self.cb.submit();
+        assert_eq!(cb1, cb2);
+
+        html! {
+            <form onsubmit={self.cb.submit()}>
+                <input name="email" oninput={self.cb.edit(SignupField::Email)} />
+                <input name="age" onchange={self.cb.change(SignupField::Age)} />
+            </form>
+        }
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
+
+#[test]
+fn parse_and_apply() {
+    let mut signup = Signup::default();
+
+    let edit = Signup::parse(SignupField::Email, "me@example.com").unwrap();
+    assert_eq!(edit, SignupEdit::Email("me@example.com".to_string()));
+    signup.apply(edit);
+    signup.apply(Signup::parse(SignupField::Age, "42").unwrap());
+    assert_eq!(
+        signup,
+        Signup {
+            email: "me@example.com".to_string(),
+            age: 42,
+        },
+    );
+
+    let err = Signup::parse(SignupField::Email, "nobody").unwrap_err();
+    assert_eq!(err.field, "email");
+    assert_eq!(err.message, "not an email address");
+    let err = Signup::parse(SignupField::Age, "old").unwrap_err();
+    assert_eq!(err.field, "age");
+}
+
+#[test]
+fn from_values() {
+    let values = HashMap::from([("email", "me@example.com"), ("age", "42")]);
+    let signup = Signup::from_values(|name| values[name].to_string()).unwrap();
+    assert_eq!(
+        signup,
+        Signup {
+            email: "me@example.com".to_string(),
+            age: 42,
+        },
+    );
+
+    let err = Signup::from_values(|_| String::new()).unwrap_err();
+    assert_eq!(err.field, "email");
+}
@@ -0,0 +1,79 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+fn first_char(value: &str) -> Option<char> {
+    value.chars().next()
+}
+
+fn len(value: &str) -> usize {
+    value.len()
+}
+
+#[derive(Debug, PartialEq, Callbacks)]
+pub enum Msg {
+    OnSelect(#[curry(from_event = len)] usize, String),
+    OnRename {
+        #[curry]
+        id: u32,
+        #[curry(from_event = first_char)]
+        initial: Option<char>,
+        name: String,
+    },
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        // the key is not given to the getter, all the items share the same callback
+        let cb1: Callback<String> = self.cb.on_select();
+        let cb2: Callback<String> = self.cb.on_select();
+        assert_eq!(cb1, cb2);
+
+        let cb1: Callback<String> = self.cb.on_rename(1);
+        let cb2: Callback<String> = self.cb.on_rename(2);
+        assert_ne!(cb1, cb2);
+
+        let messages = Rc::new(RefCell::new(Vec::new()));
+        let cb: MsgCallbacks = MsgCallbacks::from_callback(Callback::from({
+            let messages = messages.clone();
+            move |msg| messages.borrow_mut().push(msg)
+        }));
+        cb.on_select().emit("abc".to_string());
+        cb.on_rename(7).emit("Ferris".to_string());
+        assert_eq!(
+            *messages.borrow(),
+            [
+                Msg::OnSelect(3, "abc".to_string()),
+                Msg::OnRename {
+                    id: 7,
+                    initial: Some('F'),
+                    name: "Ferris".to_string(),
+                },
+            ],
+        );
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
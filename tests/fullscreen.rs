@@ -0,0 +1,54 @@
+#![cfg(feature = "listen")]
+
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[listen(document, "fullscreenchange")]
+    FullscreenChanged(bool),
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+    player: NodeRef,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+            player: NodeRef::default(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<bool> = self.cb.fullscreen_changed();
+        let cb2: Callback<bool> = self.cb.fullscreen_changed();
+        assert_eq!(cb1, cb2);
+
+        self.cb.exit_fullscreen();
+        self.cb.stop_fullscreen_changed();
+
+        html! {
+            <video ref={self.player.clone()} />
+        }
+    }
+}
+
+// the element does not exist outside of the browser, this only checks the signature
+#[allow(dead_code)]
+fn request_fullscreen(cb: &MsgCallbacks<Test>, node_ref: &NodeRef) {
+    cb.request_fullscreen(node_ref);
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
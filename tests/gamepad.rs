@@ -0,0 +1,44 @@
+#![cfg(feature = "gamepad")]
+
+use yew::prelude::*;
+use yew_callbacks::{Callbacks, GamepadInput};
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[gamepad]
+    Gamepad(GamepadInput),
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<GamepadInput> = self.cb.gamepad();
+        let cb2: Callback<GamepadInput> = self.cb.gamepad();
+        assert_eq!(cb1, cb2);
+
+        // there are no gamepads outside of the browser
+        self.cb.stop_gamepad();
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
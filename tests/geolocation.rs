@@ -0,0 +1,52 @@
+#![cfg(feature = "geolocation")]
+
+use yew::prelude::*;
+use yew_callbacks::{Callbacks, GeolocationError, GeolocationPosition};
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[geolocation(watch)]
+    Moved(GeolocationPosition),
+    #[geolocation(watch)]
+    Tracked(Result<GeolocationPosition, GeolocationError>),
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<GeolocationPosition> = self.cb.moved();
+        let cb2: Callback<GeolocationPosition> = self.cb.moved();
+        assert_eq!(cb1, cb2);
+
+        // there is no geolocation outside of the browser
+        assert!(!self.cb.is_running_moved());
+        self.cb.start_moved();
+        self.cb.start_tracked();
+        assert!(!self.cb.is_running_moved());
+        assert!(!self.cb.is_running_tracked());
+        self.cb.stop_moved();
+        self.cb.stop_tracked();
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
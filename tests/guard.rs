@@ -0,0 +1,88 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Debug, PartialEq)]
+pub enum Role {
+    Viewer,
+    Editor,
+}
+
+fn can_edit(role: &Role) -> bool {
+    *role == Role::Editor
+}
+
+#[derive(Debug, PartialEq, Callbacks)]
+pub enum Msg {
+    #[guard(can_edit)]
+    Save,
+    #[guard(can_edit)]
+    Edit(#[curry] usize, String),
+    Select(usize),
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        // nothing is allowed without a context, the callbacks that do nothing are cached too
+        let noop: Callback<()> = self.cb.save();
+        assert_eq!(noop, self.cb.save());
+        assert_eq!(self.cb.edit(1), self.cb.edit(2));
+
+        self.cb.set_guard_context(Role::Editor);
+        let save = self.cb.save();
+        assert_ne!(save, noop);
+        assert_eq!(save, self.cb.save());
+        assert_eq!(*self.cb.save_ref(), save);
+        assert_ne!(self.cb.edit(1), self.cb.edit(2));
+
+        self.cb.set_guard_context(Role::Viewer);
+        assert_eq!(self.cb.save(), noop);
+
+        let messages = Rc::new(RefCell::new(Vec::new()));
+        let cb: MsgCallbacks = MsgCallbacks::from_callback(Callback::from({
+            let messages = messages.clone();
+            move |msg| messages.borrow_mut().push(msg)
+        }));
+        cb.set_guard_context(Role::Viewer);
+        cb.save().emit(());
+        cb.edit(1).emit("a".to_string());
+        cb.select().emit(2);
+        // the handles and the callbacks created before are checked when they send the message
+        let bound = cb.handle_edit(1);
+        cb.set_guard_context(Role::Editor);
+        let edit = cb.edit(3);
+        cb.set_guard_context(Role::Viewer);
+        bound.emit("b".to_string());
+        edit.emit("c".to_string());
+        cb.set_guard_context(Role::Editor);
+        bound.emit("d".to_string());
+        assert_eq!(
+            *messages.borrow(),
+            [Msg::Select(2), Msg::Edit(1, "d".to_string())],
+        );
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
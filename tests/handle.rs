@@ -0,0 +1,59 @@
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+#[callbacks(handle)]
+pub enum Msg {
+    OnClick(#[curry] usize, MouseEvent),
+    OnReset,
+}
+
+struct Test {
+    cb: MsgCallbacksHandle<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        let handle = self.cb.clone();
+        assert!(handle == self.cb);
+        assert_eq!(handle.on_reset(), self.cb.on_reset());
+        assert_eq!(handle.on_click(1), self.cb.on_click(1));
+
+        html! {
+            <>
+                <Child index={0} cb={self.cb.clone()} />
+                <Child index={1} cb={handle} />
+            </>
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct ChildProps {
+    index: usize,
+    cb: MsgCallbacksHandle<Test>,
+}
+
+#[function_component]
+fn Child(props: &ChildProps) -> Html {
+    html! {
+        <button onclick={props.cb.on_click(props.index)} />
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
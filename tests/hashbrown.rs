@@ -0,0 +1,48 @@
+#![cfg(feature = "hashbrown")]
+
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    OnSelect(#[curry] String, MouseEvent),
+    OnMove(#[curry] usize, #[curry] usize, MouseEvent),
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        let first = self.cb.on_select("a".to_string());
+        assert_eq!(first, self.cb.on_select("a".to_string()));
+        assert_ne!(first, self.cb.on_select("b".to_string()));
+        assert_eq!(self.cb.on_move(1, 2), self.cb.on_move(1, 2));
+        assert_eq!(
+            format!("{:?}", self.cb),
+            "MsgCallbacks { OnSelect: 2 cached, OnMove: 1 cached, .. }",
+        );
+
+        html! {
+            <button onclick={first} />
+        }
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
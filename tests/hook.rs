@@ -0,0 +1,48 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Callbacks)]
+#[callbacks(hook)]
+pub enum Msg {
+    Increment,
+    Add(#[curry] usize, MouseEvent),
+}
+
+#[function_component]
+fn Test() -> Html {
+    let cb: MsgCallbacksHandle = use_msg_callbacks_with(|msg| match msg {
+        Msg::Increment => {
+            COUNTER.fetch_add(1, Ordering::SeqCst);
+        }
+        Msg::Add(n, _) => {
+            COUNTER.fetch_add(n, Ordering::SeqCst);
+        }
+    });
+
+    let cb1: Callback<()> = cb.increment();
+    let cb2: Callback<()> = cb.increment();
+    assert_eq!(cb1, cb2);
+
+    let cb3: Callback<MouseEvent> = cb.add(1);
+    let cb4: Callback<MouseEvent> = cb.add(1);
+    let cb5: Callback<MouseEvent> = cb.add(2);
+    assert_eq!(cb3, cb4);
+    assert_ne!(cb3, cb5);
+
+    cb1.emit(());
+    cb1.emit(());
+
+    html! {}
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+    assert_eq!(COUNTER.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,67 @@
+//! The generated code must neither trip strict lints nor depend on the prelude.
+#![deny(warnings, missing_docs, unused_qualifications)]
+#![deny(clippy::pedantic)]
+#![allow(dead_code)]
+
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+// the names of the prelude are shadowed
+struct Option;
+struct Some;
+struct None;
+struct Default;
+struct From;
+struct Into;
+struct String;
+struct Box;
+struct Clone;
+struct PartialEq;
+struct Debug;
+
+/// The messages of the component.
+#[derive(Callbacks)]
+#[callbacks(by_name, kind, handle)]
+pub enum Msg {
+    /// Clicked.
+    OnClick,
+    /// Typed.
+    OnInput(InputEvent),
+    /// Selected.
+    OnSelect(#[curry] usize, MouseEvent),
+}
+
+struct Test {
+    cb: MsgCallbacksHandle<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<()> = self.cb.on_click();
+        let cb2: Callback<()> = self.cb.on_click();
+        assert_eq!(cb1, cb2);
+
+        let cb3: Callback<MouseEvent> = self.cb.on_select(0);
+        let cb4: Callback<MouseEvent> = self.cb.on_select(0);
+        assert_eq!(cb3, cb4);
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
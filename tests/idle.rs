@@ -0,0 +1,49 @@
+#![cfg(feature = "idle")]
+
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[idle]
+    Idle(f64),
+    #[idle(timeout = 200)]
+    Recompute(f64),
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<f64> = self.cb.idle();
+        let cb2: Callback<f64> = self.cb.idle();
+        assert_eq!(cb1, cb2);
+
+        // there are no idle periods outside of the browser
+        self.cb.start_recompute();
+        assert!(!self.cb.is_running_recompute());
+        self.cb.stop_recompute();
+        self.cb.stop_idle();
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,52 @@
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+fn parse(value: String) -> u32 {
+    value.parse().expect("not a number")
+}
+
+#[derive(Callbacks)]
+pub enum Msg {
+    OnClick(MouseEvent),
+    Select(#[curry] usize, String),
+    #[input(String, with = parse)]
+    Set(u32),
+}
+
+#[derive(Callbacks)]
+#[callbacks(eager)]
+pub enum Eager {
+    #[input(String, with = parse)]
+    Set(u32),
+}
+
+#[function_component]
+fn Preview() -> Html {
+    let cb = use_state(MsgCallbacks::<()>::inert);
+
+    html! {
+        <>
+            <button onclick={cb.on_click()} />
+            <button onclick={cb.select(0).reform(|_| String::new())} />
+        </>
+    }
+}
+
+#[test]
+fn emit_nothing() {
+    let cb = MsgCallbacks::<()>::inert();
+    cb.select(0).emit("first".to_string());
+    cb.set().emit("not a number".to_string());
+    assert_eq!(cb.select(0), cb.select(0));
+
+    let cb = EagerCallbacks::<()>::inert();
+    cb.set().emit("not a number".to_string());
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Preview>::new();
+        let _ = renderer.render().await;
+    });
+}
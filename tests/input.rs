@@ -0,0 +1,62 @@
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl From<MouseEvent> for Point {
+    fn from(event: MouseEvent) -> Self {
+        Self {
+            x: event.client_x(),
+            y: event.client_y(),
+        }
+    }
+}
+
+fn key(event: KeyboardEvent) -> String {
+    event.key()
+}
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[input(MouseEvent)]
+    Clicked(Point),
+    #[input(MouseEvent)]
+    Moved(#[curry] usize, Point),
+    #[input(KeyboardEvent, with = key)]
+    Pressed { key: String },
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        html! {
+            <div onclick={self.cb.clicked()} onkeydown={self.cb.pressed()}>
+                <span onmousemove={self.cb.moved(0)} />
+            </div>
+        }
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "observers")]
+
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[observe_intersection(threshold = 0.5)]
+    Visible(#[curry] usize, bool),
+    #[observe_intersection]
+    LoadMore(#[curry] String, bool),
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+    node_ref: NodeRef,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+            node_ref: NodeRef::default(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<bool> = self.cb.visible(1);
+        let cb2: Callback<bool> = self.cb.visible(1);
+        let cb3: Callback<bool> = self.cb.visible(2);
+        assert_eq!(cb1, cb2);
+        assert_ne!(cb1, cb3);
+
+        // the node is not rendered so there is nothing to observe
+        self.cb.observe_visible(1, &self.node_ref);
+        self.cb.unobserve_visible(&1);
+        self.cb
+            .observe_load_more("bottom".to_string(), &self.node_ref);
+        self.cb.unobserve_load_more(&"bottom".to_string());
+
+        html! {
+            <div ref={self.node_ref.clone()} />
+        }
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
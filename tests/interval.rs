@@ -0,0 +1,55 @@
+#![cfg(feature = "timers")]
+
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[interval(ms = 1000)]
+    Tick,
+    #[interval(ms = 250)]
+    Poll(u32),
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<()> = self.cb.tick();
+        let cb2: Callback<()> = self.cb.tick();
+        assert_eq!(cb1, cb2);
+
+        let cb3: Callback<u32> = self.cb.poll();
+        let cb4: Callback<u32> = self.cb.poll();
+        assert_eq!(cb3, cb4);
+
+        // there are no timers outside of the browser
+        assert!(!self.cb.is_running_tick());
+        self.cb.start_tick();
+        self.cb.stop_tick();
+        self.cb.stop_poll();
+        assert!(!self.cb.is_running_tick());
+        assert!(!self.cb.is_running_poll());
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,67 @@
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    OnCell {
+        #[curry]
+        sheet: Option<usize>,
+        #[curry]
+        row: usize,
+        #[curry]
+        col: usize,
+        value: String,
+    },
+    OnRename(#[curry] String, #[curry] usize, InputEvent),
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        let kept = self.cb.on_cell(0, 0, 0);
+        let _ = self.cb.on_cell(1, 0, 0);
+        let _ = self.cb.on_cell(1, 2, 3);
+        let _ = self.cb.on_cell(None, 2, 3);
+        self.cb.invalidate_on_cell_prefix(1);
+        assert_eq!(kept, self.cb.on_cell(0, 0, 0));
+        assert_eq!(
+            format!("{:?}", self.cb),
+            "MsgCallbacks { OnCell: 2 cached, OnRename: 0 cached, .. }",
+        );
+        self.cb.invalidate_on_cell_prefix(None);
+        self.cb.invalidate_on_cell_prefix(5);
+        assert_eq!(kept, self.cb.on_cell(0, 0, 0));
+
+        let kept = self.cb.on_rename("b".to_string(), 1);
+        let _ = self.cb.on_rename("a".to_string(), 0);
+        let _ = self.cb.on_rename("a".to_string(), 1);
+        self.cb.invalidate_on_rename_prefix("a".to_string());
+        assert_eq!(kept, self.cb.on_rename("b".to_string(), 1));
+        assert_eq!(
+            format!("{:?}", self.cb),
+            "MsgCallbacks { OnCell: 1 cached, OnRename: 1 cached, .. }",
+        );
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -4,8 +4,11 @@ use yew_callbacks::Callbacks;
 #[derive(Callbacks)]
 pub enum Msg {
     OnClick,
+    #[callbacks(tuple)]
     OnInput(InputEvent, KeyboardEvent),
+    #[callbacks(tuple)]
     OnKeyPress(#[curry] usize, KeyboardEvent, InputEvent, #[curry] String),
+    #[callbacks(tuple)]
     OnStuff {
         #[curry]
         index: usize,
@@ -14,6 +17,7 @@ pub enum Msg {
         #[curry]
         key: String,
     },
+    #[callbacks(tuple)]
     OnOtherStuff {
         kb_event: KeyboardEvent,
         input_event: InputEvent,
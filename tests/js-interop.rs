@@ -0,0 +1,62 @@
+#![cfg(feature = "js-interop")]
+
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[js]
+    Clicked,
+    #[js]
+    Zoomed(f64),
+    #[js]
+    Selected(String),
+    #[js(forget)]
+    MarkerClicked(#[curry] usize, MouseEvent),
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<f64> = self.cb.zoomed();
+        let cb2: Callback<f64> = self.cb.zoomed();
+        assert_eq!(cb1, cb2);
+
+        html! {}
+    }
+}
+
+// there is no JavaScript outside of the browser, this only checks the signatures
+#[allow(dead_code)]
+fn functions(cb: &MsgCallbacks<Test>) -> [js_sys::Function; 4] {
+    let functions = [
+        cb.clicked_js(),
+        cb.zoomed_js(),
+        cb.selected_js(),
+        cb.marker_clicked_js(1),
+    ];
+    cb.forget_marker_clicked_js(1);
+    cb.drop_selected_js();
+    cb.clear_js();
+    functions
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
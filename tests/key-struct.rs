@@ -0,0 +1,98 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Debug, PartialEq, Callbacks)]
+pub enum Msg {
+    OnCell {
+        #[curry]
+        sheet: usize,
+        #[curry]
+        row: usize,
+        #[curry]
+        col: Option<usize>,
+        value: String,
+    },
+    // a single key stays as it is
+    OnSheet {
+        #[curry]
+        sheet: usize,
+    },
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        let key = MsgCallbacksOnCellKey {
+            sheet: 0,
+            row: 1,
+            col: Some(2),
+        };
+        assert_eq!(
+            format!("{key:?}"),
+            "MsgCallbacksOnCellKey { sheet: 0, row: 1, col: Some(2) }",
+        );
+
+        // the getters of the keys and of the fields return the same callbacks
+        let cb1: Callback<String> = self.cb.on_cell_by_key(key.clone());
+        assert_eq!(cb1, self.cb.on_cell(0, 1, 2));
+        assert_ne!(cb1, self.cb.on_cell(0, 1, None));
+        let keyed = self
+            .cb
+            .on_cell_keyed([key.clone()])
+            .collect::<Vec<(MsgCallbacksOnCellKey, _)>>();
+        assert_eq!(keyed, [(key.clone(), cb1.clone())]);
+        assert_eq!(*self.cb.handle_on_cell(0, 1, 2).keys(), key);
+        let _: Callback<()> = self.cb.on_sheet(0);
+
+        self.cb.sync_on_cell_keys([&key]);
+        assert_eq!(
+            format!("{:?}", self.cb),
+            "MsgCallbacks { OnCell: 1 cached, OnSheet: 1 cached, .. }",
+        );
+
+        let messages = Rc::new(RefCell::new(Vec::new()));
+        let cb: MsgCallbacks = MsgCallbacks::from_callback(Callback::from({
+            let messages = messages.clone();
+            move |msg| messages.borrow_mut().push(msg)
+        }));
+        cb.on_cell_by_key(MsgCallbacksOnCellKey {
+            sheet: 3,
+            row: 4,
+            col: None,
+        })
+        .emit("a".to_string());
+        assert_eq!(
+            *messages.borrow(),
+            [Msg::OnCell {
+                sheet: 3,
+                row: 4,
+                col: None,
+                value: "a".to_string(),
+            }],
+        );
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,52 @@
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    OnClick(#[curry] usize, MouseEvent),
+    Shift(#[curry] usize, #[curry] String, MouseEvent),
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        let first = self.cb.on_click(0);
+        let keyed = self.cb.on_click_keyed(0..3).collect::<Vec<_>>();
+        assert_eq!(keyed.len(), 3);
+        assert_eq!(keyed[0], (0, first));
+        for (i, callback) in &keyed {
+            assert_eq!(*callback, self.cb.on_click(*i));
+        }
+
+        let moves = self
+            .cb
+            .shift_keyed([(1, "up".to_string()), (1, "down".to_string())])
+            .collect::<Vec<_>>();
+        assert_eq!(moves[1].1, self.cb.shift(1, "down".to_string()));
+
+        keyed
+            .into_iter()
+            .map(|(_, onclick)| html! { <button {onclick} /> })
+            .collect()
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
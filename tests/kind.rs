@@ -0,0 +1,64 @@
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+#[callbacks(kind)]
+pub enum Msg {
+    OnClick(MouseEvent),
+    OnReset,
+    OnKeyPress(#[curry] usize, KeyboardEvent),
+    OnInput { event: InputEvent },
+    SetName(String),
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        assert_eq!(
+            MsgKind::ALL,
+            [
+                MsgKind::OnClick,
+                MsgKind::OnReset,
+                MsgKind::OnKeyPress,
+                MsgKind::OnInput,
+                MsgKind::SetName,
+            ]
+        );
+        assert_eq!(MsgKind::OnKeyPress.name(), "on_key_press");
+        assert_eq!(Msg::OnReset.kind(), MsgKind::OnReset);
+
+        let cb1: Callback<Event> = self.cb.callback_for(MsgKind::OnClick).unwrap();
+        let cb2: Callback<Event> = self.cb.by_name("on_click").unwrap();
+        assert_eq!(cb1, cb2);
+
+        let callbacks = MsgKind::ALL
+            .iter()
+            .filter_map(|kind| self.cb.callback_for(*kind))
+            .count();
+        assert_eq!(callbacks, 3);
+        // the callback of `SetName` does not receive an event
+        assert!(self.cb.callback_for(MsgKind::SetName).is_none());
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "listen")]
+
+use yew::prelude::*;
+use yew_callbacks::{Callbacks, EventListenerOptions, ListenerHandle};
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[listen(window, "resize")]
+    Resized,
+    #[listen(document, "keydown")]
+    KeyDown(KeyboardEvent),
+    #[listen(node_ref, "scroll")]
+    Scrolled,
+    #[listen(node_ref, "transitionend")]
+    FadedOut(TransitionEvent),
+    #[attach("click")]
+    Clicked(MouseEvent),
+    #[attach("wheel")]
+    Zoomed(WheelEvent),
+    OnClick(#[curry] usize, MouseEvent),
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<()> = self.cb.resized();
+        let cb2: Callback<()> = self.cb.resized();
+        assert_eq!(cb1, cb2);
+
+        let cb3: Callback<KeyboardEvent> = self.cb.key_down();
+        let cb4: Callback<KeyboardEvent> = self.cb.key_down();
+        assert_eq!(cb3, cb4);
+
+        self.cb.stop_resized();
+        self.cb.stop_resized();
+        self.cb.stop_key_down();
+        assert_eq!(cb1, self.cb.resized());
+
+        // the element does not exist until the component is rendered
+        let node_ref = NodeRef::default();
+        self.cb.listen_scrolled(&node_ref);
+        self.cb.stop_scrolled();
+        self.cb.listen_faded_out(&node_ref);
+        self.cb.listen_faded_out(&node_ref);
+        assert!(self.cb.attach_zoomed_active(&node_ref).is_none());
+
+        html! {}
+    }
+}
+
+// there are no DOM nodes outside of the browser, this only checks the signature
+#[allow(dead_code)]
+fn attach_clicked(cb: &MsgCallbacks<Test>, target: &web_sys::EventTarget) -> ListenerHandle {
+    assert_eq!(cb.clicked(), cb.clicked());
+    cb.attach_clicked(target, EventListenerOptions::enable_prevent_default())
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "listen")]
+
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[media_query("(max-width: 600px)")]
+    Mobile(bool),
+    #[media_query("(prefers-color-scheme: dark)")]
+    DarkMode { enabled: bool },
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<bool> = self.cb.mobile();
+        let cb2: Callback<bool> = self.cb.mobile();
+        assert_eq!(cb1, cb2);
+
+        let cb3: Callback<bool> = self.cb.dark_mode();
+        let cb4: Callback<bool> = self.cb.dark_mode();
+        assert_eq!(cb3, cb4);
+
+        // there is no window to query outside of the browser
+        assert!(!self.cb.matches_mobile());
+        assert!(!self.cb.matches_dark_mode());
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,58 @@
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    OnClick(#[curry] Option<usize>, MouseEvent),
+    OnSelect {
+        #[curry]
+        row: Option<usize>,
+        #[curry]
+        column: usize,
+        event: MouseEvent,
+    },
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        assert_eq!(self.cb.on_click(1), self.cb.on_click(Some(1)));
+        assert_eq!(self.cb.on_click(None), self.cb.on_click(None));
+        assert_ne!(self.cb.on_click(None), self.cb.on_click(0));
+        assert_eq!(self.cb.on_select(None, 0), self.cb.on_select(None, 0));
+        assert_ne!(self.cb.on_select(None, 0), self.cb.on_select(0, 0));
+
+        html! {
+            <table>
+                <tr onclick={self.cb.on_click(None)}>
+                    <th onclick={self.cb.on_select(None, 0)} />
+                </tr>
+                { for (0..3).map(|i| html! {
+                    <tr onclick={self.cb.on_click(i)}>
+                        <td onclick={self.cb.on_select(i, 0)} />
+                    </tr>
+                }) }
+            </table>
+        }
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
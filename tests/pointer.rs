@@ -0,0 +1,56 @@
+#![cfg(feature = "pointer")]
+
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[capture]
+    Grab(PointerEvent),
+    #[capture]
+    Release(PointerEvent),
+    #[capture]
+    GrabHandle(#[curry] usize, PointerEvent),
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<PointerEvent> = self.cb.grab();
+        let cb2: Callback<PointerEvent> = self.cb.grab();
+        assert_eq!(cb1, cb2);
+
+        let cb3: Callback<PointerEvent> = self.cb.grab_handle(1);
+        let cb4: Callback<PointerEvent> = self.cb.grab_handle(1);
+        assert_eq!(cb3, cb4);
+        assert_ne!(cb3, self.cb.grab_handle(2));
+
+        assert!(!self.cb.has_pointer_capture());
+
+        html! {
+            <div onpointerdown={self.cb.grab()} onpointerup={self.cb.release()}>
+                <span onpointerdown={self.cb.grab_handle(0)} />
+            </div>
+        }
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "listen")]
+
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Debug, PartialEq)]
+pub enum Page {
+    Home,
+    Other(String),
+}
+
+fn parse_page(location: &str) -> Page {
+    match location {
+        "/" => Page::Home,
+        other => Page::Other(other.to_string()),
+    }
+}
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[popstate]
+    LocationChanged(String),
+    #[popstate(parse = parse_page)]
+    PageChanged(Page),
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<String> = self.cb.location_changed();
+        let cb2: Callback<String> = self.cb.location_changed();
+        assert_eq!(cb1, cb2);
+
+        let cb3: Callback<Page> = self.cb.page_changed();
+        let cb4: Callback<Page> = self.cb.page_changed();
+        assert_eq!(cb3, cb4);
+
+        self.cb.stop_location_changed();
+        self.cb.stop_page_changed();
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    assert_eq!(parse_page("/"), Page::Home);
+
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
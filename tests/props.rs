@@ -0,0 +1,52 @@
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+#[callbacks(props)]
+pub enum Msg {
+    OnClick(MouseEvent),
+    OnReset,
+    OnKeyPress(#[curry] usize, KeyboardEvent),
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        let props1: MsgCallbackProps = self.cb.props();
+        let props2: MsgCallbackProps = self.cb.props();
+        assert_eq!(props1, props2);
+        assert_eq!(props1.on_click, self.cb.on_click());
+        assert_eq!(props1.on_reset, self.cb.on_reset());
+
+        html! {
+            <Child ..props1 />
+        }
+    }
+}
+
+#[function_component]
+fn Child(props: &MsgCallbackProps) -> Html {
+    html! {
+        <button onclick={props.on_click.clone()} />
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
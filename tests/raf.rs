@@ -0,0 +1,46 @@
+#![cfg(feature = "render")]
+
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[raf]
+    Frame(f64),
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<f64> = self.cb.frame();
+        let cb2: Callback<f64> = self.cb.frame();
+        assert_eq!(cb1, cb2);
+
+        self.cb.start_frame();
+        self.cb.start_frame();
+        self.cb.stop_frame();
+        self.cb.stop_frame();
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,60 @@
+use std::rc::Rc;
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Action {
+    Increment,
+    Add(#[curry] usize, MouseEvent),
+}
+
+#[derive(Default, PartialEq)]
+struct Counter(usize);
+
+impl Reducible for Counter {
+    type Action = Action;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        match action {
+            Action::Increment => Self(self.0 + 1).into(),
+            Action::Add(n, _) => Self(self.0 + n).into(),
+        }
+    }
+}
+
+#[function_component]
+fn Test() -> Html {
+    let counter = use_reducer(Counter::default);
+    // the dependencies come first since Yew 0.21
+    #[cfg(not(all(feature = "yew-0_21", not(feature = "yew-0_20"))))]
+    let cb = use_memo(
+        |dispatcher| ActionCallbacks::from_dispatcher(dispatcher.clone()),
+        counter.dispatcher(),
+    );
+    #[cfg(all(feature = "yew-0_21", not(feature = "yew-0_20")))]
+    let cb = use_memo(counter.dispatcher(), |dispatcher| {
+        ActionCallbacks::from_dispatcher(dispatcher.clone())
+    });
+
+    let cb1: Callback<()> = cb.increment();
+    let cb2: Callback<()> = cb.increment();
+    assert_eq!(cb1, cb2);
+
+    let cb1: Callback<MouseEvent> = cb.add(1);
+    let cb2: Callback<MouseEvent> = cb.add(1);
+    let cb3: Callback<MouseEvent> = cb.add(2);
+    assert_eq!(cb1, cb2);
+    assert_ne!(cb1, cb3);
+
+    let _: ActionCallbacks = counter.dispatcher().into();
+
+    html! {}
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
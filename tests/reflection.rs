@@ -0,0 +1,40 @@
+use yew::prelude::*;
+use yew_callbacks::{CallbackInfo, Callbacks};
+
+#[derive(Callbacks)]
+pub enum Msg {
+    OnClick(MouseEvent),
+    OnReset,
+    Drag(#[curry] usize, #[curry] Option<String>, MouseEvent),
+    #[callbacks(skip)]
+    Loaded(String),
+}
+
+#[test]
+fn callbacks() {
+    assert_eq!(
+        MsgCallbacks::<()>::CALLBACKS,
+        [
+            CallbackInfo {
+                variant: "OnClick",
+                getter: "on_click",
+                curried: &[],
+                input: "MouseEvent",
+            },
+            CallbackInfo {
+                variant: "OnReset",
+                getter: "on_reset",
+                curried: &[],
+                input: "()",
+            },
+            CallbackInfo {
+                variant: "Drag",
+                getter: "drag",
+                curried: &["usize", "Option<String>"],
+                input: "MouseEvent",
+            },
+        ],
+    );
+    assert!(!MsgCallbacks::<()>::CALLBACKS[0].is_curried());
+    assert!(MsgCallbacks::<()>::CALLBACKS[2].is_curried());
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "registry")]
+
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Debug, Callbacks)]
+pub enum Msg {
+    OnSelect(#[curry] usize, MouseEvent),
+    OnReset,
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let first = MsgCallbacks::<Self>::reclaim(ctx.link().clone(), &1);
+        let cb1: Callback<MouseEvent> = first.on_select(3);
+        let cb2: Callback<()> = first.on_reset();
+        first.store(1);
+
+        // the callbacks of the stored cache are reclaimed
+        let second = MsgCallbacks::<Self>::reclaim(ctx.link().clone(), &1);
+        assert_eq!(second.on_select(3), cb1);
+        assert_eq!(second.on_reset(), cb2);
+        assert!(!MsgCallbacks::<Self>::evict(&1));
+
+        // another key has its own cache
+        let other = MsgCallbacks::<Self>::reclaim(ctx.link().clone(), &2);
+        assert_ne!(other.on_select(3), cb1);
+
+        second.store(1);
+        other.store(2);
+        assert!(MsgCallbacks::<Self>::evict(&1));
+        let third = MsgCallbacks::<Self>::reclaim(ctx.link().clone(), &1);
+        assert_ne!(third.on_select(3), cb1);
+
+        MsgCallbacks::<Self>::evict_all();
+        assert!(!MsgCallbacks::<Self>::evict(&2));
+
+        third.store(1);
+        yew_callbacks::clear_registry();
+        assert!(!MsgCallbacks::<Self>::evict(&1));
+
+        html! {
+            <button onclick={self.cb.on_select(0)} />
+        }
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,54 @@
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+// stands for a crate that does not depend on yew-callbacks
+mod protocol {
+    use yew::prelude::*;
+
+    #[allow(dead_code)]
+    pub enum Msg {
+        OnClick(usize, MouseEvent),
+        OnReset(Event),
+        Renamed { name: String },
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Callbacks)]
+#[callbacks(remote = "protocol::Msg")]
+enum Msg {
+    OnClick(#[curry] usize, MouseEvent),
+    OnReset(Event),
+    Renamed { name: String },
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = protocol::Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let _: Callback<String> = self.cb.renamed();
+
+        html! {
+            <button onclick={self.cb.on_click(0)} onreset={self.cb.on_reset()} />
+        }
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
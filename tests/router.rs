@@ -0,0 +1,121 @@
+#![cfg(feature = "router")]
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+use yew_router::history::{AnyHistory, History, MemoryHistory};
+use yew_router::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Routable)]
+pub enum Route {
+    #[at("/")]
+    Home,
+    #[at("/game/:id")]
+    Game { id: usize },
+    #[at("/about")]
+    About,
+}
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[navigate(Route::About)]
+    About,
+    #[navigate(Route::Home, silent)]
+    GoHome(MouseEvent),
+    #[navigate(Route::Home, silent)]
+    Reset,
+    OpenGame(
+        #[curry]
+        #[navigate]
+        Route,
+        MouseEvent,
+    ),
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+    navigated: Cell<bool>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+            navigated: Cell::new(false),
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let cb1: Callback<()> = self.cb.about();
+        let cb2: Callback<()> = self.cb.about();
+        assert_eq!(cb1, cb2);
+
+        let cb3: Callback<MouseEvent> = self.cb.go_home();
+        let cb4: Callback<MouseEvent> = self.cb.go_home();
+        assert_eq!(cb3, cb4);
+
+        let cb5: Callback<MouseEvent> = self.cb.open_game(Route::Game { id: 1 });
+        let cb6: Callback<MouseEvent> = self.cb.open_game(Route::Game { id: 1 });
+        let cb7: Callback<MouseEvent> = self.cb.open_game(Route::Game { id: 2 });
+        assert_eq!(cb5, cb6);
+        assert_ne!(cb5, cb7);
+
+        // the router renders again after the navigation
+        if !self.navigated.replace(true) {
+            let messages = Rc::new(RefCell::new(Vec::new()));
+            let cb: MsgCallbacks = MsgCallbacks::from_callback(Callback::from({
+                let messages = messages.clone();
+                move |msg| {
+                    messages.borrow_mut().push(match msg {
+                        Msg::About => "About",
+                        Msg::GoHome(_) => "GoHome",
+                        Msg::Reset => "Reset",
+                        Msg::OpenGame(..) => "OpenGame",
+                    })
+                }
+            }));
+            cb.set_navigator(ctx.link().navigator().unwrap());
+
+            // a silent variant only pushes the route
+            cb.reset().emit(());
+            assert!(messages.borrow().is_empty());
+            cb.about().emit(());
+            assert_eq!(*messages.borrow(), ["About"]);
+
+            cb1.emit(());
+        }
+
+        html! {}
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct AppProps {
+    history: AnyHistory,
+}
+
+#[function_component]
+fn App(props: &AppProps) -> Html {
+    html! {
+        <Router history={props.history.clone()}>
+            <Test />
+        </Router>
+    }
+}
+
+#[test]
+fn run_tests() {
+    let history = AnyHistory::from(MemoryHistory::new());
+
+    futures::executor::block_on(async {
+        let renderer = yew::LocalServerRenderer::<App>::with_props(AppProps {
+            history: history.clone(),
+        });
+        let _ = renderer.render().await;
+    });
+    assert_eq!(history.location().path(), "/about");
+}
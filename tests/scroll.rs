@@ -0,0 +1,56 @@
+#![cfg(feature = "scroll")]
+
+use yew::prelude::*;
+use yew_callbacks::{Callbacks, ScrollPosition};
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[scroll(throttle = 100)]
+    Scrolled(ScrollPosition),
+    #[scroll(node_ref, throttle = 50)]
+    ListScrolled(ScrollPosition),
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+    list_ref: NodeRef,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+            list_ref: NodeRef::default(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<ScrollPosition> = self.cb.scrolled();
+        let cb2: Callback<ScrollPosition> = self.cb.scrolled();
+        assert_eq!(cb1, cb2);
+
+        let cb3: Callback<ScrollPosition> = self.cb.list_scrolled();
+        let cb4: Callback<ScrollPosition> = self.cb.list_scrolled();
+        assert_eq!(cb3, cb4);
+
+        // the node is not rendered during SSR
+        self.cb.listen_list_scrolled(&self.list_ref);
+        self.cb.stop_list_scrolled();
+        self.cb.stop_scrolled();
+
+        html! {
+            <ul ref={self.list_ref.clone()} />
+        }
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "sensors")]
+
+use yew::prelude::*;
+use yew_callbacks::{Callbacks, DeviceMotion, DeviceOrientation};
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[device_orientation]
+    Oriented(DeviceOrientation),
+    #[device_motion]
+    Moved(DeviceMotion),
+    PermissionGranted(bool),
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<DeviceOrientation> = self.cb.oriented();
+        let cb2: Callback<DeviceOrientation> = self.cb.oriented();
+        assert_eq!(cb1, cb2);
+
+        let cb3: Callback<DeviceMotion> = self.cb.moved();
+        let cb4: Callback<DeviceMotion> = self.cb.moved();
+        assert_eq!(cb3, cb4);
+
+        // there are no sensors outside of the browser
+        let granted = self.cb.permission_granted();
+        self.cb
+            .request_permission_oriented(move |allowed| granted.emit(allowed));
+        self.cb.stop_oriented();
+        self.cb.stop_moved();
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "service-worker")]
+
+use serde::Deserialize;
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(tag = "type")]
+pub enum WorkerMessage {
+    UpdateAvailable { version: String },
+}
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[listen(service_worker, "message")]
+    FromServiceWorker(WorkerMessage),
+    #[listen(service_worker, "controllerchange")]
+    ControllerChanged,
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<WorkerMessage> = self.cb.from_service_worker();
+        let cb2: Callback<WorkerMessage> = self.cb.from_service_worker();
+        assert_eq!(cb1, cb2);
+
+        let cb3: Callback<()> = self.cb.controller_changed();
+        let cb4: Callback<()> = self.cb.controller_changed();
+        assert_eq!(cb3, cb4);
+
+        // there is no service worker outside of the browser
+        self.cb.stop_from_service_worker();
+        self.cb.stop_controller_changed();
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
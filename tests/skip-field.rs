@@ -0,0 +1,60 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Debug, PartialEq, Callbacks)]
+pub enum Msg {
+    OnClick(MouseEvent, #[skip] u32),
+    OnSelect(#[curry] usize, #[skip(true)] bool),
+    OnReset {
+        event: Event,
+        #[skip(String::from("form"))]
+        origin: String,
+    },
+    Refresh(#[skip] Option<u64>),
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let cb = MsgCallbacks::<()>::from(Callback::from({
+            let received = received.clone();
+            move |msg| received.borrow_mut().push(msg)
+        }));
+        cb.on_select(1).emit(());
+        cb.refresh().emit(());
+        assert_eq!(
+            *received.borrow(),
+            [Msg::OnSelect(1, true), Msg::Refresh(None)],
+        );
+
+        html! {
+            <form onreset={self.cb.on_reset()}>
+                <button onclick={self.cb.on_click()} />
+                <button onclick={self.cb.on_select(0).reform(|_| ())} />
+            </form>
+        }
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,50 @@
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+#[callbacks(kind)]
+pub enum Msg {
+    OnClick(MouseEvent),
+    #[callbacks(skip)]
+    Loaded(#[curry] Box<dyn std::any::Any>, String),
+}
+
+#[derive(Callbacks)]
+pub enum Task {
+    Done(Box<dyn FnOnce() -> String>),
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        assert!(self.cb.callback_for(MsgKind::Loaded).is_none());
+        let tasks = TaskCallbacks::<()>::from(Callback::from(|task: Task| match task {
+            Task::Done(f) => assert_eq!(f(), "done"),
+        }));
+        tasks.done().emit(Box::new(|| "done".to_string()));
+
+        html! {
+            <button onclick={self.cb.on_click()} />
+        }
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
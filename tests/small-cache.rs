@@ -0,0 +1,57 @@
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    OnSelect(#[curry] usize, MouseEvent),
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        let small = (0..3).map(|i| self.cb.on_select(i)).collect::<Vec<_>>();
+        for (i, callback) in small.iter().enumerate() {
+            assert_eq!(*callback, self.cb.on_select(i));
+        }
+
+        // past a few callbacks they are moved into a map, the cached ones are kept
+        let large = (0..20).map(|i| self.cb.on_select(i)).collect::<Vec<_>>();
+        assert_eq!(large[..3], small[..]);
+        for (i, callback) in large.iter().enumerate() {
+            assert_eq!(*callback, self.cb.on_select(i));
+        }
+        assert_eq!(
+            format!("{:?}", self.cb),
+            "MsgCallbacks { OnSelect: 20 cached, .. }",
+        );
+
+        self.cb.sync_on_select_keys(&[1, 19]);
+        assert_eq!(large[19], self.cb.on_select(19));
+        assert_eq!(
+            format!("{:?}", self.cb),
+            "MsgCallbacks { OnSelect: 2 cached, .. }",
+        );
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,104 @@
+#![cfg(feature = "small-code")]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Debug, PartialEq, Callbacks)]
+pub enum Msg {
+    OnReset,
+    OnRename(String),
+    #[callbacks(tuple)]
+    OnResize(u32, u32),
+    OnScroll {
+        top: i32,
+        #[skip]
+        smooth: bool,
+    },
+    OnSelect(#[curry] usize, String),
+}
+
+#[derive(Debug, PartialEq, Callbacks)]
+#[callbacks(eager)]
+pub enum EagerMsg {
+    OnReset,
+    OnRename(String),
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        let cb1: Callback<()> = self.cb.on_reset();
+        let cb2: Callback<()> = self.cb.on_reset();
+        assert_eq!(cb1, cb2);
+
+        let cb1: Callback<String> = self.cb.on_rename();
+        let cb2: Callback<String> = self.cb.on_rename();
+        assert_eq!(cb1, cb2);
+
+        let cb1: Callback<String> = self.cb.on_select(0);
+        let cb2: Callback<String> = self.cb.on_select(1);
+        assert_ne!(cb1, cb2);
+
+        // the variants sharing the same function still get their own messages
+        let messages = Rc::new(RefCell::new(Vec::new()));
+        let cb: MsgCallbacks = MsgCallbacks::from_callback(Callback::from({
+            let messages = messages.clone();
+            move |msg| messages.borrow_mut().push(msg)
+        }));
+        cb.on_reset().emit(());
+        cb.on_rename().emit("a".to_string());
+        cb.on_resize().emit((640, 480));
+        cb.on_scroll().emit(12);
+        cb.on_select(3).emit("b".to_string());
+        assert_eq!(
+            *messages.borrow(),
+            [
+                Msg::OnReset,
+                Msg::OnRename("a".to_string()),
+                Msg::OnResize(640, 480),
+                Msg::OnScroll {
+                    top: 12,
+                    smooth: false
+                },
+                Msg::OnSelect(3, "b".to_string()),
+            ],
+        );
+
+        let messages = Rc::new(RefCell::new(Vec::new()));
+        let cb: EagerMsgCallbacks = EagerMsgCallbacks::from_callback(Callback::from({
+            let messages = messages.clone();
+            move |msg| messages.borrow_mut().push(msg)
+        }));
+        assert_eq!(cb.on_rename(), cb.on_rename());
+        cb.on_rename().emit("c".to_string());
+        cb.on_reset().emit(());
+        assert_eq!(
+            *messages.borrow(),
+            [EagerMsg::OnRename("c".to_string()), EagerMsg::OnReset],
+        );
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
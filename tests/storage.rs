@@ -0,0 +1,57 @@
+#![cfg(feature = "storage")]
+
+use serde::Deserialize;
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Debug, Deserialize)]
+pub struct Entry {
+    pub description: String,
+    pub completed: bool,
+}
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[storage("yew.todomvc.self")]
+    EntriesChanged(Option<Vec<Entry>>),
+    #[storage("yew.todomvc.filter")]
+    FilterChanged(String),
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<Option<Vec<Entry>>> = self.cb.entries_changed();
+        let cb2: Callback<Option<Vec<Entry>>> = self.cb.entries_changed();
+        assert_eq!(cb1, cb2);
+
+        let cb3: Callback<String> = self.cb.filter_changed();
+        let cb4: Callback<String> = self.cb.filter_changed();
+        assert_eq!(cb3, cb4);
+
+        self.cb.stop_entries_changed();
+        self.cb.stop_filter_changed();
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
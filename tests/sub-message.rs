@@ -0,0 +1,55 @@
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum ToolbarMsg {
+    OnSave,
+    OnSearch(#[curry] usize, InputEvent),
+}
+
+pub enum Msg {
+    Toolbar(ToolbarMsg),
+}
+
+impl From<ToolbarMsg> for Msg {
+    fn from(msg: ToolbarMsg) -> Self {
+        Self::Toolbar(msg)
+    }
+}
+
+struct Test {
+    toolbar: ToolbarMsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            toolbar: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        let cb1: Callback<()> = self.toolbar.on_save();
+        let cb2: Callback<()> = self.toolbar.on_save();
+        assert_eq!(cb1, cb2);
+
+        let cb1: Callback<InputEvent> = self.toolbar.on_search(0);
+        let cb2: Callback<InputEvent> = self.toolbar.on_search(0);
+        let cb3: Callback<InputEvent> = self.toolbar.on_search(1);
+        assert_eq!(cb1, cb2);
+        assert_ne!(cb1, cb3);
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
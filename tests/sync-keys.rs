@@ -0,0 +1,55 @@
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    OnSelect(#[curry] usize, MouseEvent),
+    OnRename(#[curry] String, #[curry] usize, InputEvent),
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+    items: Vec<usize>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+            items: vec![1, 3],
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        self.cb.warm_on_select(0..5);
+        let kept = self.cb.on_select(3);
+        self.cb.sync_on_select_keys(&self.items);
+        assert_eq!(
+            format!("{:?}", self.cb),
+            "MsgCallbacks { OnSelect: 2 cached, OnRename: 0 cached, .. }",
+        );
+        assert_eq!(kept, self.cb.on_select(3));
+
+        let _ = self.cb.on_rename("a".to_string(), 0);
+        let _ = self.cb.on_rename("b".to_string(), 1);
+        self.cb.sync_on_rename_keys([&("b".to_string(), 1)]);
+        self.cb.sync_on_select_keys([]);
+        assert_eq!(
+            format!("{:?}", self.cb),
+            "MsgCallbacks { OnSelect: 0 cached, OnRename: 1 cached, .. }",
+        );
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,116 @@
+#![cfg(feature = "test-util")]
+
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Debug, Callbacks)]
+pub enum Msg {
+    OnClick(#[curry] usize, MouseEvent),
+    OnKeyDown(KeyboardEvent),
+    OnSubmit(SubmitEvent),
+    OnReset(Event),
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+    clicks: usize,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+            clicks: 0,
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Msg) -> bool {
+        match msg {
+            Msg::OnClick(..) => {
+                self.clicks += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        html! {
+            <form onsubmit={self.cb.on_submit()} onreset={self.cb.on_reset()}>
+                <button onclick={self.cb.on_click(0)} onkeydown={self.cb.on_key_down()} />
+                <span>{ self.clicks }</span>
+            </form>
+        }
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
+
+// the events can only be created and the component mounted in a browser
+#[cfg(target_arch = "wasm32")]
+mod browser {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn simulate() {
+        let messages = Rc::new(RefCell::new(Vec::new()));
+        let cb: MsgCallbacks = MsgCallbacks::from_callback(Callback::from({
+            let messages = messages.clone();
+            move |msg| messages.borrow_mut().push(msg)
+        }));
+
+        cb.simulate_on_click(3);
+        cb.simulate_on_key_down();
+        cb.simulate_on_submit();
+
+        let messages = messages.borrow();
+        assert_eq!(messages.len(), 3);
+        assert!(
+            matches!(&messages[0], Msg::OnClick(3, event) if event.type_() == "click"),
+            "{:?}",
+            messages[0],
+        );
+        assert!(
+            matches!(&messages[1], Msg::OnKeyDown(event) if event.type_() == "keydown"),
+            "{:?}",
+            messages[1],
+        );
+        assert!(
+            matches!(&messages[2], Msg::OnSubmit(event) if event.type_() == "submit"),
+            "{:?}",
+            messages[2],
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn mount() {
+        let app = MsgCallbacks::<Test>::test_mount(());
+        app.settle().await;
+        assert_eq!(app.root().child_element_count(), 1);
+        assert_eq!(app.query("span").text_content().as_deref(), Some("0"));
+
+        // the callback of `view()` is called by the browser
+        app.dispatch("button", &MouseEvent::new("click").unwrap());
+        app.settle().await;
+        assert_eq!(app.query("span").text_content().as_deref(), Some("1"));
+
+        app.simulate_on_click(0);
+        app.simulate_on_submit();
+        app.settle().await;
+        assert_eq!(app.query("span").text_content().as_deref(), Some("2"));
+    }
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "timers")]
+
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[timeout]
+    Dismiss,
+    #[timeout]
+    Undo(usize),
+    #[timeout]
+    Shift { x: i32, y: i32 },
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<()> = self.cb.dismiss();
+        let cb2: Callback<usize> = self.cb.undo();
+        let cb3: Callback<(i32, i32)> = self.cb.shift();
+
+        self.cb.schedule_dismiss(3000);
+        self.cb.schedule_dismiss(5000);
+        self.cb.schedule_undo(5000, 1);
+        self.cb.schedule_shift(100, (1, 2));
+        self.cb.cancel_dismiss();
+        self.cb.cancel_undo();
+        self.cb.cancel_shift();
+
+        assert_eq!(cb1, self.cb.dismiss());
+        assert_eq!(cb2, self.cb.undo());
+        assert_eq!(cb3, self.cb.shift());
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "ttl")]
+
+use std::time::Duration;
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    OnSelect(#[curry(ttl = 50ms)] usize, MouseEvent),
+    OnPanel {
+        #[curry]
+        panel: usize,
+        #[curry(ttl = 1h)]
+        tab: usize,
+    },
+    OnClose(#[curry] usize),
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        let cb1: Callback<MouseEvent> = self.cb.on_select(1);
+        let _ = self.cb.on_select(2);
+        let _ = self.cb.on_panel(1, 2);
+        let _ = self.cb.on_close(1);
+        assert_eq!(cb1, self.cb.on_select(1));
+
+        // the callbacks that have not been used for the `ttl` are dropped when another one is
+        // created
+        std::thread::sleep(Duration::from_millis(100));
+        let _ = self.cb.on_select(2);
+        let _ = self.cb.on_select(3);
+        assert_eq!(
+            format!("{:?}", self.cb),
+            "MsgCallbacks { OnSelect: 2 cached, OnPanel: 1 cached, OnClose: 1 cached, .. }",
+        );
+        assert_ne!(cb1, self.cb.on_select(1));
+
+        // or right away by `sweep()`, the variants without `ttl` are kept
+        std::thread::sleep(Duration::from_millis(100));
+        self.cb.sweep();
+        assert_eq!(
+            format!("{:?}", self.cb),
+            "MsgCallbacks { OnSelect: 0 cached, OnPanel: 1 cached, OnClose: 1 cached, .. }",
+        );
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+#[callbacks(eager = true)]
+enum Msg {
+    Reset,
+}
+
+fn main() {}
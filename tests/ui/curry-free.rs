@@ -0,0 +1,32 @@
+#![allow(dead_code)]
+
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+// without curried variants there is no map in the cache, nor the methods that need one
+#[derive(Callbacks)]
+enum Msg {
+    OnClick(MouseEvent),
+    OnSelect(usize),
+}
+
+struct Test;
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(_: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let cb = MsgCallbacks::new(ctx.link().clone());
+        let onclick = cb.cached_reform((), || cb.on_select().reform(|_| 1));
+        let cb: MsgCallbacks = cb.erase();
+
+        html! { <button {onclick} /> }
+    }
+}
+
+fn main() {}
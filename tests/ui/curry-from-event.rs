@@ -0,0 +1,14 @@
+#![allow(dead_code)]
+
+use yew_callbacks::Callbacks;
+
+fn index(_: &(String, String)) -> usize {
+    0
+}
+
+#[derive(Callbacks)]
+enum Msg {
+    Select(#[curry(from_event = index)] usize, String, String),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#![allow(dead_code)]
+
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+enum Msg {
+    Loaded(#[curry] Box<dyn std::any::Any>, String),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#![allow(dead_code)]
+
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+enum Msg<T> {
+    Value(T),
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#![allow(dead_code)]
+
+use yew_callbacks::Callbacks;
+
+mod protocol {
+    pub enum Msg {
+        Reset,
+    }
+}
+
+#[derive(Callbacks)]
+#[callbacks(kind, remote = "protocol::Msg")]
+enum Msg {
+    Reset,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+enum Msg {
+    #[callbacks(skip, eager)]
+    Reset,
+}
+
+fn main() {}
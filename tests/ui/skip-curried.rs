@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+enum Msg {
+    OnClick(#[curry] #[skip] usize, MouseEvent),
+}
+
+fn main() {}
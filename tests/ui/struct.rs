@@ -0,0 +1,8 @@
+#![allow(dead_code)]
+
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+struct Msg;
+
+fn main() {}
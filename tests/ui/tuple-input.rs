@@ -0,0 +1,12 @@
+#![allow(dead_code)]
+#![deny(deprecated)]
+
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+enum Msg {
+    OnInput(InputEvent, KeyboardEvent),
+}
+
+fn main() {}
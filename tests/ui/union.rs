@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+union Msg {
+    a: u32,
+    b: f32,
+}
+
+fn main() {}
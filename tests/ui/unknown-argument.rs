@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+#[callbacks(eagr)]
+enum Msg {
+    Reset,
+}
+
+fn main() {}
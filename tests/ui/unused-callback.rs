@@ -0,0 +1,16 @@
+#![allow(dead_code)]
+#![deny(unused_must_use)]
+
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+enum Msg {
+    OnClick(#[curry] usize, MouseEvent),
+}
+
+fn wire(cb: &MsgCallbacks) {
+    cb.on_click(0);
+}
+
+fn main() {}
@@ -0,0 +1,79 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+fn check_email(email: &str) -> Result<(), String> {
+    if email.contains('@') {
+        Ok(())
+    } else {
+        Err(format!("{email:?} is not an email address"))
+    }
+}
+
+fn check_tag(tag: &str) -> Result<(), String> {
+    if tag.is_empty() {
+        Err("empty tag".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Callbacks)]
+pub enum Msg {
+    #[validate(check_email, error = Invalid)]
+    SetEmail(String),
+    #[validate(check_tag, error = Invalid)]
+    SetTag(#[curry] usize, String),
+    Invalid(String),
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        let cb1: Callback<String> = self.cb.set_email();
+        let cb2: Callback<String> = self.cb.set_email();
+        assert_eq!(cb1, cb2);
+
+        let messages = Rc::new(RefCell::new(Vec::new()));
+        let cb: MsgCallbacks = MsgCallbacks::from_callback(Callback::from({
+            let messages = messages.clone();
+            move |msg| messages.borrow_mut().push(msg)
+        }));
+        cb.set_email().emit("me@example.com".to_string());
+        cb.set_email().emit("nobody".to_string());
+        cb.set_tag(1).emit("rust".to_string());
+        cb.set_tag(2).emit(String::new());
+        assert_eq!(
+            *messages.borrow(),
+            [
+                Msg::SetEmail("me@example.com".to_string()),
+                Msg::Invalid("\"nobody\" is not an email address".to_string()),
+                Msg::SetTag(1, "rust".to_string()),
+                Msg::Invalid("empty tag".to_string()),
+            ],
+        );
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,47 @@
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Callbacks)]
+pub enum Msg {
+    OnSelect(#[curry] usize, MouseEvent),
+}
+
+struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Properties = ();
+    type Message = Msg;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let cb = MsgCallbacks::from(ctx.link());
+        cb.warm_on_select(0..2000);
+
+        Self { cb }
+    }
+
+    fn view(&self, _: &Context<Self>) -> Html {
+        assert_eq!(
+            format!("{:?}", self.cb),
+            "MsgCallbacks { OnSelect: 2000 cached, .. }",
+        );
+
+        let _ = self.cb.on_select(1999);
+        self.cb.warm_on_select(1990..2010);
+        assert_eq!(
+            format!("{:?}", self.cb),
+            "MsgCallbacks { OnSelect: 2010 cached, .. }",
+        );
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "net")]
+
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+use yew_callbacks::Callbacks;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Chat {
+    pub author: String,
+    pub text: String,
+}
+
+#[derive(Callbacks)]
+pub enum Msg {
+    #[websocket]
+    Text(String),
+    #[websocket]
+    Binary(Vec<u8>),
+    #[websocket(json, open = "Connected", closed = "Disconnected", error = "Failed")]
+    Received(Chat),
+    Connected,
+    Disconnected,
+    Failed,
+}
+
+pub struct Test {
+    cb: MsgCallbacks<Self>,
+}
+
+impl Component for Test {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            cb: ctx.link().into(),
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let cb1: Callback<Chat> = self.cb.received();
+        let cb2: Callback<Chat> = self.cb.received();
+        assert_eq!(cb1, cb2);
+
+        // there are no sockets outside of the browser so nothing is sent
+        self.cb.connect_received("ws://localhost:8080/chat");
+        self.cb.send_received(Chat {
+            author: "bob".to_string(),
+            text: "hello".to_string(),
+        });
+        self.cb.close_received();
+
+        self.cb.connect_text("ws://localhost:8080/echo");
+        self.cb.send_text("hello".to_string());
+        self.cb.close_text();
+
+        self.cb.send_binary(vec![1, 2, 3]);
+        self.cb.close_binary();
+
+        html! {}
+    }
+}
+
+#[test]
+fn run_tests() {
+    futures::executor::block_on(async {
+        let renderer = yew::ServerRenderer::<Test>::new();
+        let _ = renderer.render().await;
+    });
+}
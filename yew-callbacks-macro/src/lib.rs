@@ -0,0 +1,7529 @@
+//! Derive macro for [yew-callbacks](https://docs.rs/yew-callbacks).
+//!
+//! You should not depend on this crate directly, use `yew-callbacks` instead.
+
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro_error::{abort, proc_macro_error};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::spanned::Spanned;
+
+#[proc_macro_derive(
+    Callbacks,
+    attributes(
+        alias,
+        attach,
+        before_unload,
+        bridge,
+        broadcast,
+        callbacks,
+        capture,
+        clipboard,
+        connectivity,
+        curry,
+        data_transfer,
+        deferred,
+        delegate,
+        device_motion,
+        device_orientation,
+        drop_files,
+        event_source,
+        external,
+        form,
+        gamepad,
+        geolocation,
+        guard,
+        idle,
+        input,
+        interval,
+        js,
+        listen,
+        media_query,
+        navigate,
+        observe_intersection,
+        popstate,
+        raf,
+        scroll,
+        skip,
+        storage,
+        timeout,
+        validate,
+        visibility,
+        websocket
+    )
+)]
+#[proc_macro_error]
+pub fn main(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    derive_callbacks(&input).into()
+}
+
+#[proc_macro]
+#[proc_macro_error]
+pub fn define_callbacks(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    let callbacks = derive_callbacks(&input);
+
+    // without the derive there is nothing to register the helper attributes
+    let mut item = input;
+    strip_helper_attrs(&mut item.attrs);
+    if let syn::Data::Enum(e) = &mut item.data {
+        for variant in e.variants.iter_mut() {
+            strip_helper_attrs(&mut variant.attrs);
+            for field in variant.fields.iter_mut() {
+                strip_helper_attrs(&mut field.attrs);
+            }
+            if transform(variant).is_some() {
+                variant.discriminant = None;
+            }
+        }
+    }
+
+    quote! {
+        #item
+        #callbacks
+    }
+    .into()
+}
+
+#[proc_macro_derive(FillProps, attributes(fill_props))]
+#[proc_macro_error]
+pub fn fill_props(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    derive_fill_props(&input).into()
+}
+
+fn derive_fill_props(input: &syn::DeriveInput) -> TokenStream {
+    let name = &input.ident;
+    let fields = match &input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => abort!(
+            name,
+            "`#[derive(FillProps)]` only supports structs with named fields"
+        ),
+    };
+    if !input.generics.params.is_empty() {
+        abort!(
+            input.generics,
+            "`#[derive(FillProps)]` does not support generic structs"
+        );
+    }
+
+    let cache = match input
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("fill_props"))
+    {
+        Some(attr) => match attr.parse_args::<syn::Path>() {
+            Ok(cache) => cache,
+            Err(err) => abort!(err.span(), "{}", err),
+        },
+        None => abort!(
+            name,
+            "`#[derive(FillProps)]` requires the cache, for example `#[fill_props(MsgCallbacks)]`"
+        ),
+    };
+
+    let values = fields
+        .iter()
+        .map(|field| {
+            let ident = field.ident.as_ref().unwrap();
+            let skip = match field
+                .attrs
+                .iter()
+                .find(|attr| attr.path.is_ident("fill_props"))
+            {
+                Some(attr) => match attr.parse_args::<Ident>() {
+                    Ok(arg) if arg == "skip" => true,
+                    Ok(arg) => abort!(arg, "expected `skip`"),
+                    Err(err) => abort!(err.span(), "{}", err),
+                },
+                None => false,
+            };
+            // `onclick` and `on_click` are both wired to the getter `on_click()`
+            let getter = Ident::new(
+                &format!("__fill_props_{}", ident.to_string().replace('_', "")),
+                ident.span(),
+            );
+
+            match callback_field(&field.ty) {
+                Some(false) if !skip => quote! {
+                    #ident: callbacks.#getter()
+                },
+                Some(true) if !skip => quote! {
+                    #ident: ::core::option::Option::Some(callbacks.#getter())
+                },
+                _ => quote! {
+                    #ident: ::core::default::Default::default()
+                },
+            }
+        })
+        .collect::<Vec<_>>();
+
+    quote! {
+        #[automatically_derived]
+        impl<C> ::yew_callbacks::FillProps<#cache<C>> for #name {
+            fn fill_props(callbacks: &#cache<C>) -> Self {
+                Self {
+                    #(#values,)*
+                }
+            }
+        }
+    }
+}
+
+#[proc_macro_derive(Form, attributes(validate))]
+#[proc_macro_error]
+pub fn form(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    derive_form(&input).into()
+}
+
+fn derive_form(input: &syn::DeriveInput) -> TokenStream {
+    let name = &input.ident;
+    let vis = &input.vis;
+    let fields = match &input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => abort!(
+            name,
+            "`#[derive(Form)]` only supports structs with named fields"
+        ),
+    };
+    if !input.generics.params.is_empty() {
+        abort!(
+            input.generics,
+            "`#[derive(Form)]` does not support generic structs"
+        );
+    }
+
+    let field_enum = Ident::new(&format!("{name}Field"), Span::call_site());
+    let edit_enum = Ident::new(&format!("{name}Edit"), Span::call_site());
+    let idents = fields
+        .iter()
+        .map(|field| field.ident.as_ref().unwrap())
+        .collect::<Vec<_>>();
+    let variants = idents
+        .iter()
+        .map(|ident| Ident::new(&ident.to_string().to_upper_camel_case(), ident.span()))
+        .collect::<Vec<_>>();
+    let tys = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    // the elements are found by the names of the fields
+    let labels = idents
+        .iter()
+        .map(|ident| ident.to_string())
+        .collect::<Vec<_>>();
+    let parses = fields
+        .iter()
+        .zip(labels.iter())
+        .map(|(field, label)| {
+            let ty = &field.ty;
+            let validate = field
+                .attrs
+                .iter()
+                .find(|attr| attr.path.is_ident("validate"))
+                .map(|attr| match attr.parse_args::<syn::Path>() {
+                    Ok(path) => quote! {
+                        ::yew_callbacks::__private::validate_form_value(#label, #path(&value))?;
+                    },
+                    Err(err) => abort!(err.span(), "{}", err),
+                });
+
+            quote! {{
+                let value = ::yew_callbacks::__private::parse_form_value::<#ty>(#label, value)?;
+                #validate
+                value
+            }}
+        })
+        .collect::<Vec<_>>();
+    let parse_arms = variants
+        .iter()
+        .zip(parses.iter())
+        .map(|(variant, parse)| {
+            quote! {
+                #field_enum::#variant => ::core::result::Result::Ok(#edit_enum::#variant(#parse)),
+            }
+        })
+        .collect::<Vec<_>>();
+    let field_doc = format!("The fields of [`{name}`], to get the callback of one of them.");
+    let edit_doc = format!("A new value for one of the fields of [`{name}`].");
+
+    quote! {
+        #[doc = #field_doc]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        #[allow(missing_docs)]
+        #vis enum #field_enum {
+            #(#variants,)*
+        }
+
+        #[doc = #edit_doc]
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(missing_docs)]
+        #vis enum #edit_enum {
+            #(#variants(#tys),)*
+        }
+
+        #[automatically_derived]
+        impl ::yew_callbacks::Form for #name {
+            type Field = #field_enum;
+            type Edit = #edit_enum;
+
+            fn parse(
+                field: Self::Field,
+                value: &str,
+            ) -> ::core::result::Result<Self::Edit, ::yew_callbacks::FormError> {
+                match field {
+                    #(#parse_arms)*
+                }
+            }
+
+            fn apply(&mut self, edit: Self::Edit) {
+                match edit {
+                    #(#edit_enum::#variants(value) => self.#idents = value,)*
+                }
+            }
+
+            fn from_values(
+                values: impl ::core::ops::Fn(&str) -> ::std::string::String,
+            ) -> ::core::result::Result<Self, ::yew_callbacks::FormError> {
+                ::core::result::Result::Ok(Self {
+                    #(#idents: {
+                        let value = &values(#labels);
+                        #parses
+                    },)*
+                })
+            }
+        }
+    }
+}
+
+// `Some(true)` for an `Option<Callback<_>>`
+fn callback_field(ty: &syn::Type) -> Option<bool> {
+    let segment = match ty {
+        syn::Type::Path(ty) if ty.qself.is_none() => ty.path.segments.last()?,
+        _ => return None,
+    };
+
+    if segment.ident == "Callback" {
+        return Some(false);
+    }
+
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) if segment.ident == "Option" => {
+            match args.args.first()? {
+                syn::GenericArgument::Type(ty) if callback_field(ty) == Some(false) => Some(true),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+// the same as the `attributes` of the derive
+const HELPER_ATTRS: &[&str] = &[
+    "alias",
+    "attach",
+    "before_unload",
+    "bridge",
+    "broadcast",
+    "callbacks",
+    "capture",
+    "clipboard",
+    "connectivity",
+    "curry",
+    "data_transfer",
+    "deferred",
+    "delegate",
+    "device_motion",
+    "device_orientation",
+    "drop_files",
+    "event_source",
+    "external",
+    "form",
+    "gamepad",
+    "geolocation",
+    "guard",
+    "idle",
+    "input",
+    "interval",
+    "js",
+    "listen",
+    "media_query",
+    "navigate",
+    "observe_intersection",
+    "popstate",
+    "raf",
+    "scroll",
+    "skip",
+    "storage",
+    "timeout",
+    "validate",
+    "visibility",
+    "websocket",
+];
+
+fn strip_helper_attrs(attrs: &mut Vec<syn::Attribute>) {
+    attrs.retain(|attr| !HELPER_ATTRS.iter().any(|x| attr.path.is_ident(x)));
+}
+
+// what the features of the enum are generated from, shared by all the `*_parts()`
+#[derive(Clone, Copy)]
+struct Context<'a> {
+    // without the skipped variants and the variants of the forms
+    e: &'a syn::DataEnum,
+    vis: &'a syn::Visibility,
+    // of the cache
+    name: &'a Ident,
+    enum_path: &'a TokenStream,
+    enum_path_name: &'a str,
+    must_use: &'a TokenStream,
+    allow: &'a TokenStream,
+    tys: &'a [TokenStream],
+    curried_tys: &'a [Option<TokenStream>],
+    options: &'a Options,
+}
+
+// what a feature adds to the cache, the parts of all the features are assembled in one place by
+// `derive_callbacks()`
+#[derive(Default)]
+struct Parts {
+    fields: Vec<TokenStream>,
+    inits: Vec<TokenStream>,
+    // run before the cache is built, they can wrap `dispatch`
+    setups: Vec<TokenStream>,
+    // run once the cache is built, unless it is inert
+    starts: Vec<TokenStream>,
+    methods: Vec<TokenStream>,
+    // next to the cache
+    items: Vec<TokenStream>,
+}
+
+impl FromIterator<Parts> for Parts {
+    fn from_iter<I: IntoIterator<Item = Parts>>(iter: I) -> Self {
+        iter.into_iter().fold(Parts::default(), |mut all, parts| {
+            all.fields.extend(parts.fields);
+            all.inits.extend(parts.inits);
+            all.setups.extend(parts.setups);
+            all.starts.extend(parts.starts);
+            all.methods.extend(parts.methods);
+            all.items.extend(parts.items);
+            all
+        })
+    }
+}
+
+fn derive_callbacks(input: &syn::DeriveInput) -> TokenStream {
+    let enum_name = &input.ident;
+    let vis = &input.vis;
+    let e = match &input.data {
+        syn::Data::Enum(e) => e,
+        syn::Data::Struct(s) => abort!(
+            s.struct_token,
+            "`#[derive(Callbacks)]` only supports enums, not structs"
+        ),
+        syn::Data::Union(u) => abort!(
+            u.union_token,
+            "`#[derive(Callbacks)]` only supports enums, not unions"
+        ),
+    };
+    // the generated cache and its `impl` blocks only name the enum, without its parameters
+    if !input.generics.params.is_empty() {
+        abort!(
+            input.generics,
+            "`#[derive(Callbacks)]` does not support generic enums"
+        );
+    }
+    for variant in e.variants.iter() {
+        check_curry(variant);
+    }
+    let options = Options::from_attrs(&input.attrs);
+    // with `remote`, the enum is a copy of an enum of another crate: the messages are created with
+    // the path of the original but the generated items are named after the copy
+    let enum_path = match &options.remote {
+        Some(path) => quote! { #path },
+        None => quote! { #enum_name },
+    };
+    let enum_path_name = match &options.remote {
+        Some(path) => path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<_>>()
+            .join("::"),
+        None => enum_name.to_string(),
+    };
+    // the skipped variants get nothing, only `kind()` must know all the variants; the variants of
+    // the forms get their own getters
+    let all_variants = &e.variants;
+    let form_variants = e
+        .variants
+        .iter()
+        .filter(|variant| !is_skipped(variant))
+        .filter_map(|variant| Some((variant, form_variant(variant)?)))
+        .collect::<Vec<_>>();
+    let e = &syn::DataEnum {
+        variants: e
+            .variants
+            .iter()
+            .filter(|variant| !is_skipped(variant) && form_variant(variant).is_none())
+            .cloned()
+            .collect(),
+        ..e.clone()
+    };
+    for variant in e.variants.iter() {
+        check_trait_objects(variant, &options);
+        validate(variant);
+        aliases(variant);
+        deferred(variant);
+        delegate(variant);
+        curry_ttl(variant);
+        guard(variant);
+        if external(variant) && options.eager {
+            abort!(
+                variant,
+                "`#[external]` cannot be used with `#[callbacks(eager)]`"
+            );
+        }
+    }
+    // the generated items are neither documented nor written by hand, they must not trip the lints
+    // of the user's crate
+    let allow = quote! {
+        #[allow(
+            missing_docs,
+            unused_qualifications,
+            clippy::type_complexity,
+            clippy::pedantic,
+            clippy::nursery
+        )]
+    };
+    // the oldest version wins if more than one is enabled (`--all-features`), 0.20 is the default
+    let yew_0_20 =
+        cfg!(feature = "yew-0_20") || !cfg!(any(feature = "yew-0_21", feature = "yew-next"));
+    // fetching a callback without giving it to an element is always a mistake
+    let must_use = quote! {
+        #[must_use = "the callback does nothing until it is given to an element or a component"]
+    };
+
+    let name = Ident::new(&format!("{enum_name}Callbacks"), Span::call_site());
+
+    let field_names = e
+        .variants
+        .iter()
+        .map(|variant| {
+            Ident::new(
+                &format!("callback_{}", variant.ident.to_string().to_snake_case()),
+                Span::call_site(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let curried_tys = e
+        .variants
+        .iter()
+        .map(|variant| match &variant.fields {
+            syn::Fields::Unit => None,
+            syn::Fields::Unnamed(syn::FieldsUnnamed {
+                unnamed: fields, ..
+            })
+            | syn::Fields::Named(syn::FieldsNamed { named: fields, .. }) => {
+                let tys = fields
+                    .iter()
+                    .filter(|field| is_curried(field))
+                    .map(|field| &field.ty)
+                    .collect::<Vec<_>>();
+
+                if tys.is_empty() {
+                    None
+                } else if let Some(key_struct) = key_struct(&name, variant) {
+                    Some(quote! {
+                        #key_struct
+                    })
+                } else {
+                    Some(quote! {
+                        (#(#tys),*)
+                    })
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let tys = e
+        .variants
+        .iter()
+        .map(|variant| match &variant.fields {
+            syn::Fields::Unit => {
+                quote! {
+                    ()
+                }
+            }
+            syn::Fields::Unnamed(syn::FieldsUnnamed {
+                unnamed: fields, ..
+            })
+            | syn::Fields::Named(syn::FieldsNamed { named: fields, .. }) => {
+                let tys = fields
+                    .iter()
+                    .filter(|field| is_input(field))
+                    .map(|field| &field.ty)
+                    .collect::<Vec<_>>();
+
+                quote! {
+                    (#(#tys),*)
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let clipboards = e.variants.iter().map(clipboard).collect::<Vec<_>>();
+    let drags = e.variants.iter().map(drag).collect::<Vec<_>>();
+    let captures = e.variants.iter().map(capture).collect::<Vec<_>>();
+    let has_capture = captures.iter().any(|x| *x);
+
+    // variants that receive the event and extract the data themselves
+    let extractors = clipboards
+        .iter()
+        .zip(drags.iter())
+        .map(|(clipboard, drag)| match (clipboard, drag) {
+            (Some(Clipboard::Paste), _) => Some((
+                quote! { ::yew::events::Event },
+                quote! {
+                    ::yew_callbacks::__private::FromDataTransfer::from_data_transfer(
+                        ::yew_callbacks::__private::clipboard_data(&event),
+                        "text/plain",
+                    )
+                },
+            )),
+            (_, Some(format)) => Some((
+                quote! { ::yew::events::DragEvent },
+                quote! {
+                    ::yew_callbacks::__private::FromDataTransfer::from_data_transfer(
+                        ::yew_callbacks::__private::drag_data(&event),
+                        #format,
+                    )
+                },
+            )),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    // the inline transformations of `define_callbacks!` are the same with a closure written by the
+    // user
+    let extractors = extractors
+        .into_iter()
+        .zip(e.variants.iter())
+        .map(|(extractor, variant)| match transform(variant) {
+            Some((ty, closure)) => Some((quote! { #ty }, quote! { (#closure)(event) })),
+            None => extractor,
+        })
+        .collect::<Vec<_>>();
+
+    // `#[input]` converts the event with `From` or with the given function
+    let extractors = extractors
+        .into_iter()
+        .zip(e.variants.iter())
+        .map(|(extractor, variant)| match input_type(variant) {
+            Some(input) => Some((input.ty, input.convert)),
+            None => extractor,
+        })
+        .collect::<Vec<_>>();
+
+    let tys = tys
+        .into_iter()
+        .zip(extractors.iter())
+        .map(|(ty, extractor)| match extractor {
+            Some((ty, _)) => ty.clone(),
+            None => ty,
+        })
+        .collect::<Vec<_>>();
+
+    let navigates = e.variants.iter().map(navigate).collect::<Vec<_>>();
+    let has_navigator = navigates.iter().any(Option::is_some);
+    // the caches of the enums without curried variants get no map at all
+    let has_curried = curried_tys.iter().any(Option::is_some);
+    // the navigator can only be retrieved from the scope of a struct component
+    let component_bound = if has_navigator {
+        quote! { ::yew::html::Component }
+    } else {
+        quote! { ::yew::html::BaseComponent }
+    };
+
+    let makers = e
+        .variants
+        .iter()
+        .zip(curried_tys.iter())
+        .map(|(variant, curried_ty)| {
+            let name = &variant.ident;
+
+            match &variant.fields {
+                _ if curried_ty.is_some() => None,
+                syn::Fields::Unit => Some((quote! { _ }, quote! { #enum_path::#name })),
+                syn::Fields::Unnamed(syn::FieldsUnnamed {
+                    unnamed: fields, ..
+                }) => {
+                    let idents = (0..fields.len())
+                        .map(|i| Ident::new(&format!("arg_{i}"), Span::call_site()))
+                        .collect::<Vec<_>>();
+                    let ins = fields
+                        .iter()
+                        .zip(idents.iter())
+                        .filter_map(|(field, ident)| is_input(field).then_some(ident))
+                        .collect::<Vec<_>>();
+                    let values = fields
+                        .iter()
+                        .zip(idents.iter())
+                        .map(|(field, ident)| default_value(field).unwrap_or(quote! { #ident }))
+                        .collect::<Vec<_>>();
+
+                    Some((
+                        quote! { (#(#ins),*) },
+                        quote! { #enum_path::#name(#(#values),*) },
+                    ))
+                }
+                syn::Fields::Named(syn::FieldsNamed { named: fields, .. }) => {
+                    let ins = fields
+                        .iter()
+                        .filter(|field| is_input(field))
+                        .map(|field| &field.ident)
+                        .collect::<Vec<_>>();
+                    let values = fields
+                        .iter()
+                        .map(|field| {
+                            let ident = &field.ident;
+
+                            match default_value(field) {
+                                Some(value) => quote! { #ident: #value },
+                                None => quote! { #ident },
+                            }
+                        })
+                        .collect::<Vec<_>>();
+
+                    Some((
+                        quote! { (#(#ins),*) },
+                        quote! { #enum_path::#name { #(#values),* } },
+                    ))
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let makers = makers
+        .into_iter()
+        .zip(extractors.iter())
+        .zip(e.variants.iter())
+        .map(|((maker, extractor), variant)| match extractor {
+            // the curried callbacks convert the event themselves
+            Some((_, data)) if maker.is_some() => {
+                let name = &variant.ident;
+                let values = variant
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, field)| {
+                        if event_key(field).is_some() {
+                            // read by `with_event_keys()`
+                            let ident = field.ident.clone().unwrap_or_else(|| {
+                                Ident::new(&format!("arg_{i}"), Span::call_site())
+                            });
+                            return quote! { #ident };
+                        }
+                        let value = default_value(field).unwrap_or_else(|| data.clone());
+
+                        match &field.ident {
+                            Some(ident) => quote! { #ident: #value },
+                            None => value,
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let body = match &variant.fields {
+                    syn::Fields::Named(_) => quote! { #enum_path::#name { #(#values),* } },
+                    _ => quote! { #enum_path::#name(#(#values),*) },
+                };
+
+                Some((quote! { event }, body))
+            }
+            _ => maker,
+        })
+        .collect::<Vec<_>>();
+
+    // the keys of `#[curry(from_event = ...)]` are read before the event is moved into the message
+    let makers = makers
+        .into_iter()
+        .zip(e.variants.iter())
+        .zip(tys.iter())
+        .map(|((maker, variant), ty)| {
+            maker.map(|(pat, body)| {
+                let body = with_event_keys(variant, &pat, ty, body);
+                (pat, body)
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // the pointer is captured (or released) before the message is dispatched
+    let makers = makers
+        .into_iter()
+        .zip(captures.iter())
+        .map(|(maker, capture)| match maker {
+            Some((pat, body)) if *capture => Some((
+                pat.clone(),
+                quote! {{
+                    pointer_capture.track(&#pat);
+                    #body
+                }},
+            )),
+            maker => maker,
+        })
+        .collect::<Vec<_>>();
+
+    // `#[validate]` checks the message before it is sent
+    let makers = makers
+        .into_iter()
+        .zip(e.variants.iter())
+        .map(|(maker, variant)| {
+            maker.map(|(pat, body)| (pat, validated(&enum_path, variant, body)))
+        })
+        .collect::<Vec<_>>();
+
+    // with `small-code`, the plain callbacks only differ by the index of their variant: they all
+    // go through the same function instead of having a closure each
+    let erased = makers
+        .iter()
+        .zip(navigates.iter())
+        .zip(captures.iter())
+        .zip(e.variants.iter())
+        .enumerate()
+        .map(|(i, (((maker, navigate), capture), variant))| {
+            (cfg!(feature = "small-code")
+                && maker.is_some()
+                && navigate.is_none()
+                && !capture
+                && !deferred(variant)
+                && !external(variant))
+            .then_some(i)
+        })
+        .collect::<Vec<_>>();
+    let erased_arms = makers
+        .iter()
+        .zip(tys.iter())
+        .zip(erased.iter())
+        .filter_map(|((maker, ty), i)| {
+            let (pat, body) = maker.as_ref()?;
+            let i = (*i)?;
+
+            Some(quote! {
+                #i => {
+                    let #pat: #ty = *::core::result::Result::unwrap(input.downcast::<#ty>());
+                    #body
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    // outside of `impl<C>` so they are not generated again for every component
+    let erased_fn = quote! { <#name>::__erased };
+    let erased_fns = (!erased_arms.is_empty()).then(|| {
+        quote! {
+            fn __erased<I: 'static>(
+                dispatch: &::yew::callback::Callback<#enum_path>,
+                index: usize,
+            ) -> ::yew::callback::Callback<I> {
+                let dispatch = dispatch.clone();
+                ::yew::callback::Callback::from(move |input: I| {
+                    dispatch.emit(Self::__message(index, ::std::boxed::Box::new(input)))
+                })
+            }
+
+            fn __message(
+                index: usize,
+                input: ::std::boxed::Box<dyn ::core::any::Any>,
+            ) -> #enum_path {
+                match index {
+                    #(#erased_arms)*
+                    _ => ::core::unreachable!(),
+                }
+            }
+        }
+    });
+
+    let inits = field_names
+        .iter()
+        .zip(makers.iter())
+        .zip(navigates.iter())
+        .zip(captures.iter())
+        .zip(erased.iter())
+        .zip(e.variants.iter())
+        .map(
+            |(((((field_name, maker), navigate), capture), erased), variant)| match maker {
+                Some(_) if options.eager && erased.is_some() => {
+                    let callback =
+                        unless_inert(quote! { inert }, quote! { #erased_fn(&dispatch, #erased) });
+
+                    quote! {
+                        #field_name: #callback,
+                    }
+                }
+                Some((pat, body)) if options.eager => {
+                    let callback = reform(
+                        deferred_dispatch(variant, quote! { dispatch }),
+                        quote! { navigator },
+                        pat,
+                        body,
+                        navigate.as_ref(),
+                    );
+                    let callback = if *capture {
+                        quote! {{
+                            let pointer_capture = pointer_capture.clone();
+                            #callback
+                        }}
+                    } else {
+                        callback
+                    };
+                    let callback = unless_inert(quote! { inert }, callback);
+
+                    quote! {
+                        #field_name: #callback,
+                    }
+                }
+                _ => match curry_ttl(variant) {
+                    Some(ttl) => quote! {
+                        #field_name: ::std::cell::RefCell::new(
+                            ::yew_callbacks::__private::CurriedMap::with_ttl(#ttl),
+                        ),
+                    },
+                    None => quote! {
+                        #field_name: ::core::default::Default::default(),
+                    },
+                },
+            },
+        )
+        .collect::<Vec<_>>();
+
+    // `sweep()` drops the callbacks of all the variants with a `ttl` that have not been used for
+    // that long, without waiting for a callback to be created
+    let sweep = {
+        let sweeps = e
+            .variants
+            .iter()
+            .zip(field_names.iter())
+            .filter(|(variant, _)| curry_ttl(variant).is_some())
+            .map(|(variant, field_name)| {
+                let path = format!("{enum_path_name}::{}", variant.ident);
+
+                quote! {
+                    ::yew_callbacks::__private::sweep(&self.#field_name, #path);
+                }
+            })
+            .collect::<Vec<_>>();
+
+        (!sweeps.is_empty()).then(|| {
+            quote! {
+                /// Drops the curried callbacks that have not been returned by their getter for
+                /// the `ttl` of their variant.
+                #[track_caller]
+                #vis fn sweep(&self) {
+                    #(#sweeps)*
+                }
+            }
+        })
+    };
+
+    let callbacks = field_names
+        .iter()
+        .zip(tys.iter())
+        .zip(curried_tys.iter())
+        .map(|((field_name, ty), curried_ty)| {
+            if let Some(curried_ty) = curried_ty {
+                quote! {
+                    #field_name: ::std::cell::RefCell<
+                        ::yew_callbacks::__private::CurriedMap<
+                            #curried_ty,
+                            ::yew::callback::Callback<#ty>,
+                        >,
+                    >,
+                }
+            } else if options.eager {
+                quote! {
+                    #field_name: ::yew::callback::Callback<#ty>,
+                }
+            } else {
+                quote! {
+                    #field_name: ::std::cell::OnceCell<::yew::callback::Callback<#ty>>,
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // the same names and types as in the documentation of the getters
+    let infos = e
+        .variants
+        .iter()
+        .zip(tys.iter())
+        .map(|(variant, ty)| {
+            let name = variant.ident.to_string();
+            let getter = name.to_snake_case();
+            let curried = variant
+                .fields
+                .iter()
+                .filter(|field| is_curried(field))
+                .map(|field| {
+                    let ty = &field.ty;
+                    type_name(&quote! { #ty })
+                })
+                .collect::<Vec<_>>();
+            let input = type_name(ty);
+
+            quote! {
+                ::yew_callbacks::CallbackInfo {
+                    variant: #name,
+                    getter: #getter,
+                    curried: &[#(#curried),*],
+                    input: #input,
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let debug_fields = e
+        .variants
+        .iter()
+        .zip(field_names.iter())
+        .zip(curried_tys.iter())
+        .map(|((variant, field_name), curried_ty)| {
+            let label = variant.ident.to_string();
+            let cached = if curried_ty.is_some() {
+                quote! { ::yew_callbacks::__private::Cached::curried(&self.#field_name) }
+            } else if options.eager {
+                quote! { ::yew_callbacks::__private::Cached::Yes }
+            } else {
+                quote! { ::yew_callbacks::__private::Cached::once(&self.#field_name) }
+            };
+
+            quote! {
+                .field(#label, &#cached)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // the variants shadow the name of the cache in the getters
+    let cache_name = name.clone();
+    let constructors = e
+        .variants
+        .iter()
+        .zip(tys.iter())
+        .zip(field_names.iter())
+        .zip(curried_tys.iter())
+        .zip(makers.iter())
+        .zip(navigates.iter())
+        .zip(erased.iter())
+        .map(|((((((variant, ty), field_name), curried_ty), maker), navigate), erased)| {
+            let name = &variant.ident;
+            let fn_name = Ident::new(&name.to_string().to_snake_case(), Span::call_site());
+            // named in the panic messages of the cache
+            let path = format!("{enum_path_name}::{name}");
+            let capture = variant.attrs.iter().any(|attr| attr.path.is_ident("capture"));
+            let with_capture = |callback: TokenStream| {
+                if capture {
+                    quote! {{
+                        let pointer_capture = self.pointer_capture.clone();
+                        #callback
+                    }}
+                } else {
+                    callback
+                }
+            };
+            let external_field = external(variant)
+                .then(|| Ident::new(&format!("external_{fn_name}"), Span::call_site()));
+            let maker = maker.as_ref().map(|(pat, body)| {
+                // the callback given by the parent can change, the cached one always sends to the
+                // last one
+                if let Some(external_field) = &external_field {
+                    return unless_inert(
+                        quote! { self.inert },
+                        quote! {{
+                            let external = self.#external_field.clone();
+                            ::yew::callback::Callback::from(move |input: #ty| {
+                                let callback = external.borrow().clone();
+                                if let ::core::option::Option::Some(callback) = callback {
+                                    callback.emit(input);
+                                }
+                            })
+                        }},
+                    );
+                }
+                if let Some(i) = erased {
+                    return unless_inert(
+                        quote! { self.inert },
+                        quote! { #erased_fn(&self.dispatch, #i) },
+                    );
+                }
+                unless_inert(
+                    quote! { self.inert },
+                    with_capture(reform(
+                        deferred_dispatch(variant, quote! { self.dispatch }),
+                        quote! { self.navigator },
+                        pat,
+                        body,
+                        navigate.as_ref(),
+                    )),
+                )
+            });
+            let doc = getter_doc(&path, variant, ty);
+            let ref_name = Ident::new(&format!("{fn_name}_ref"), Span::call_site());
+            let ref_doc = format!(
+                " The same callback as [`{fn_name}()`](Self::{fn_name}), borrowed from the cache \
+                instead of cloned."
+            );
+            // with `#[guard]`, the getters return a callback that does nothing while the variant
+            // is not allowed, the same one every time
+            let guarded_field = Ident::new(&format!("guarded_{fn_name}"), Span::call_site());
+            let guard_check = |get: &str| {
+                let get = Ident::new(get, Span::call_site());
+
+                guard(variant).map(|path| {
+                    quote! {
+                        if !self.guard_context.allows(#path) {
+                            return ::yew_callbacks::__private::#get(
+                                &self.#guarded_field,
+                                ::yew::callback::Callback::noop,
+                            );
+                        }
+                    }
+                })
+            };
+            let guard_get = guard_check("get_or_init");
+            let guard_ref = guard_check("get_or_init_ref");
+
+            match &variant.fields {
+                _ if options.eager && maker.is_some() => {
+                    quote! {
+                        #(#doc)*
+                        #[inline]
+                        #[track_caller]
+                        #must_use
+                        #vis fn #fn_name(&self) -> ::yew::callback::Callback<#ty> {
+                            #guard_get
+                            self.#field_name.clone()
+                        }
+
+                        #[doc = #ref_doc]
+                        #[inline]
+                        #vis fn #ref_name(&self) -> &::yew::callback::Callback<#ty> {
+                            #guard_ref
+                            &self.#field_name
+                        }
+                    }
+                }
+                syn::Fields::Unit => {
+                    quote! {
+                        #(#doc)*
+                        #[inline]
+                        #[track_caller]
+                        #must_use
+                        fn #fn_name(&self) -> ::yew::callback::Callback<#ty> {
+                            #guard_get
+                            ::yew_callbacks::__private::get_or_init(&self.#field_name, || {
+                                #maker
+                            })
+                        }
+
+                        #[doc = #ref_doc]
+                        #[inline]
+                        #[track_caller]
+                        fn #ref_name(&self) -> &::yew::callback::Callback<#ty> {
+                            #guard_ref
+                            ::yew_callbacks::__private::get_or_init_ref(&self.#field_name, || {
+                                #maker
+                            })
+                        }
+                    }
+                }
+                syn::Fields::Unnamed(syn::FieldsUnnamed {
+                    unnamed: fields, ..
+                })
+                | syn::Fields::Named(syn::FieldsNamed { named: fields, .. }) => {
+                    let is_named = fields.iter().any(|field| field.ident.is_some());
+                    let idents = fields
+                        .iter()
+                        .enumerate()
+                        .map(|(i, field)| {
+                            field.ident.clone().unwrap_or_else(|| {
+                                Ident::new(&format!("arg_{i}"), Span::call_site())
+                            })
+                        })
+                        .collect::<Vec<_>>();
+
+                    if curried_ty.is_some() {
+                        let args = fields
+                            .iter()
+                            .zip(idents.iter())
+                            .filter_map(|(field, ident)| is_curried(field).then_some(ident))
+                            .collect::<Vec<_>>();
+                        let args_sig = fields
+                            .iter()
+                            .zip(idents.iter())
+                            .filter(|(field, _)| is_curried(field))
+                            .map(|(field, ident)| {
+                                let ty = &field.ty;
+
+                                if is_option(ty) {
+                                    quote! {
+                                        #ident: impl ::core::convert::Into<#ty>
+                                    }
+                                } else {
+                                    quote! {
+                                        #ident: #ty
+                                    }
+                                }
+                            })
+                            .collect::<Vec<_>>();
+                        // `None` or the value itself can be given for an optional key
+                        let conversions = fields
+                            .iter()
+                            .zip(idents.iter())
+                            .filter(|(field, _)| is_curried(field) && is_option(&field.ty))
+                            .map(|(field, ident)| {
+                                let ty = &field.ty;
+
+                                quote! {
+                                    let #ident: #ty = ::core::convert::Into::into(#ident);
+                                }
+                            })
+                            .collect::<Vec<_>>();
+                        let ins = fields
+                            .iter()
+                            .zip(idents.iter())
+                            .filter_map(|(field, ident)| is_input(field).then_some(ident))
+                            .collect::<Vec<_>>();
+                        let constructor = if is_named {
+                            let cloned_args = fields
+                                .iter()
+                                .zip(idents.iter())
+                                .map(|(field, ident)| {
+                                    if let Some(value) = default_value(field) {
+                                        quote! {
+                                            #ident: #value
+                                        }
+                                    } else if is_curried(field) {
+                                        quote! {
+                                            #ident: #ident.clone()
+                                        }
+                                    } else {
+                                        quote! {
+                                            #ident
+                                        }
+                                    }
+                                })
+                                .collect::<Vec<_>>();
+
+                            quote! {
+                                #enum_path::#name { #(#cloned_args),* }
+                            }
+                        } else {
+                            let cloned_args = fields
+                                .iter()
+                                .zip(idents.iter())
+                                .map(|(field, ident)| {
+                                    if let Some(value) = default_value(field) {
+                                        value
+                                    } else if is_curried(field) {
+                                        quote! {
+                                            #ident.clone()
+                                        }
+                                    } else {
+                                        quote! {
+                                            #ident
+                                        }
+                                    }
+                                })
+                                .collect::<Vec<_>>();
+
+                            quote! {
+                                #enum_path::#name(#(#cloned_args),*)
+                            }
+                        };
+
+                        let constructor = if capture {
+                            quote! {{
+                                pointer_capture.track(&(#(#ins),*));
+                                #constructor
+                            }}
+                        } else {
+                            constructor
+                        };
+                        // with `#[input]`, the callback receives the event and converts it
+                        let (pat, constructor) = match input_type(variant) {
+                            Some(Input { convert, .. }) => (
+                                quote! { event },
+                                quote! {{
+                                    #(let #ins = #convert;)*
+                                    #constructor
+                                }},
+                            ),
+                            None => (quote! { (#(#ins),*) }, constructor),
+                        };
+                        let constructor = with_event_keys(variant, &pat, ty, constructor);
+                        let constructor = validated(&enum_path, variant, constructor);
+                        let callback = unless_inert(
+                            quote! { self.inert },
+                            with_capture(reform(
+                                deferred_dispatch(variant, quote! { self.dispatch }),
+                                quote! { self.navigator },
+                                &pat,
+                                &constructor,
+                                navigate.as_ref(),
+                            )),
+                        );
+
+                        let key_struct = key_struct(&cache_name, variant);
+                        let keys = keys_of(key_struct.as_ref(), &args);
+                        let keyed_name =
+                            Ident::new(&format!("{fn_name}_keyed"), Span::call_site());
+                        let keyed_doc = format!(
+                            " Returns the callbacks of `{fn_name}()` for all the `keys` at once, \
+                            borrowing the cache only once."
+                        );
+                        let warm_name =
+                            Ident::new(&format!("warm_{fn_name}"), Span::call_site());
+                        let warm_doc = format!(
+                            " Creates the callbacks of `{fn_name}()` for all the `keys` ahead of \
+                            time, for example in `create()` instead of during the first render."
+                        );
+                        let sync_name =
+                            Ident::new(&format!("sync_{fn_name}_keys"), Span::call_site());
+                        let sync_doc = format!(
+                            " Drops the callbacks of `{fn_name}()` whose key is not in \
+                            `current`."
+                        );
+                        // the handles of all the keys share one callback that receives the keys
+                        // along with the input
+                        let dispatcher = unless_inert(
+                            quote! { self.inert },
+                            with_capture(reform(
+                                deferred_dispatch(variant, quote! { self.dispatch }),
+                                quote! { self.navigator },
+                                &quote! { (#keys, #pat): (#curried_ty, #ty) },
+                                &constructor,
+                                navigate.as_ref(),
+                            )),
+                        );
+                        let handle_name =
+                            Ident::new(&format!("handle_{fn_name}"), Span::call_site());
+                        let bound_field =
+                            Ident::new(&format!("bound_{fn_name}"), Span::call_site());
+                        let bound_name = Ident::new(
+                            &format!("{cache_name}Bound{}", variant.ident),
+                            Span::call_site(),
+                        );
+                        let handle_doc = format!(
+                            " Returns a handle that sends the message of `{fn_name}()` for these \
+                            keys, without creating its callback."
+                        );
+                        let factory_fn =
+                            Ident::new(&format!("{fn_name}_factory"), Span::call_site());
+                        let factory_field =
+                            Ident::new(&format!("factory_{fn_name}"), Span::call_site());
+                        let factory_name = Ident::new(
+                            &format!("{cache_name}{}Factory", variant.ident),
+                            Span::call_site(),
+                        );
+                        let factory_doc = format!(
+                            " Returns a factory that creates the callbacks of `{fn_name}()` for \
+                            any key, for a child that decides which keys it renders."
+                        );
+                        // the callbacks of `<variant>_a11y()` go along with the ones of the getter
+                        let a11y_field =
+                            Ident::new(&format!("a11y_{fn_name}"), Span::call_site());
+                        let sync_a11y = has_a11y(variant, ty).then(|| {
+                            quote! {
+                                let current = current
+                                    .into_iter()
+                                    .collect::<::std::vec::Vec<_>>();
+                                if let ::core::option::Option::Some(a11y) = self.#a11y_field.get() {
+                                    ::yew_callbacks::__private::retain_keys(
+                                        a11y,
+                                        #path,
+                                        ::core::iter::Iterator::copied(current.iter()),
+                                    );
+                                }
+                            }
+                        });
+                        // the first key is usually the group of the others, a sheet and its cells
+                        let invalidate_prefix = (args.len() > 1).then(|| {
+                            let (first, first_ty) = fields
+                                .iter()
+                                .zip(idents.iter())
+                                .find(|(field, _)| is_curried(field))
+                                .map(|(field, ident)| (ident, &field.ty))
+                                .unwrap();
+                            let (first_sig, conversion) = if is_option(first_ty) {
+                                (
+                                    quote! { impl ::core::convert::Into<#first_ty> },
+                                    quote! {
+                                        let #first: #first_ty = ::core::convert::Into::into(#first);
+                                    },
+                                )
+                            } else {
+                                (quote! { #first_ty }, quote! {})
+                            };
+                            let first_key = if key_struct.is_some() {
+                                quote! { keys.#first }
+                            } else {
+                                quote! { keys.0 }
+                            };
+                            let prefix_name = Ident::new(
+                                &format!("invalidate_{fn_name}_prefix"),
+                                Span::call_site(),
+                            );
+                            let invalidate_a11y = has_a11y(variant, ty).then(|| {
+                                quote! {
+                                    if let ::core::option::Option::Some(a11y) =
+                                        self.#a11y_field.get()
+                                    {
+                                        ::yew_callbacks::__private::retain_where(
+                                            a11y,
+                                            #path,
+                                            |keys| #first_key != #first,
+                                        );
+                                    }
+                                }
+                            });
+                            let prefix_doc = format!(
+                                " Drops the callbacks of `{fn_name}()` whose first key is \
+                                `{first}`, the callbacks of the other keys are kept."
+                            );
+
+                            quote! {
+                                #[doc = #prefix_doc]
+                                #[track_caller]
+                                #vis fn #prefix_name(&self, #first: #first_sig) {
+                                    #conversion
+                                    #invalidate_a11y
+                                    ::yew_callbacks::__private::retain_where(
+                                        &self.#field_name,
+                                        #path,
+                                        |keys| #first_key != #first,
+                                    );
+                                }
+                            }
+                        });
+                        let guard_keyed = guard(variant).map(|path| {
+                            quote! {
+                                if !self.guard_context.allows(#path) {
+                                    let noop = ::yew_callbacks::__private::get_or_init(
+                                        &self.#guarded_field,
+                                        ::yew::callback::Callback::noop,
+                                    );
+                                    return keys
+                                        .into_iter()
+                                        .map(|keys| (keys, ::core::clone::Clone::clone(&noop)))
+                                        .collect::<::std::vec::Vec<_>>()
+                                        .into_iter();
+                                }
+                            }
+                        });
+                        let by_key = key_struct.as_ref().map(|key_struct| {
+                            let by_key_name =
+                                Ident::new(&format!("{fn_name}_by_key"), Span::call_site());
+                            let by_key_doc = format!(
+                                " The same callback as [`{fn_name}()`](Self::{fn_name}) with its \
+                                keys given as a [`{key_struct}`]."
+                            );
+
+                            quote! {
+                                #[doc = #by_key_doc]
+                                #[inline]
+                                #[track_caller]
+                                #must_use
+                                #vis fn #by_key_name(&self, keys: #key_struct)
+                                    -> ::yew::callback::Callback<#ty>
+                                {
+                                    let #keys = keys;
+                                    self.#fn_name(#(#args),*)
+                                }
+                            }
+                        });
+
+                        quote! {
+                            #(#doc)*
+                            #[inline]
+                            #[track_caller]
+                            #must_use
+                            #vis fn #fn_name(&self #(, #args_sig )* )
+                                -> ::yew::callback::Callback<#ty>
+                            {
+                                #guard_get
+                                #(#conversions)*
+                                ::yew_callbacks::__private::get_or_insert(
+                                    &self.#field_name,
+                                    #path,
+                                    #keys,
+                                    |#keys| #callback,
+                                )
+                            }
+
+                            #by_key
+
+                            #invalidate_prefix
+
+                            #[doc = #keyed_doc]
+                            #[track_caller]
+                            #vis fn #keyed_name(
+                                &self,
+                                keys: impl ::core::iter::IntoIterator<Item = #curried_ty>,
+                            ) -> impl ::core::iter::Iterator<
+                                Item = (#curried_ty, ::yew::callback::Callback<#ty>),
+                            > {
+                                #guard_keyed
+                                ::yew_callbacks::__private::get_or_insert_all(
+                                    &self.#field_name,
+                                    #path,
+                                    keys,
+                                    |#keys| #callback,
+                                )
+                            }
+
+                            #[doc = #warm_doc]
+                            #[track_caller]
+                            #vis fn #warm_name(
+                                &self,
+                                keys: impl ::core::iter::IntoIterator<Item = #curried_ty>,
+                            ) {
+                                self.#keyed_name(keys).for_each(::core::mem::drop);
+                            }
+
+                            #[doc = #sync_doc]
+                            #[track_caller]
+                            #vis fn #sync_name<'a>(
+                                &self,
+                                current: impl ::core::iter::IntoIterator<Item = &'a #curried_ty>,
+                            )
+                            where
+                                #curried_ty: 'a,
+                            {
+                                #sync_a11y
+                                ::yew_callbacks::__private::retain_keys(
+                                    &self.#field_name,
+                                    #path,
+                                    current,
+                                );
+                            }
+
+                            #[doc = #handle_doc]
+                            #[inline]
+                            #[track_caller]
+                            #must_use
+                            #vis fn #handle_name(&self #(, #args_sig )* ) -> #bound_name {
+                                #(#conversions)*
+                                #bound_name {
+                                    dispatcher: ::yew_callbacks::__private::get_or_init(
+                                        &self.#bound_field,
+                                        || #dispatcher,
+                                    ),
+                                    keys: #keys,
+                                }
+                            }
+
+                            #[doc = #factory_doc]
+                            #[inline]
+                            #[must_use]
+                            #vis fn #factory_fn(&self) -> #factory_name {
+                                ::yew_callbacks::__private::get_or_init(&self.#factory_field, || {
+                                    #factory_name {
+                                        dispatcher: ::yew_callbacks::__private::get_or_init(
+                                            &self.#bound_field,
+                                            || #dispatcher,
+                                        ),
+                                        callbacks: ::core::default::Default::default(),
+                                    }
+                                })
+                            }
+                        }
+                    } else {
+                        quote! {
+                            #(#doc)*
+                            #[inline]
+                            #[track_caller]
+                            #must_use
+                            #vis fn #fn_name(&self) -> ::yew::callback::Callback<#ty> {
+                                #guard_get
+                                ::yew_callbacks::__private::get_or_init(&self.#field_name, || {
+                                    #maker
+                                })
+                            }
+
+                            #[doc = #ref_doc]
+                            #[inline]
+                            #[track_caller]
+                            #vis fn #ref_name(&self) -> &::yew::callback::Callback<#ty> {
+                                #guard_ref
+                                ::yew_callbacks::__private::get_or_init_ref(
+                                    &self.#field_name,
+                                    || #maker,
+                                )
+                            }
+                        }
+                    }
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // the keys of a builder are given by name, the type of the builder tells which ones are
+    // already there so `get()` only exists once they all are
+    let (builder_items, builder_methods) = e
+        .variants
+        .iter()
+        .zip(tys.iter())
+        .filter(|(variant, _)| variant_options(variant).builder)
+        .map(|(variant, ty)| {
+            let fn_name = Ident::new(
+                &variant.ident.to_string().to_snake_case(),
+                Span::call_site(),
+            );
+            let builder_fn = Ident::new(&format!("{fn_name}_builder"), Span::call_site());
+            let builder_name = Ident::new(
+                &format!("{name}{}Builder", variant.ident),
+                Span::call_site(),
+            );
+            let keys = variant
+                .fields
+                .iter()
+                .filter(|field| is_curried(field))
+                .collect::<Vec<_>>();
+            let params = (0..keys.len())
+                .map(|i| Ident::new(&format!("K{i}"), Span::call_site()))
+                .collect::<Vec<_>>();
+            let values = (0..keys.len())
+                .map(|i| Ident::new(&format!("key_{i}"), Span::call_site()))
+                .collect::<Vec<_>>();
+            let key_tys = keys.iter().map(|field| &field.ty).collect::<Vec<_>>();
+            let setters = keys.iter().enumerate().map(|(i, field)| {
+                let ident = &field.ident;
+                let ty = &field.ty;
+                // the key `i` is replaced, the others are kept as they are
+                let replace = |items: &[Ident], item: TokenStream| {
+                    items
+                        .iter()
+                        .enumerate()
+                        .map(|(j, other)| {
+                            if j == i {
+                                item.clone()
+                            } else {
+                                quote! { #other }
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                };
+                let others = params
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| *j != i)
+                    .map(|(_, param)| param);
+                let before = replace(&params, quote! { () });
+                let after = replace(&params, quote! { #ty });
+                let kept = replace(&values, quote! { _ });
+                let (arg, value) = if is_option(ty) {
+                    (
+                        quote! { impl ::core::convert::Into<#ty> },
+                        quote! { ::core::convert::Into::into(#ident) },
+                    )
+                } else {
+                    (quote! { #ty }, quote! { #ident })
+                };
+                let set = replace(&values, value);
+                let doc = format!(" Sets the key `{}`.", ident.as_ref().unwrap());
+
+                quote! {
+                    #[automatically_derived]
+                    #allow
+                    impl<'a, C, #(#others),*> #builder_name<'a, C, #(#before),*> {
+                        #[doc = #doc]
+                        #[inline]
+                        #vis fn #ident(
+                            self,
+                            #ident: #arg,
+                        ) -> #builder_name<'a, C, #(#after),*> {
+                            let (#(#kept,)*) = self.keys;
+                            #builder_name {
+                                callbacks: self.callbacks,
+                                keys: (#(#set,)*),
+                            }
+                        }
+                    }
+                }
+            });
+            let unset = keys.iter().map(|_| quote! { () }).collect::<Vec<_>>();
+            let struct_doc = format!(
+                " The keys of [`{name}::{fn_name}()`], given by name. Returned by \
+                [`{name}::{builder_fn}()`]."
+            );
+            let builder_doc = format!(
+                " The same callback as [`{fn_name}()`](Self::{fn_name}) with its keys given by \
+                name, `get()` can only be called once they are all given."
+            );
+
+            (
+                quote! {
+                    #[doc = #struct_doc]
+                    #[must_use = "the callback is only returned by `get()`"]
+                    #allow
+                    #vis struct #builder_name<'a, C, #(#params = ()),*> {
+                        callbacks: &'a #name<C>,
+                        keys: (#(#params,)*),
+                    }
+
+                    #(#setters)*
+
+                    #[automatically_derived]
+                    #allow
+                    impl<'a, C> #builder_name<'a, C, #(#key_tys),*> {
+                        /// Returns the callback of these keys.
+                        #[inline]
+                        #[track_caller]
+                        #vis fn get(self) -> ::yew::callback::Callback<#ty> {
+                            let (#(#values,)*) = self.keys;
+                            self.callbacks.#fn_name(#(#values),*)
+                        }
+                    }
+                },
+                quote! {
+                    #[doc = #builder_doc]
+                    #[inline]
+                    #vis fn #builder_fn(&self) -> #builder_name<'_, C> {
+                        #builder_name {
+                            callbacks: self,
+                            keys: (#(#unset,)*),
+                        }
+                    }
+                },
+            )
+        })
+        .unzip::<_, _, Vec<_>, Vec<_>>();
+
+    // the curried fields are already `Clone`, `Eq` and `Hash`, `Debug` is only implemented when
+    // they all are
+    let key_items = e
+        .variants
+        .iter()
+        .filter_map(|variant| Some((variant, key_struct(&name, variant)?)))
+        .map(|(variant, key_struct)| {
+            let fn_name = variant.ident.to_string().to_snake_case();
+            let keys = variant
+                .fields
+                .iter()
+                .filter(|field| is_curried(field))
+                .collect::<Vec<_>>();
+            let idents = keys.iter().map(|field| &field.ident).collect::<Vec<_>>();
+            let key_tys = keys.iter().map(|field| &field.ty).collect::<Vec<_>>();
+            let doc = format!(
+                " The curried keys of [`{name}::{fn_name}()`], the key of its callbacks in the \
+                cache."
+            );
+
+            quote! {
+                #[doc = #doc]
+                #[derive(
+                    ::core::clone::Clone,
+                    ::core::cmp::PartialEq,
+                    ::core::cmp::Eq,
+                    ::core::hash::Hash,
+                )]
+                #allow
+                #vis struct #key_struct {
+                    #(#vis #idents: #key_tys,)*
+                }
+
+                #[automatically_derived]
+                #allow
+                impl ::core::fmt::Debug for #key_struct
+                where
+                    #(for<'a> #key_tys: ::core::fmt::Debug,)*
+                {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct(::core::stringify!(#key_struct))
+                            #(.field(::core::stringify!(#idents), &self.#idents))*
+                            .finish()
+                    }
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let kind = options.kind.then(|| {
+        let kind_name = Ident::new(&format!("{enum_name}Kind"), Span::call_site());
+        let variants = all_variants
+            .iter()
+            .map(|variant| &variant.ident)
+            .collect::<Vec<_>>();
+        let names = variants
+            .iter()
+            .map(|variant| variant.to_string().to_snake_case())
+            .collect::<Vec<_>>();
+        let patterns = all_variants
+            .iter()
+            .map(|variant| {
+                let name = &variant.ident;
+
+                match &variant.fields {
+                    syn::Fields::Unit => quote! { #enum_path::#name },
+                    syn::Fields::Unnamed(_) => quote! { #enum_path::#name(..) },
+                    syn::Fields::Named(_) => quote! { #enum_path::#name { .. } },
+                }
+            })
+            .collect::<Vec<_>>();
+        // the kinds have the same values as the variants, for example to match constants in JS
+        let discriminants = all_variants
+            .iter()
+            .map(|variant| match &variant.discriminant {
+                Some((eq, expr)) if transform(variant).is_none() => quote! { #eq #expr },
+                _ => quote! {},
+            })
+            .collect::<Vec<_>>();
+        let repr = input
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("repr"))
+            .collect::<Vec<_>>();
+        let len = variants.len();
+
+        quote! {
+            #[derive(
+                ::core::fmt::Debug,
+                ::core::clone::Clone,
+                ::core::marker::Copy,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq,
+                ::core::hash::Hash,
+            )]
+            #(#repr)*
+            #allow
+            #vis enum #kind_name {
+                #(#variants #discriminants,)*
+            }
+
+            #[automatically_derived]
+            #allow
+            impl #kind_name {
+                #vis const ALL: [Self; #len] = [#(Self::#variants),*];
+
+                #vis fn name(self) -> &'static str {
+                    match self {
+                        #(Self::#variants => #names,)*
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            #allow
+            impl #enum_path {
+                #vis fn kind(&self) -> #kind_name {
+                    match self {
+                        #(#patterns => #kind_name::#variants,)*
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            #allow
+            impl<C> #name<C> {
+                #must_use
+                #vis fn callback_for(
+                    &self,
+                    kind: #kind_name,
+                ) -> ::core::option::Option<::yew::callback::Callback<::yew::events::Event>> {
+                    self.by_name(kind.name())
+                }
+            }
+        }
+    });
+
+    let props = options.props.then(|| {
+        let props_name = Ident::new(&format!("{enum_name}CallbackProps"), Span::call_site());
+        let (fn_names, tys) = e
+            .variants
+            .iter()
+            .zip(tys.iter())
+            .zip(curried_tys.iter())
+            .filter(|(_, curried_ty)| curried_ty.is_none())
+            .map(|((variant, ty), _)| {
+                (
+                    Ident::new(
+                        &variant.ident.to_string().to_snake_case(),
+                        Span::call_site(),
+                    ),
+                    ty,
+                )
+            })
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+
+        quote! {
+            #[derive(
+                ::core::fmt::Debug,
+                ::core::clone::Clone,
+                ::core::cmp::PartialEq,
+                ::yew::html::Properties,
+            )]
+            #allow
+            #vis struct #props_name {
+                #(#vis #fn_names: ::yew::callback::Callback<#tys>,)*
+            }
+
+            #[automatically_derived]
+            #allow
+            impl<C> #name<C> {
+                #vis fn props(&self) -> #props_name {
+                    #props_name {
+                        #(#fn_names: self.#fn_names(),)*
+                    }
+                }
+            }
+        }
+    });
+
+    // the getters under a name without underscores, for the fields of `#[derive(FillProps)]`
+    let fill_props = cfg!(feature = "fill-props").then(|| {
+        let getters = e
+            .variants
+            .iter()
+            .zip(tys.iter())
+            .zip(curried_tys.iter())
+            .filter(|(_, curried_ty)| curried_ty.is_none())
+            .map(|((variant, ty), _)| {
+                let snake_name = variant.ident.to_string().to_snake_case();
+                let fn_name = Ident::new(&snake_name, Span::call_site());
+                let fill_name = Ident::new(
+                    &format!("__fill_props_{}", snake_name.replace('_', "")),
+                    Span::call_site(),
+                );
+
+                quote! {
+                    #[doc(hidden)]
+                    #vis fn #fill_name(&self) -> ::yew::callback::Callback<#ty> {
+                        self.#fn_name()
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        quote! {
+            #[automatically_derived]
+            #allow
+            impl<C> #name<C> {
+                /// Builds the `Properties` of a child component, see `#[derive(FillProps)]`.
+                #must_use
+                #vis fn fill_props<P: ::yew_callbacks::FillProps<Self>>(&self) -> P {
+                    P::fill_props(self)
+                }
+
+                #(#getters)*
+            }
+        }
+    });
+
+    let handle_name = Ident::new(&format!("{name}Handle"), Span::call_site());
+    let handle = (options.handle || options.context || options.hook).then(|| {
+        quote! {
+            #allow
+            #vis struct #handle_name<C = ()>(::std::rc::Rc<#name<C>>);
+
+            #[automatically_derived]
+            #allow
+            impl<C> ::core::fmt::Debug for #handle_name<C> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_tuple(::core::stringify!(#handle_name))
+                        .field(&self.0)
+                        .finish()
+                }
+            }
+
+            #[automatically_derived]
+            #allow
+            impl<C> ::core::clone::Clone for #handle_name<C> {
+                fn clone(&self) -> Self {
+                    Self(self.0.clone())
+                }
+            }
+
+            #[automatically_derived]
+            #allow
+            impl<C> ::core::cmp::PartialEq for #handle_name<C> {
+                fn eq(&self, other: &Self) -> bool {
+                    ::std::rc::Rc::ptr_eq(&self.0, &other.0)
+                }
+            }
+
+            #[automatically_derived]
+            #allow
+            impl<C> ::std::ops::Deref for #handle_name<C> {
+                type Target = #name<C>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.0
+                }
+            }
+
+            #[automatically_derived]
+            #allow
+            impl<C: #component_bound> ::core::convert::From<::yew::html::Scope<C>>
+                for #handle_name<C>
+            where
+                C::Message: ::core::convert::From<#enum_path>,
+            {
+                fn from(link: ::yew::html::Scope<C>) -> Self {
+                    Self(::std::rc::Rc::new(#name::new(link)))
+                }
+            }
+
+            #[automatically_derived]
+            #allow
+            impl<C: #component_bound> ::core::convert::From<&::yew::html::Scope<C>>
+                for #handle_name<C>
+            where
+                C::Message: ::core::convert::From<#enum_path>,
+            {
+                fn from(link: &::yew::html::Scope<C>) -> Self {
+                    Self(::std::rc::Rc::new(#name::new(link.to_owned())))
+                }
+            }
+
+            #[automatically_derived]
+            #allow
+            impl<C> ::core::convert::From<::yew::callback::Callback<#enum_path>>
+                for #handle_name<C>
+            {
+                fn from(dispatch: ::yew::callback::Callback<#enum_path>) -> Self {
+                    Self(::std::rc::Rc::new(#name::from_callback(dispatch)))
+                }
+            }
+
+            #[automatically_derived]
+            #allow
+            impl<C, R> ::core::convert::From<::yew::functional::UseReducerDispatcher<R>>
+                for #handle_name<C>
+            where
+                R: ::yew::functional::Reducible<Action = #enum_path> + 'static,
+            {
+                fn from(dispatcher: ::yew::functional::UseReducerDispatcher<R>) -> Self {
+                    Self(::std::rc::Rc::new(dispatcher.into()))
+                }
+            }
+        }
+    });
+
+    let context = options.context.then(|| {
+        let provider_name = Ident::new(&format!("{name}Provider"), Span::call_site());
+        let provider_props_name = Ident::new(&format!("{name}ProviderProps"), Span::call_site());
+        let hook_name = Ident::new(
+            &format!("use_{}", name.to_string().to_snake_case()),
+            Span::call_site(),
+        );
+
+        quote! {
+            #[derive(::yew::html::Properties)]
+            #allow
+            #vis struct #provider_props_name<C: 'static> {
+                #vis context: #handle_name<C>,
+                #[prop_or_default]
+                #vis children: ::yew::html::Children,
+            }
+
+            #[automatically_derived]
+            #allow
+            impl<C> ::core::cmp::PartialEq for #provider_props_name<C> {
+                fn eq(&self, other: &Self) -> bool {
+                    self.context == other.context && self.children == other.children
+                }
+            }
+
+            #[::yew::functional::function_component]
+            #allow
+            #vis fn #provider_name<C: 'static>(
+                props: &#provider_props_name<C>,
+            ) -> ::yew::html::Html {
+                ::yew::html! {
+                    <::yew::context::ContextProvider<#handle_name<C>>
+                        context={props.context.clone()}
+                    >
+                        { props.children.clone() }
+                    </::yew::context::ContextProvider<#handle_name<C>>>
+                }
+            }
+
+            #[::yew::functional::hook]
+            #allow
+            #vis fn #hook_name<C: 'static>() -> ::core::option::Option<#handle_name<C>> {
+                ::yew::functional::use_context::<#handle_name<C>>()
+            }
+        }
+    });
+
+    let hook = options.hook.then(|| {
+        let hook_name = Ident::new(
+            &format!("use_{}_with", name.to_string().to_snake_case()),
+            Span::call_site(),
+        );
+
+        let make = quote! {
+            |_| {
+                #handle_name::from(::yew::callback::Callback::from(move |msg: #enum_path| {
+                    let dispatch = latest.borrow().clone();
+                    dispatch(msg)
+                }))
+            }
+        };
+        // the dependencies are the first argument since Yew 0.21
+        let use_memo = if yew_0_20 {
+            quote! { ::yew::functional::use_memo(#make, ()) }
+        } else {
+            quote! { ::yew::functional::use_memo((), #make) }
+        };
+
+        quote! {
+            #[::yew::functional::hook]
+            #allow
+            #vis fn #hook_name<F: ::core::ops::Fn(#enum_path) + 'static>(
+                dispatch: F,
+            ) -> #handle_name {
+                let latest = ::yew::functional::use_mut_ref(|| {
+                    ::std::rc::Rc::new(|_| {}) as ::std::rc::Rc<dyn ::core::ops::Fn(#enum_path)>
+                });
+                *latest.borrow_mut() = ::std::rc::Rc::new(dispatch);
+
+                let handle = #use_memo;
+
+                (*handle).clone()
+            }
+        }
+    });
+
+    let worker = cfg!(feature = "agent").then(|| {
+        quote! {
+            #[automatically_derived]
+            #allow
+            impl<W> ::core::convert::From<::yew_callbacks::__private::gloo_worker::WorkerScope<W>>
+                for #name<W>
+            where
+                W: ::yew_callbacks::__private::gloo_worker::Worker,
+                W::Message: ::core::convert::From<#enum_path>,
+            {
+                fn from(scope: ::yew_callbacks::__private::gloo_worker::WorkerScope<W>) -> Self {
+                    Self::from_callback(::yew::callback::Callback::from(move |msg: #enum_path| {
+                        scope.send_message(msg)
+                    }))
+                }
+            }
+
+            #[automatically_derived]
+            #allow
+            impl<W> ::core::convert::From<&::yew_callbacks::__private::gloo_worker::WorkerScope<W>>
+                for #name<W>
+            where
+                W: ::yew_callbacks::__private::gloo_worker::Worker,
+                W::Message: ::core::convert::From<#enum_path>,
+            {
+                fn from(scope: &::yew_callbacks::__private::gloo_worker::WorkerScope<W>) -> Self {
+                    Self::from(scope.to_owned())
+                }
+            }
+        }
+    });
+
+    let new_body = if has_navigator {
+        quote! {
+            let callbacks = Self::from_callback(link.callback(|msg: #enum_path| msg));
+            callbacks.navigator.set(move || {
+                ::yew_callbacks::__private::yew_router::scope_ext::RouterScopeExt::navigator(&link)
+            });
+            callbacks
+        }
+    } else {
+        quote! {
+            Self::from_callback(link.callback(|msg: #enum_path| msg))
+        }
+    };
+
+    // with `diagnostics`, the caches built again and again for a component type are reported
+    let new_body = if cfg!(feature = "diagnostics") {
+        quote! {
+            let callbacks = { #new_body };
+            callbacks.alive.built_by::<C>(::core::stringify!(#name));
+            callbacks
+        }
+    } else {
+        new_body
+    };
+
+    let (registry_methods, reclaim) = if cfg!(feature = "registry") {
+        let set_navigator = has_navigator.then(|| {
+            quote! {
+                callbacks.navigator.set(move || {
+                    ::yew_callbacks::__private::yew_router::scope_ext::RouterScopeExt::navigator(
+                        &link,
+                    )
+                });
+            }
+        });
+
+        (
+            quote! {
+                /// Stores the cache under `key` until it is reclaimed by
+                /// [`reclaim()`](Self::reclaim), for example in `destroy()`. The cache already
+                /// stored under `key` is dropped.
+                #[track_caller]
+                #vis fn store<K>(self, key: K)
+                where
+                    C: 'static,
+                    K: ::core::cmp::Eq + ::core::hash::Hash + 'static,
+                {
+                    ::yew_callbacks::__private::registry_store(key, self);
+                }
+
+                /// Drops the cache stored under `key`. Returns `false` if there was none.
+                #[track_caller]
+                #vis fn evict<K>(key: &K) -> bool
+                where
+                    C: 'static,
+                    K: ::core::cmp::Eq + ::core::hash::Hash + 'static,
+                {
+                    ::yew_callbacks::__private::registry_take::<Self, K>(key).is_some()
+                }
+
+                /// Drops all the caches of this type that are stored.
+                #vis fn evict_all()
+                where
+                    C: 'static,
+                {
+                    ::yew_callbacks::__private::registry_evict_all::<Self>();
+                }
+            },
+            quote! {
+                /// Takes the cache stored under `key` by [`store()`](Self::store) and sends its
+                /// messages to `link` from now on, or builds a new one if there is none.
+                #[track_caller]
+                #vis fn reclaim<K>(link: ::yew::html::Scope<C>, key: &K) -> Self
+                where
+                    K: ::core::cmp::Eq + ::core::hash::Hash + 'static,
+                {
+                    match ::yew_callbacks::__private::registry_take::<Self, K>(key) {
+                        ::core::option::Option::Some(callbacks) => {
+                            *callbacks.relay.borrow_mut() =
+                                link.callback(|msg: #enum_path| msg);
+                            #set_navigator
+                            callbacks
+                        }
+                        ::core::option::Option::None => Self::new(link),
+                    }
+                }
+            },
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
+
+    // there are no warnings for proc-macros on stable, the use of a deprecated item is one
+    let tuple_warnings = e
+        .variants
+        .iter()
+        .filter(|variant| is_tuple_input(variant))
+        .map(|variant| {
+            let name = &variant.ident;
+            let span = variant.ident.span();
+            let warning = Ident::new(&format!("{name}_receives_a_tuple"), span);
+            let note = format!(
+                "the callback of `{enum_path_name}::{name}` receives a tuple of its fields and \
+                cannot be given to an element: add `#[curry]` to the fields known in advance, \
+                `#[callbacks(skip)]` if the callback is not needed or `#[callbacks(tuple)]` if the \
+                tuple is intended"
+            );
+
+            quote_spanned! {span=>
+                const _: () = {
+                    #[deprecated(note = #note)]
+                    #[allow(non_camel_case_types)]
+                    struct #warning;
+                    let _ = #warning;
+                };
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let test_mount = cfg!(feature = "test-util").then(|| {
+        quote! {
+            #vis fn test_mount(props: C::Properties) -> ::yew_callbacks::TestMount<Self> {
+                ::yew_callbacks::__private::test_mount(|root| {
+                    let app = ::yew::Renderer::<C>::with_root_and_props(root, props).render();
+                    let callbacks = Self::new(::core::clone::Clone::clone(&*app));
+
+                    (callbacks, ::std::boxed::Box::new(move || app.destroy()))
+                })
+            }
+        }
+    });
+
+    let cx = Context {
+        e,
+        vis,
+        name: &name,
+        enum_path: &enum_path,
+        enum_path_name: &enum_path_name,
+        must_use: &must_use,
+        allow: &allow,
+        tys: &tys,
+        curried_tys: &curried_tys,
+        options: &options,
+    };
+    // the fields are declared and the starts are run in this order, the setups wrap `dispatch` one
+    // after the other
+    let Parts {
+        fields,
+        inits,
+        setups,
+        starts,
+        methods,
+        items,
+    } = [
+        Parts {
+            fields: callbacks,
+            inits,
+            ..Default::default()
+        },
+        by_name_parts(&cx),
+        navigator_parts(&cx, has_navigator),
+        relay_parts(&cx),
+        alive_parts(&cx),
+        guard_parts(&cx, all_variants),
+        listener_parts(&cx),
+        attach_parts(&cx),
+        simulate_parts(&cx, &extractors),
+        interval_parts(&cx),
+        timeout_parts(&cx),
+        raf_parts(&cx),
+        idle_parts(&cx),
+        intersection_parts(&cx),
+        media_query_parts(&cx),
+        watcher_parts(&cx),
+        popstate_parts(&cx),
+        storage_parts(&cx),
+        broadcast_parts(&cx),
+        socket_parts(&cx),
+        event_source_parts(&cx),
+        form_parts(&cx, &form_variants),
+        bind_parts(&cx, &extractors),
+        alias_parts(&cx, &navigates),
+        delegated_parts(&cx),
+        external_parts(&cx),
+        a11y_parts(&cx),
+        bound_parts(&cx, &makers),
+        geolocation_parts(&cx),
+        bridge_parts(&cx),
+        sensor_parts(&cx),
+        scroll_parts(&cx),
+        gamepad_parts(&cx),
+        before_unload_parts(&cx),
+        js_parts(&cx),
+        clipboard_parts(&cx, &clipboards),
+        allow_drop_parts(&cx, &drags),
+        pointer_capture_parts(&cx, has_capture),
+        reforms_parts(&cx, has_curried),
+    ]
+    .into_iter()
+    .collect();
+    let starts = starts
+        .into_iter()
+        .filter(|start| !start.is_empty())
+        .collect::<Vec<_>>();
+
+    let init = quote! {
+        Self {
+            #(#inits)*
+            dispatch,
+            inert,
+            _component: ::std::marker::PhantomData,
+        }
+    };
+    let init_body = if starts.is_empty() {
+        init
+    } else {
+        quote! {
+            let callbacks = #init;
+            // nothing is listened to when there is nothing to send the messages to
+            if !inert {
+                #(#starts)*
+            }
+            callbacks
+        }
+    };
+
+    let struct_fields = quote! {
+        dispatch: ::yew::callback::Callback<#enum_path>,
+        #(#fields)*
+        inert: bool,
+    };
+    // none of the fields depend on the component, they are moved as they are by `erase()`
+    let struct_field_names = match syn::parse2::<syn::FieldsNamed>(quote! {{ #struct_fields }}) {
+        Ok(fields) => fields
+            .named
+            .into_iter()
+            .filter_map(|field| field.ident)
+            .collect::<Vec<_>>(),
+        Err(err) => abort!(err.span(), "{}", err),
+    };
+    // the lean cache of an enum without curried variants has no `erase()` either, `from_callback()`
+    // builds it without the type of the component
+    let erase = has_curried.then(|| {
+        quote! {
+            /// Turns the cache of a component into a cache without the type of the component,
+            /// for the functions that are not generic over it. The getters work the same.
+            #vis fn erase(self) -> #name {
+                let Self {
+                    #(#struct_field_names,)*
+                    _component,
+                } = self;
+
+                #name {
+                    #(#struct_field_names,)*
+                    _component: ::std::marker::PhantomData,
+                }
+            }
+        }
+    });
+    let debug_reforms = has_curried.then(|| {
+        quote! {
+            if let ::core::option::Option::Some(reforms) = self.reforms.get() {
+                reforms.debug(&mut debug);
+            }
+        }
+    });
+
+    quote! {
+        #allow
+        #[must_use = "the callbacks are only cached for as long as the cache is kept"]
+        #vis struct #name<C = ()> {
+            #struct_fields
+            _component: ::std::marker::PhantomData<C>,
+        }
+
+        // only the callbacks are shown, the listeners, timers, ... are not interesting
+        #[automatically_derived]
+        #allow
+        impl<C> ::core::fmt::Debug for #name<C> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let mut debug = f.debug_struct(::core::stringify!(#name));
+                #(debug #debug_fields;)*
+                #debug_reforms
+                debug.finish_non_exhaustive()
+            }
+        }
+
+        #[automatically_derived]
+        #allow
+        impl<C> #name<C> {
+            /// The getters of the cache, in the order of the variants.
+            #vis const CALLBACKS: &'static [::yew_callbacks::CallbackInfo] = &[#(#infos),*];
+
+            #vis fn from_callback(dispatch: ::yew::callback::Callback<#enum_path>) -> Self {
+                Self::__new(dispatch, false)
+            }
+
+            /// Builds a cache whose callbacks do nothing, without a component to send the
+            /// messages to. The listeners of the enum are not registered either.
+            #vis fn inert() -> Self {
+                Self::__new(::yew::callback::Callback::from(|_| ()), true)
+            }
+
+            fn __new(dispatch: ::yew::callback::Callback<#enum_path>, inert: bool) -> Self {
+                #(#setups)*
+
+                #init_body
+            }
+
+            #erase
+
+            #(#constructors)*
+
+            #sweep
+
+            #(#builder_methods)*
+
+            #(#methods)*
+
+            #registry_methods
+        }
+
+        #[automatically_derived]
+        #allow
+        impl #name {
+            #vis fn from_dispatcher<R>(dispatcher: ::yew::functional::UseReducerDispatcher<R>) -> Self
+            where
+                R: ::yew::functional::Reducible<Action = #enum_path> + 'static,
+            {
+                Self::from_callback(::yew::callback::Callback::from(move |action| {
+                    dispatcher.dispatch(action)
+                }))
+            }
+
+            #erased_fns
+        }
+
+        #[automatically_derived]
+        #allow
+        impl<C: #component_bound> #name<C>
+        where
+            C::Message: ::core::convert::From<#enum_path>,
+        {
+            #vis fn new(link: ::yew::html::Scope<C>) -> Self {
+                #new_body
+            }
+
+            #reclaim
+
+            #test_mount
+        }
+
+        #[automatically_derived]
+        #allow
+        impl<C> ::core::convert::From<::yew::callback::Callback<#enum_path>> for #name<C> {
+            fn from(dispatch: ::yew::callback::Callback<#enum_path>) -> Self {
+                Self::from_callback(dispatch)
+            }
+        }
+
+        #[automatically_derived]
+        #allow
+        impl<C, R> ::core::convert::From<::yew::functional::UseReducerDispatcher<R>> for #name<C>
+        where
+            R: ::yew::functional::Reducible<Action = #enum_path> + 'static,
+        {
+            fn from(dispatcher: ::yew::functional::UseReducerDispatcher<R>) -> Self {
+                Self::from_callback(::yew::callback::Callback::from(move |action| {
+                    dispatcher.dispatch(action)
+                }))
+            }
+        }
+
+        #[automatically_derived]
+        #allow
+        impl<C: #component_bound> ::core::convert::From<::yew::html::Scope<C>> for #name<C>
+        where
+            C::Message: ::core::convert::From<#enum_path>,
+        {
+            fn from(link: ::yew::html::Scope<C>) -> Self {
+                Self::new(link)
+            }
+        }
+
+        #[automatically_derived]
+        #allow
+        impl<C: #component_bound> ::core::convert::From<&::yew::html::Scope<C>> for #name<C>
+        where
+            C::Message: ::core::convert::From<#enum_path>,
+        {
+            fn from(link: &::yew::html::Scope<C>) -> Self {
+                Self::new(link.to_owned())
+            }
+        }
+
+        #worker
+
+        #(#tuple_warnings)*
+
+        #(#builder_items)*
+
+        #(#key_items)*
+
+        #(#items)*
+
+        #kind
+
+        #props
+
+        #fill_props
+
+        #handle
+
+        #context
+
+        #hook
+    }
+}
+
+// one cell per name, the names are known in advance so they do not need a map
+fn by_name_parts(cx: &Context) -> Parts {
+    let Context {
+        e,
+        vis,
+        must_use,
+        tys,
+        curried_tys,
+        options,
+        ..
+    } = *cx;
+    if options.by_name || options.kind {
+        let arms = e
+            .variants
+            .iter()
+            .zip(tys.iter())
+            .zip(curried_tys.iter())
+            .filter(|(_, curried_ty)| curried_ty.is_none())
+            .map(|((variant, ty), _)| {
+                let fn_name = Ident::new(
+                    &variant.ident.to_string().to_snake_case(),
+                    Span::call_site(),
+                );
+                let name = fn_name.to_string();
+                // a callback that receives nothing ignores the event, one that receives anything
+                // but an event is not available
+                let make = if ty.to_string() == "()" {
+                    quote! {
+                        |this| ::core::option::Option::Some(
+                            this.#fn_name().reform(|_: ::yew::events::Event| ()),
+                        )
+                    }
+                } else {
+                    quote! {
+                        |this| {
+                            #[allow(unused_imports)]
+                            use ::yew_callbacks::__private::{ByNameEvent, ByNameOther};
+                            let cast = (&::yew_callbacks::__private::ByName::<#ty>::new())
+                                .cast::<::yew::events::Event>()?;
+                            let callback = this.#fn_name();
+                            ::core::option::Option::Some(::yew::callback::Callback::from(
+                                move |event: ::yew::events::Event| {
+                                    if let ::core::option::Option::Some(event) = cast(event) {
+                                        callback.emit(event);
+                                    }
+                                },
+                            ))
+                        }
+                    }
+                };
+
+                (name, make)
+            })
+            .enumerate()
+            .map(|(i, (name, make))| {
+                quote! {
+                    #name => (#i, #make),
+                }
+            })
+            .collect::<Vec<_>>();
+        let len = arms.len();
+
+        Parts {
+            fields: vec![quote! {
+                callbacks_by_name: [
+                    ::std::cell::OnceCell<::yew::callback::Callback<::yew::events::Event>>;
+                    #len
+                ],
+            }],
+            inits: vec![quote! {
+                callbacks_by_name: ::core::array::from_fn(|_| ::std::cell::OnceCell::new()),
+            }],
+            methods: vec![quote! {
+                #[track_caller]
+                #must_use
+                #vis fn by_name(
+                    &self,
+                    name: &str,
+                ) -> ::core::option::Option<::yew::callback::Callback<::yew::events::Event>> {
+                    let (i, make): (
+                        usize,
+                        fn(
+                            &Self,
+                        ) -> ::core::option::Option<
+                            ::yew::callback::Callback<::yew::events::Event>,
+                        >,
+                    ) = match name {
+                        #(#arms)*
+                        _ => return ::core::option::Option::None,
+                    };
+
+                    match self.callbacks_by_name[i].get() {
+                        ::core::option::Option::Some(callback) => {
+                            ::core::option::Option::Some(::core::clone::Clone::clone(callback))
+                        }
+                        ::core::option::Option::None => {
+                            let callback = make(self)?;
+                            ::core::option::Option::Some(::yew_callbacks::__private::get_or_init(
+                                &self.callbacks_by_name[i],
+                                || callback,
+                            ))
+                        }
+                    }
+                }
+            }],
+            ..Default::default()
+        }
+    } else {
+        Parts::default()
+    }
+}
+
+fn navigator_parts(cx: &Context, has_navigator: bool) -> Parts {
+    let Context { vis, .. } = *cx;
+    if has_navigator {
+        Parts {
+            fields: vec![quote! {
+                navigator: ::yew_callbacks::__private::NavigatorSlot,
+            }],
+            setups: vec![quote! {
+                let navigator = ::yew_callbacks::__private::NavigatorSlot::default();
+            }],
+            inits: vec![quote! {
+                navigator,
+            }],
+            methods: vec![quote! {
+                #vis fn set_navigator(
+                    &self,
+                    navigator: ::yew_callbacks::__private::yew_router::navigator::Navigator,
+                ) {
+                    self.navigator.set(move || ::core::option::Option::Some(navigator.clone()));
+                }
+            }],
+            ..Default::default()
+        }
+    } else {
+        Parts::default()
+    }
+}
+
+// a stored cache is reclaimed by the next component, its callbacks send their messages through
+// a relay that can be pointed at the new one
+fn relay_parts(cx: &Context) -> Parts {
+    let Context { enum_path, .. } = *cx;
+    if cfg!(feature = "registry") {
+        Parts {
+            fields: vec![quote! {
+                relay: ::std::rc::Rc<::std::cell::RefCell<::yew::callback::Callback<#enum_path>>>,
+            }],
+            setups: vec![quote! {
+                let relay = ::std::rc::Rc::new(::std::cell::RefCell::new(dispatch));
+                let dispatch = {
+                    let relay = relay.clone();
+                    ::yew::callback::Callback::from(move |msg: #enum_path| {
+                        let dispatch = relay.borrow().clone();
+                        dispatch.emit(msg)
+                    })
+                };
+            }],
+            inits: vec![quote! {
+                relay,
+            }],
+            ..Default::default()
+        }
+    } else {
+        Parts::default()
+    }
+}
+
+// with `diagnostics`, the messages sent by the callbacks of a dropped cache are reported
+fn alive_parts(cx: &Context) -> Parts {
+    let Context {
+        name, enum_path, ..
+    } = *cx;
+    if cfg!(feature = "diagnostics") {
+        Parts {
+            fields: vec![quote! {
+                alive: ::yew_callbacks::__private::Alive,
+            }],
+            setups: vec![quote! {
+                let alive = ::yew_callbacks::__private::Alive::default();
+                let dispatch = ::yew::callback::Callback::from(alive.watch(
+                    ::core::stringify!(#name),
+                    {
+                        let dispatch = dispatch.clone();
+                        move |msg: #enum_path| dispatch.emit(msg)
+                    },
+                ));
+            }],
+            inits: vec![quote! {
+                alive,
+            }],
+            ..Default::default()
+        }
+    } else {
+        Parts::default()
+    }
+}
+
+// with `#[guard]`, the messages of the variants that are not allowed are not sent either, for the
+// callbacks that do not come from a getter (`bind_*()`, `by_name()`, listeners, ...)
+fn guard_parts(
+    cx: &Context,
+    all_variants: &syn::punctuated::Punctuated<syn::Variant, syn::Token![,]>,
+) -> Parts {
+    let Context {
+        e,
+        vis,
+        enum_path,
+        tys,
+        ..
+    } = *cx;
+    let guards = all_variants
+        .iter()
+        .filter_map(|variant| {
+            let path = guard(variant)?;
+            let name = &variant.ident;
+            let pattern = match &variant.fields {
+                syn::Fields::Unit => quote! { #enum_path::#name },
+                syn::Fields::Unnamed(_) => quote! { #enum_path::#name(..) },
+                syn::Fields::Named(_) => quote! { #enum_path::#name { .. } },
+            };
+
+            Some(quote! {
+                #pattern => guard_context.allows(#path),
+            })
+        })
+        .collect::<Vec<_>>();
+    if guards.is_empty() {
+        return Parts::default();
+    }
+
+    let guard_context = Parts {
+        fields: vec![quote! {
+            guard_context: ::yew_callbacks::__private::GuardContext,
+        }],
+        setups: vec![quote! {
+            let guard_context = ::yew_callbacks::__private::GuardContext::default();
+            let dispatch = ::yew::callback::Callback::from({
+                let guard_context = guard_context.clone();
+                let dispatch = dispatch.clone();
+                move |msg: #enum_path| {
+                    let allowed = match &msg {
+                        #(#guards)*
+                        #[allow(unreachable_patterns)]
+                        _ => true,
+                    };
+                    if allowed {
+                        dispatch.emit(msg);
+                    }
+                }
+            });
+        }],
+        inits: vec![quote! {
+            guard_context,
+        }],
+        methods: vec![quote! {
+            /// Sets the context given to the functions of `#[guard]`, for example the role of the
+            /// user. The getters only return the callbacks it allows on the next render.
+            #vis fn set_guard_context<T: 'static>(&self, context: T) {
+                self.guard_context.set(context);
+            }
+        }],
+        ..Default::default()
+    };
+    let guarded = e
+        .variants
+        .iter()
+        .zip(tys.iter())
+        .filter(|(variant, _)| guard(variant).is_some())
+        .map(|(variant, ty)| {
+            let guarded_field = Ident::new(
+                &format!("guarded_{}", variant.ident.to_string().to_snake_case()),
+                Span::call_site(),
+            );
+
+            Parts {
+                fields: vec![quote! {
+                    #guarded_field: ::std::cell::OnceCell<::yew::callback::Callback<#ty>>,
+                }],
+                inits: vec![quote! {
+                    #guarded_field: ::core::default::Default::default(),
+                }],
+                ..Default::default()
+            }
+        });
+
+    std::iter::once(guard_context).chain(guarded).collect()
+}
+
+fn listener_parts(cx: &Context) -> Parts {
+    let Context { e, vis, .. } = *cx;
+    let listens = e.variants.iter().map(listen).collect::<Vec<_>>();
+    let mut parts = e
+        .variants
+        .iter()
+        .zip(listens.iter())
+        .filter_map(|(variant, listen)| Some((variant, listen.as_ref()?)))
+        .map(|(variant, listen)| {
+            let Listen {
+                target,
+                event_type,
+                detail,
+            } = listen;
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let listener_field = Ident::new(&format!("listener_{snake_name}"), Span::call_site());
+            let listen_name = Ident::new(&format!("listen_{snake_name}"), Span::call_site());
+            let stop_name = Ident::new(&format!("stop_{snake_name}"), Span::call_site());
+            let emit = match variant.fields.iter().next() {
+                None => quote! {
+                    move |_: ::yew_callbacks::__private::JsValue| callback.emit(())
+                },
+                Some(field) if is_fullscreen_change(event_type, field) => quote! {
+                    move |_: ::yew_callbacks::__private::JsValue| {
+                        callback.emit(::yew_callbacks::__private::is_fullscreen())
+                    }
+                },
+                // the messages of the service worker are deserialized into the field
+                Some(field) if is_service_worker(target) => {
+                    let ty = &field.ty;
+
+                    quote! {
+                        move |event: ::yew_callbacks::__private::JsValue| {
+                            if let ::core::option::Option::Some(message) =
+                                ::yew_callbacks::__private::decode_message::<#ty>(&event)
+                            {
+                                callback.emit(message);
+                            }
+                        }
+                    }
+                }
+                Some(_) if detail.is_some() => quote! {
+                    move |event: ::yew_callbacks::__private::JsValue| {
+                        if let ::core::option::Option::Some(detail) =
+                            ::yew_callbacks::__private::decode_detail::<#detail>(&event)
+                        {
+                            callback.emit(detail);
+                        }
+                    }
+                },
+                Some(field) => {
+                    let ty = &field.ty;
+
+                    quote! {
+                        move |event: #ty| callback.emit(event)
+                    }
+                }
+            };
+            let (start, listen_method) = match target {
+                Some(target) => (
+                    quote! {
+                        let callback = callbacks.#fn_name();
+                        callbacks.#listener_field.start(
+                            ::yew_callbacks::__private::ListenTarget::#target,
+                            #event_type,
+                            #emit,
+                        );
+                    },
+                    quote! {},
+                ),
+                // the element only exists once the component is rendered, it is safe to call
+                // `listen_*()` after every render: the listener only moves when the element changes
+                None => (
+                    quote! {},
+                    quote! {
+                        #vis fn #listen_name(&self, node_ref: &::yew::html::NodeRef) {
+                            let element =
+                                node_ref.cast::<::yew_callbacks::__private::web_sys::Element>();
+
+                            match element {
+                                ::core::option::Option::Some(element)
+                                    if !self.#listener_field.is_on(&element) =>
+                                {
+                                    let callback = self.#fn_name();
+                                    self.#listener_field.start(
+                                        ::yew_callbacks::__private::ListenTarget::Element(element),
+                                        #event_type,
+                                        #emit,
+                                    );
+                                }
+                                ::core::option::Option::Some(_) => {}
+                                ::core::option::Option::None => self.#listener_field.stop(),
+                            }
+                        }
+                    },
+                ),
+            };
+
+            Parts {
+                fields: vec![quote! {
+                    #listener_field: ::yew_callbacks::__private::Listener,
+                }],
+                inits: vec![quote! {
+                    #listener_field: ::core::default::Default::default(),
+                }],
+                starts: vec![start],
+                methods: vec![quote! {
+                    #listen_method
+
+                    #vis fn #stop_name(&self) {
+                        self.#listener_field.stop();
+                    }
+                }],
+                ..Default::default()
+            }
+        })
+        .collect::<Parts>();
+
+    // the state of the fullscreen mode is sent to the first variant listening to its changes
+    let fullscreen = e
+        .variants
+        .iter()
+        .zip(listens.iter())
+        .find(|(variant, listen)| {
+            let field = variant.fields.iter().next();
+            match (listen, field) {
+                (Some(listen), Some(field)) => is_fullscreen_change(&listen.event_type, field),
+                _ => false,
+            }
+        })
+        .map(|(variant, _)| {
+            let fn_name = Ident::new(
+                &variant.ident.to_string().to_snake_case(),
+                Span::call_site(),
+            );
+
+            quote! {
+                #vis fn request_fullscreen(&self, node_ref: &::yew::html::NodeRef) {
+                    let element =
+                        node_ref.cast::<::yew_callbacks::__private::web_sys::Element>();
+
+                    if !::yew_callbacks::__private::request_fullscreen(element) {
+                        self.#fn_name().emit(false);
+                    }
+                }
+
+                #vis fn exit_fullscreen(&self) {
+                    ::yew_callbacks::__private::exit_fullscreen();
+                }
+            }
+        });
+    parts.methods.extend(fullscreen);
+
+    parts
+}
+
+fn js_parts(cx: &Context) -> Parts {
+    let Context {
+        e,
+        vis,
+        name,
+        curried_tys,
+        ..
+    } = *cx;
+    let mut parts = e
+        .variants
+        .iter()
+        .zip(curried_tys.iter())
+        .filter_map(|(variant, curried_ty)| Some((variant, curried_ty, js(variant)?)))
+        .map(|(variant, curried_ty, forget)| {
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let js_field = Ident::new(&format!("js_{snake_name}"), Span::call_site());
+            let js_name = Ident::new(&format!("{snake_name}_js"), Span::call_site());
+            let forget_name = Ident::new(&format!("forget_{snake_name}_js"), Span::call_site());
+            let drop_name = Ident::new(&format!("drop_{snake_name}_js"), Span::call_site());
+            let key_ty = curried_ty.clone().unwrap_or_else(|| quote! { () });
+            let (args, args_sig) = variant
+                .fields
+                .iter()
+                .enumerate()
+                .filter(|(_, field)| is_curried(field))
+                .map(|(i, field)| {
+                    let ident = field
+                        .ident
+                        .clone()
+                        .unwrap_or_else(|| Ident::new(&format!("arg_{i}"), Span::call_site()));
+                    let ty = &field.ty;
+                    let sig = quote! { #ident: #ty };
+
+                    (ident, sig)
+                })
+                .unzip::<_, _, Vec<_>, Vec<_>>();
+            let keys = keys_of(key_struct(name, variant).as_ref(), &args);
+            let closure = match variant.fields.iter().find(|field| is_input(field)) {
+                None => quote! {
+                    ::yew_callbacks::__private::Closure::<dyn ::core::ops::Fn()>::new(move || {
+                        callback.emit(())
+                    })
+                },
+                Some(syn::Field { ty, .. }) => quote! {
+                    ::yew_callbacks::__private::Closure::<dyn ::core::ops::Fn(#ty)>::new(
+                        move |value| callback.emit(value),
+                    )
+                },
+            };
+
+            Parts {
+                fields: vec![quote! {
+                    #js_field: ::yew_callbacks::__private::JsFunctions<#key_ty>,
+                }],
+                inits: vec![quote! {
+                    #js_field: ::yew_callbacks::__private::JsFunctions::new(#forget),
+                }],
+                methods: vec![quote! {
+                    #vis fn #js_name(&self #(, #args_sig)*)
+                        -> ::yew_callbacks::__private::js_sys::Function
+                    {
+                        self.#js_field.get_or_insert(#keys, |#keys| {
+                            let callback = self.#fn_name(#(#args),*);
+                            #closure
+                        })
+                    }
+
+                    #vis fn #forget_name(&self #(, #args_sig)*) {
+                        self.#js_field.forget(&#keys);
+                    }
+
+                    #vis fn #drop_name(&self #(, #args_sig)*) {
+                        self.#js_field.remove(&#keys);
+                    }
+                }],
+                ..Default::default()
+            }
+        })
+        .collect::<Parts>();
+
+    let js_field_names = e
+        .variants
+        .iter()
+        .filter(|variant| variant.attrs.iter().any(|attr| attr.path.is_ident("js")))
+        .map(|variant| {
+            let snake_name = variant.ident.to_string().to_snake_case();
+            Ident::new(&format!("js_{snake_name}"), Span::call_site())
+        })
+        .collect::<Vec<_>>();
+    let clear_js = (!js_field_names.is_empty()).then(|| {
+        quote! {
+            #vis fn clear_js(&self) {
+                #(self.#js_field_names.clear();)*
+            }
+        }
+    });
+    parts.methods.extend(clear_js);
+
+    parts
+}
+
+// the callbacks of a variant that receives nothing, for an element that is not a button: the
+// keyboard sends the message too, like the click
+fn a11y_parts(cx: &Context) -> Parts {
+    let Context {
+        e,
+        vis,
+        name,
+        enum_path_name,
+        must_use,
+        tys,
+        curried_tys,
+        ..
+    } = *cx;
+    e.variants
+        .iter()
+        .zip(tys.iter())
+        .zip(curried_tys.iter())
+        .filter(|((variant, ty), _)| has_a11y(variant, ty))
+        .map(|((variant, _), curried_ty)| {
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let a11y_field = Ident::new(&format!("a11y_{snake_name}"), Span::call_site());
+            let a11y_name = Ident::new(&format!("{snake_name}_a11y"), Span::call_site());
+            let (args, args_sig) = variant
+                .fields
+                .iter()
+                .enumerate()
+                .filter(|(_, field)| is_curried(field))
+                .map(|(i, field)| {
+                    let ident = field
+                        .ident
+                        .clone()
+                        .unwrap_or_else(|| Ident::new(&format!("arg_{i}"), Span::call_site()));
+                    let ty = &field.ty;
+                    let sig = if is_option(ty) {
+                        quote! { #ident: impl ::core::convert::Into<#ty> }
+                    } else {
+                        quote! { #ident: #ty }
+                    };
+
+                    (ident, sig)
+                })
+                .unzip::<_, _, Vec<_>, Vec<_>>();
+            let conversions = variant
+                .fields
+                .iter()
+                .enumerate()
+                .filter(|(_, field)| is_curried(field) && is_option(&field.ty))
+                .map(|(i, field)| {
+                    let ident = field
+                        .ident
+                        .clone()
+                        .unwrap_or_else(|| Ident::new(&format!("arg_{i}"), Span::call_site()));
+                    let ty = &field.ty;
+
+                    quote! {
+                        let #ident: #ty = ::core::convert::Into::into(#ident);
+                    }
+                })
+                .collect::<Vec<_>>();
+            let keys = keys_of(key_struct(name, variant).as_ref(), &args);
+            // named in the panic messages of the cache
+            let path = format!("{enum_path_name}::{}", variant.ident);
+            let doc = format!(
+                " The callbacks of [`{fn_name}()`](Self::{fn_name}) for an element that is not a \
+                button: the first one goes to `onclick` and the second one to `onkeydown`, it \
+                sends the message on Enter and Space."
+            );
+
+            let pair = quote! {
+                (
+                    ::yew::callback::Callback<()>,
+                    (
+                        ::yew::callback::Callback<::yew::events::MouseEvent>,
+                        ::yew::callback::Callback<::yew::events::KeyboardEvent>,
+                    ),
+                )
+            };
+            // nothing is allocated until `<variant>_a11y()` is called, and only a curried variant
+            // keeps its pairs in a map
+            let (field_ty, get_or_replace, key) = if let Some(curried_ty) = curried_ty {
+                (
+                    quote! {
+                        ::std::cell::RefCell<
+                            ::yew_callbacks::__private::CurriedMap<#curried_ty, #pair>,
+                        >
+                    },
+                    quote! { get_or_replace },
+                    Some(quote! { #keys, }),
+                )
+            } else {
+                (
+                    quote! { ::std::cell::RefCell<#pair> },
+                    quote! { get_or_replace_one },
+                    None,
+                )
+            };
+
+            Parts {
+                fields: vec![quote! {
+                    #a11y_field: ::std::cell::OnceCell<#field_ty>,
+                }],
+                inits: vec![quote! {
+                    #a11y_field: ::core::default::Default::default(),
+                }],
+                methods: vec![quote! {
+                    #[doc = #doc]
+                    #[track_caller]
+                    #must_use
+                    #vis fn #a11y_name(&self #(, #args_sig)*) -> (
+                        ::yew::callback::Callback<::yew::events::MouseEvent>,
+                        ::yew::callback::Callback<::yew::events::KeyboardEvent>,
+                    ) {
+                        #(#conversions)*
+                        let callback = self.#fn_name(#(::core::clone::Clone::clone(&#args)),*);
+                        ::yew_callbacks::__private::#get_or_replace(
+                            &self.#a11y_field,
+                            #path,
+                            #key
+                            callback,
+                            |callback| {
+                                (
+                                    callback.reform(|_| ()),
+                                    ::yew::callback::Callback::from(
+                                        move |event: ::yew::events::KeyboardEvent| {
+                                            let key = event.key();
+                                            if key == "Enter" || key == " " {
+                                                // Space would scroll the page
+                                                event.prevent_default();
+                                                callback.emit(());
+                                            }
+                                        },
+                                    ),
+                                )
+                            },
+                        )
+                    }
+                }],
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn interval_parts(cx: &Context) -> Parts {
+    let Context { e, vis, .. } = *cx;
+    let intervals = e.variants.iter().map(interval).collect::<Vec<_>>();
+    e.variants
+        .iter()
+        .zip(intervals.iter())
+        .filter_map(|(variant, interval)| Some((variant, interval.as_ref()?)))
+        .map(|(variant, Interval { millis })| {
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let interval_field = Ident::new(&format!("interval_{snake_name}"), Span::call_site());
+            let start_name = Ident::new(&format!("start_{snake_name}"), Span::call_site());
+            let stop_name = Ident::new(&format!("stop_{snake_name}"), Span::call_site());
+            let is_running_name =
+                Ident::new(&format!("is_running_{snake_name}"), Span::call_site());
+            let tick = if variant.fields.is_empty() {
+                quote! {
+                    move |_| callback.emit(())
+                }
+            } else {
+                quote! {
+                    move |count| callback.emit(count)
+                }
+            };
+
+            Parts {
+                fields: vec![quote! {
+                    #interval_field: ::yew_callbacks::__private::IntervalSlot,
+                }],
+                inits: vec![quote! {
+                    #interval_field: ::core::default::Default::default(),
+                }],
+                starts: vec![quote! {
+                    callbacks.#start_name();
+                }],
+                methods: vec![quote! {
+                    #vis fn #start_name(&self) {
+                        let callback = self.#fn_name();
+                        self.#interval_field.start(#millis, #tick);
+                    }
+
+                    #vis fn #stop_name(&self) {
+                        self.#interval_field.stop();
+                    }
+
+                    #vis fn #is_running_name(&self) -> bool {
+                        self.#interval_field.is_running()
+                    }
+                }],
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn timeout_parts(cx: &Context) -> Parts {
+    let Context { e, vis, tys, .. } = *cx;
+    let timeouts = e.variants.iter().map(timeout).collect::<Vec<_>>();
+    e.variants
+        .iter()
+        .zip(tys.iter())
+        .zip(timeouts.iter())
+        .filter(|(_, timeout)| **timeout)
+        .map(|((variant, ty), _)| {
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let timeout_field = Ident::new(&format!("timeout_{snake_name}"), Span::call_site());
+            let schedule_name = Ident::new(&format!("schedule_{snake_name}"), Span::call_site());
+            let cancel_name = Ident::new(&format!("cancel_{snake_name}"), Span::call_site());
+            let (arg, value) = if variant.fields.is_empty() {
+                (quote! {}, quote! { () })
+            } else {
+                (quote! { , value: #ty }, quote! { value })
+            };
+
+            Parts {
+                fields: vec![quote! {
+                    #timeout_field: ::yew_callbacks::__private::TimeoutSlot,
+                }],
+                inits: vec![quote! {
+                    #timeout_field: ::core::default::Default::default(),
+                }],
+                methods: vec![quote! {
+                    #vis fn #schedule_name(&self, millis: u32 #arg) {
+                        let callback = self.#fn_name();
+                        self.#timeout_field.schedule(millis, move || callback.emit(#value));
+                    }
+
+                    #vis fn #cancel_name(&self) {
+                        self.#timeout_field.cancel();
+                    }
+                }],
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn raf_parts(cx: &Context) -> Parts {
+    let Context { e, vis, .. } = *cx;
+    let rafs = e.variants.iter().map(raf).collect::<Vec<_>>();
+    e.variants
+        .iter()
+        .zip(rafs.iter())
+        .filter(|(_, raf)| **raf)
+        .map(|(variant, _)| {
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let raf_field = Ident::new(&format!("raf_{snake_name}"), Span::call_site());
+            let start_name = Ident::new(&format!("start_{snake_name}"), Span::call_site());
+            let stop_name = Ident::new(&format!("stop_{snake_name}"), Span::call_site());
+
+            Parts {
+                fields: vec![quote! {
+                    #raf_field: ::yew_callbacks::__private::AnimationFrameSlot,
+                }],
+                inits: vec![quote! {
+                    #raf_field: ::core::default::Default::default(),
+                }],
+                methods: vec![quote! {
+                    #vis fn #start_name(&self) {
+                        let callback = self.#fn_name();
+                        self.#raf_field.start(move |timestamp| callback.emit(timestamp));
+                    }
+
+                    #vis fn #stop_name(&self) {
+                        self.#raf_field.stop();
+                    }
+                }],
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn idle_parts(cx: &Context) -> Parts {
+    let Context { e, vis, .. } = *cx;
+    e.variants
+        .iter()
+        .filter_map(|variant| Some((variant, idle(variant)?)))
+        .map(|(variant, Idle { timeout })| {
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let idle_field = Ident::new(&format!("idle_{snake_name}"), Span::call_site());
+            let start_name = Ident::new(&format!("start_{snake_name}"), Span::call_site());
+            let stop_name = Ident::new(&format!("stop_{snake_name}"), Span::call_site());
+            let is_running_name =
+                Ident::new(&format!("is_running_{snake_name}"), Span::call_site());
+            let timeout = match timeout {
+                Some(millis) => quote! { ::core::option::Option::Some(#millis) },
+                None => quote! { ::core::option::Option::None },
+            };
+
+            Parts {
+                fields: vec![quote! {
+                    #idle_field: ::yew_callbacks::__private::IdleSlot,
+                }],
+                inits: vec![quote! {
+                    #idle_field: ::core::default::Default::default(),
+                }],
+                methods: vec![quote! {
+                    #vis fn #start_name(&self) {
+                        let callback = self.#fn_name();
+                        self.#idle_field
+                            .start(#timeout, move |time_remaining| callback.emit(time_remaining));
+                    }
+
+                    #vis fn #stop_name(&self) {
+                        self.#idle_field.stop();
+                    }
+
+                    #vis fn #is_running_name(&self) -> bool {
+                        self.#idle_field.is_running()
+                    }
+                }],
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn intersection_parts(cx: &Context) -> Parts {
+    let Context { e, vis, .. } = *cx;
+    let intersections = e
+        .variants
+        .iter()
+        .map(observe_intersection)
+        .collect::<Vec<_>>();
+    e
+        .variants
+        .iter()
+        .zip(intersections.iter())
+        .filter_map(|(variant, intersection)| Some((variant, intersection.as_ref()?)))
+        .map(|(variant, ObserveIntersection { threshold })| {
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let intersection_field =
+                Ident::new(&format!("intersection_{snake_name}"), Span::call_site());
+            let observe_name = Ident::new(&format!("observe_{snake_name}"), Span::call_site());
+            let unobserve_name = Ident::new(&format!("unobserve_{snake_name}"), Span::call_site());
+            let key_ty = &variant
+                .fields
+                .iter()
+                .find(|field| is_curried(field))
+                .unwrap()
+                .ty;
+
+            Parts {
+                fields: vec![quote! {
+                    #intersection_field: ::yew_callbacks::__private::IntersectionObserverSlot<#key_ty>,
+                }],
+                inits: vec![quote! {
+                    #intersection_field: ::core::default::Default::default(),
+                }],
+                methods: vec![quote! {
+                    #vis fn #observe_name(&self, key: #key_ty, node_ref: &::yew::html::NodeRef) {
+                        if let ::core::option::Option::Some(element) =
+                            node_ref.cast::<::yew_callbacks::__private::web_sys::Element>()
+                        {
+                            let callback = self.#fn_name(key.clone());
+                            self.#intersection_field.observe(
+                                #threshold,
+                                key,
+                                element,
+                                move |is_intersecting| callback.emit(is_intersecting),
+                            );
+                        }
+                    }
+
+                    #vis fn #unobserve_name(&self, key: &#key_ty) {
+                        self.#intersection_field.unobserve(key);
+                    }
+                }],
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn media_query_parts(cx: &Context) -> Parts {
+    let Context { e, vis, .. } = *cx;
+    let media_queries = e.variants.iter().map(media_query).collect::<Vec<_>>();
+    e.variants
+        .iter()
+        .zip(media_queries.iter())
+        .filter_map(|(variant, query)| Some((variant, query.as_ref()?)))
+        .map(|(variant, query)| {
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let media_query_field =
+                Ident::new(&format!("media_query_{snake_name}"), Span::call_site());
+            let matches_name = Ident::new(&format!("matches_{snake_name}"), Span::call_site());
+
+            Parts {
+                fields: vec![quote! {
+                    #media_query_field: ::yew_callbacks::__private::MediaQuerySlot,
+                }],
+                inits: vec![quote! {
+                    #media_query_field: ::core::default::Default::default(),
+                }],
+                starts: vec![quote! {
+                    let callback = callbacks.#fn_name();
+                    callbacks
+                        .#media_query_field
+                        .start(#query, move |matches| callback.emit(matches));
+                }],
+                methods: vec![quote! {
+                    #vis fn #matches_name(&self) -> bool {
+                        self.#media_query_field.matches()
+                    }
+                }],
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn watcher_parts(cx: &Context) -> Parts {
+    let Context { e, vis, .. } = *cx;
+    [
+        ("connectivity", quote! { ConnectivitySlot }),
+        ("visibility", quote! { VisibilitySlot }),
+    ]
+    .iter()
+    .flat_map(|(attr_name, slot)| {
+        e.variants
+            .iter()
+            .filter(|variant| watcher(variant, attr_name))
+            .map(move |variant| (attr_name, slot, variant))
+    })
+    .map(|(attr_name, slot, variant)| {
+        let snake_name = variant.ident.to_string().to_snake_case();
+        let fn_name = Ident::new(&snake_name, Span::call_site());
+        let watcher_field = Ident::new(&format!("{attr_name}_{snake_name}"), Span::call_site());
+        let stop_name = Ident::new(&format!("stop_{snake_name}"), Span::call_site());
+
+        Parts {
+            fields: vec![quote! {
+                #watcher_field: ::yew_callbacks::__private::#slot,
+            }],
+            inits: vec![quote! {
+                #watcher_field: ::core::default::Default::default(),
+            }],
+            starts: vec![quote! {
+                let callback = callbacks.#fn_name();
+                callbacks
+                    .#watcher_field
+                    .start(move |state| callback.emit(state));
+            }],
+            methods: vec![quote! {
+                #vis fn #stop_name(&self) {
+                    self.#watcher_field.stop();
+                }
+            }],
+            ..Default::default()
+        }
+    })
+    .collect()
+}
+
+fn popstate_parts(cx: &Context) -> Parts {
+    let Context { e, vis, .. } = *cx;
+    e.variants
+        .iter()
+        .filter_map(|variant| Some((variant, popstate(variant)?)))
+        .map(|(variant, parse)| {
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let popstate_field = Ident::new(&format!("popstate_{snake_name}"), Span::call_site());
+            let stop_name = Ident::new(&format!("stop_{snake_name}"), Span::call_site());
+            let value = match parse {
+                Some(parse) => quote! { #parse(&location) },
+                None => quote! { location },
+            };
+
+            Parts {
+                fields: vec![quote! {
+                    #popstate_field: ::yew_callbacks::__private::PopStateSlot,
+                }],
+                inits: vec![quote! {
+                    #popstate_field: ::core::default::Default::default(),
+                }],
+                starts: vec![quote! {
+                    let callback = callbacks.#fn_name();
+                    callbacks
+                        .#popstate_field
+                        .start(move |location| callback.emit(#value));
+                }],
+                methods: vec![quote! {
+                    #vis fn #stop_name(&self) {
+                        self.#popstate_field.stop();
+                    }
+                }],
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn storage_parts(cx: &Context) -> Parts {
+    let Context { e, vis, .. } = *cx;
+    e.variants
+        .iter()
+        .filter_map(|variant| Some((variant, storage(variant)?)))
+        .map(|(variant, key)| {
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let storage_field = Ident::new(&format!("storage_{snake_name}"), Span::call_site());
+            let stop_name = Ident::new(&format!("stop_{snake_name}"), Span::call_site());
+
+            Parts {
+                fields: vec![quote! {
+                    #storage_field: ::yew_callbacks::__private::StorageSlot,
+                }],
+                inits: vec![quote! {
+                    #storage_field: ::core::default::Default::default(),
+                }],
+                starts: vec![quote! {
+                    let callback = callbacks.#fn_name();
+                    callbacks
+                        .#storage_field
+                        .start(#key, move |value| callback.emit(value));
+                }],
+                methods: vec![quote! {
+                    #vis fn #stop_name(&self) {
+                        self.#storage_field.stop();
+                    }
+                }],
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn broadcast_parts(cx: &Context) -> Parts {
+    let Context { e, vis, tys, .. } = *cx;
+    e.variants
+        .iter()
+        .zip(tys.iter())
+        .filter_map(|(variant, ty)| Some((variant, ty, broadcast(variant)?)))
+        .map(|(variant, ty, name)| {
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let broadcast_field = Ident::new(&format!("channel_{snake_name}"), Span::call_site());
+            let broadcast_name = Ident::new(&format!("broadcast_{snake_name}"), Span::call_site());
+            let stop_name = Ident::new(&format!("stop_{snake_name}"), Span::call_site());
+
+            Parts {
+                fields: vec![quote! {
+                    #broadcast_field: ::yew_callbacks::__private::BroadcastSlot,
+                }],
+                inits: vec![quote! {
+                    #broadcast_field: ::core::default::Default::default(),
+                }],
+                starts: vec![quote! {
+                    let callback = callbacks.#fn_name();
+                    callbacks
+                        .#broadcast_field
+                        .start(#name, move |value| callback.emit(value));
+                }],
+                methods: vec![quote! {
+                    #vis fn #broadcast_name(&self, value: #ty) {
+                        self.#broadcast_field.post(&value);
+                    }
+
+                    #vis fn #stop_name(&self) {
+                        self.#broadcast_field.stop();
+                    }
+                }],
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn socket_parts(cx: &Context) -> Parts {
+    let Context { e, vis, tys, .. } = *cx;
+    e.variants
+        .iter()
+        .zip(tys.iter())
+        .filter_map(|(variant, ty)| Some((variant, ty, websocket(variant, &e.variants)?)))
+        .map(|(variant, ty, websocket)| {
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let socket_field = Ident::new(&format!("socket_{snake_name}"), Span::call_site());
+            let connect_name = Ident::new(&format!("connect_{snake_name}"), Span::call_site());
+            let send_name = Ident::new(&format!("send_{snake_name}"), Span::call_site());
+            let close_name = Ident::new(&format!("close_{snake_name}"), Span::call_site());
+            let (decode, encode) = if websocket.json {
+                (
+                    quote! { ::yew_callbacks::__private::decode_json(frame) },
+                    quote! { ::yew_callbacks::__private::encode_json(&value) },
+                )
+            } else {
+                (
+                    quote! { ::yew_callbacks::__private::FromFrame::from_frame(frame) },
+                    quote! {
+                        ::core::option::Option::Some(
+                            ::yew_callbacks::__private::IntoFrame::into_frame(value),
+                        )
+                    },
+                )
+            };
+            let handlers = [&websocket.open, &websocket.error, &websocket.closed]
+                .iter()
+                .map(|variant| match variant {
+                    Some(variant) => {
+                        let fn_name =
+                            Ident::new(&variant.to_string().to_snake_case(), Span::call_site());
+
+                        quote! {
+                            ::core::option::Option::Some({
+                                let callback = self.#fn_name();
+                                ::std::boxed::Box::new(move || callback.emit(()))
+                            })
+                        }
+                    }
+                    None => quote! { ::core::option::Option::None },
+                })
+                .collect::<Vec<_>>();
+            let (open, error, closed) = (&handlers[0], &handlers[1], &handlers[2]);
+
+            Parts {
+                fields: vec![quote! {
+                    #socket_field: ::yew_callbacks::__private::WebSocketSlot,
+                }],
+                inits: vec![quote! {
+                    #socket_field: ::core::default::Default::default(),
+                }],
+                methods: vec![quote! {
+                    #vis fn #connect_name(&self, url: &str) {
+                        let callback = self.#fn_name();
+                        self.#socket_field.connect(
+                            url,
+                            ::yew_callbacks::__private::SocketHandlers {
+                                message: ::std::boxed::Box::new(move |frame| {
+                                    if let ::core::option::Option::Some(value) = #decode {
+                                        callback.emit(value);
+                                    }
+                                }),
+                                open: #open,
+                                error: #error,
+                                closed: #closed,
+                            },
+                        );
+                    }
+
+                    #vis fn #send_name(&self, value: #ty) {
+                        self.#socket_field.send(#encode);
+                    }
+
+                    #vis fn #close_name(&self) {
+                        self.#socket_field.close();
+                    }
+                }],
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn sensor_parts(cx: &Context) -> Parts {
+    let Context { e, vis, .. } = *cx;
+    e.variants
+        .iter()
+        .filter_map(|variant| Some((variant, sensor(variant)?)))
+        .map(|(variant, sensor)| {
+            let Sensor {
+                event_type,
+                interface,
+                extract,
+            } = sensor;
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let sensor_field = Ident::new(&format!("sensor_{snake_name}"), Span::call_site());
+            let request_name = Ident::new(
+                &format!("request_permission_{snake_name}"),
+                Span::call_site(),
+            );
+            let stop_name = Ident::new(&format!("stop_{snake_name}"), Span::call_site());
+            let extract = Ident::new(extract, Span::call_site());
+
+            Parts {
+                fields: vec![quote! {
+                    #sensor_field: ::yew_callbacks::__private::Listener,
+                }],
+                inits: vec![quote! {
+                    #sensor_field: ::core::default::Default::default(),
+                }],
+                starts: vec![quote! {
+                    let callback = callbacks.#fn_name();
+                    callbacks.#sensor_field.start(
+                        ::yew_callbacks::__private::ListenTarget::Window,
+                        #event_type,
+                        move |event: ::yew_callbacks::__private::JsValue| {
+                            callback.emit(::yew_callbacks::__private::#extract(&event))
+                        },
+                    );
+                }],
+                methods: vec![quote! {
+                    #vis fn #request_name(&self, done: impl ::core::ops::FnOnce(bool) + 'static) {
+                        ::yew_callbacks::__private::request_sensor_permission(#interface, done);
+                    }
+
+                    #vis fn #stop_name(&self) {
+                        self.#sensor_field.stop();
+                    }
+                }],
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn gamepad_parts(cx: &Context) -> Parts {
+    let Context { e, vis, .. } = *cx;
+    e.variants
+        .iter()
+        .filter(|variant| gamepad(variant))
+        .map(|variant| {
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let gamepad_field = Ident::new(&format!("gamepad_{snake_name}"), Span::call_site());
+            let stop_name = Ident::new(&format!("stop_{snake_name}"), Span::call_site());
+
+            Parts {
+                fields: vec![quote! {
+                    #gamepad_field: ::yew_callbacks::__private::GamepadSlot,
+                }],
+                inits: vec![quote! {
+                    #gamepad_field: ::core::default::Default::default(),
+                }],
+                starts: vec![quote! {
+                    let callback = callbacks.#fn_name();
+                    callbacks
+                        .#gamepad_field
+                        .start(move |input| callback.emit(input));
+                }],
+                methods: vec![quote! {
+                    #vis fn #stop_name(&self) {
+                        self.#gamepad_field.stop();
+                    }
+                }],
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn scroll_parts(cx: &Context) -> Parts {
+    let Context { e, vis, .. } = *cx;
+    e.variants
+        .iter()
+        .filter_map(|variant| Some((variant, scroll(variant)?)))
+        .map(|(variant, Scroll { node_ref, millis })| {
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let scroll_field = Ident::new(&format!("scroll_{snake_name}"), Span::call_site());
+            let listen_name = Ident::new(&format!("listen_{snake_name}"), Span::call_site());
+            let stop_name = Ident::new(&format!("stop_{snake_name}"), Span::call_site());
+
+            let (start, listen_method) = if node_ref {
+                // the element only exists once the component is rendered
+                (
+                    quote! {},
+                    quote! {
+                        #vis fn #listen_name(&self, node_ref: &::yew::html::NodeRef) {
+                            let element =
+                                node_ref.cast::<::yew_callbacks::__private::web_sys::Element>();
+
+                            if let ::core::option::Option::Some(element) = element {
+                                let callback = self.#fn_name();
+                                self.#scroll_field.start(
+                                    ::yew_callbacks::__private::ListenTarget::Element(element),
+                                    #millis,
+                                    move |position| callback.emit(position),
+                                );
+                            }
+                        }
+                    },
+                )
+            } else {
+                (
+                    quote! {
+                        let callback = callbacks.#fn_name();
+                        callbacks.#scroll_field.start(
+                            ::yew_callbacks::__private::ListenTarget::Window,
+                            #millis,
+                            move |position| callback.emit(position),
+                        );
+                    },
+                    quote! {},
+                )
+            };
+
+            Parts {
+                fields: vec![quote! {
+                    #scroll_field: ::yew_callbacks::__private::ScrollSlot,
+                }],
+                inits: vec![quote! {
+                    #scroll_field: ::core::default::Default::default(),
+                }],
+                starts: vec![start],
+                methods: vec![quote! {
+                    #listen_method
+
+                    #vis fn #stop_name(&self) {
+                        self.#scroll_field.stop();
+                    }
+                }],
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn bridge_parts(cx: &Context) -> Parts {
+    let Context { e, vis, .. } = *cx;
+    e.variants
+        .iter()
+        .filter_map(|variant| Some((variant, bridge(variant)?)))
+        .map(|(variant, Bridge { worker, path })| {
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let bridge_field = Ident::new(&format!("bridge_{snake_name}"), Span::call_site());
+            let send_name = Ident::new(&format!("send_to_{snake_name}"), Span::call_site());
+            let stop_name = Ident::new(&format!("stop_{snake_name}"), Span::call_site());
+
+            Parts {
+                fields: vec![quote! {
+                    #bridge_field: ::yew_callbacks::__private::BridgeSlot<#worker>,
+                }],
+                inits: vec![quote! {
+                    #bridge_field: ::core::default::Default::default(),
+                }],
+                starts: vec![quote! {
+                    let callback = callbacks.#fn_name();
+                    callbacks.#bridge_field.start(move || {
+                        <#worker as ::yew_callbacks::__private::gloo_worker::Spawnable>::spawner()
+                            .callback(move |output| callback.emit(output))
+                            .spawn(#path)
+                    });
+                }],
+                methods: vec![quote! {
+                    #vis fn #send_name(
+                        &self,
+                        input: <#worker as ::yew_callbacks::__private::gloo_worker::Worker>::Input,
+                    ) {
+                        self.#bridge_field.send(input);
+                    }
+
+                    #vis fn #stop_name(&self) {
+                        self.#bridge_field.stop();
+                    }
+                }],
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn form_parts(cx: &Context, form_variants: &[(&syn::Variant, FormVariant)]) -> Parts {
+    let Context {
+        vis,
+        enum_path,
+        enum_path_name,
+        must_use,
+        ..
+    } = *cx;
+    form_variants
+        .iter()
+        .map(|(variant, FormVariant { model, kind, error })| {
+            let name = &variant.ident;
+            let snake_name = name.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let form_field = Ident::new(&format!("form_{snake_name}"), Span::call_site());
+            let path = format!("{enum_path_name}::{name}");
+            let model_name = quote! { #model }.to_string().replace(' ', "");
+            let message = |value: TokenStream| match variant.fields.iter().next() {
+                Some(syn::Field {
+                    ident: Some(ident), ..
+                }) => quote! { #enum_path::#name { #ident: #value } },
+                _ => quote! { #enum_path::#name(#value) },
+            };
+            // without an error variant the values that are refused are simply not sent
+            let on_error = match error {
+                Some(error) => quote! {
+                    ::core::result::Result::Err(err) => dispatch.emit(#enum_path::#error(err)),
+                },
+                None => quote! {
+                    ::core::result::Result::Err(_) => {}
+                },
+            };
+
+            match kind {
+                FormKind::Input | FormKind::Change => {
+                    let event_ty = match kind {
+                        FormKind::Change => quote! { ::yew::events::Event },
+                        _ => quote! { ::yew::events::InputEvent },
+                    };
+                    let ok = message(quote! { edit });
+                    let callback = unless_inert(
+                        quote! { self.inert },
+                        quote! {{
+                            let dispatch = self.dispatch.clone();
+                            ::yew::callback::Callback::from(move |event: #event_ty| {
+                                let value = ::yew_callbacks::__private::element_value(&event);
+                                match <#model as ::yew_callbacks::Form>::parse(field, &value) {
+                                    ::core::result::Result::Ok(edit) => dispatch.emit(#ok),
+                                    #on_error
+                                }
+                            })
+                        }},
+                    );
+                    let doc = format!(
+                        "Sends [`{path}`] with the value of the element of `field` in \
+                        [`{model_name}`], parsed and validated."
+                    );
+
+                    Parts {
+                        fields: vec![quote! {
+                            #form_field: ::std::cell::RefCell<
+                                ::yew_callbacks::__private::CurriedMap<
+                                    <#model as ::yew_callbacks::Form>::Field,
+                                    ::yew::callback::Callback<#event_ty>,
+                                >,
+                            >,
+                        }],
+                        inits: vec![quote! {
+                            #form_field: ::core::default::Default::default(),
+                        }],
+                        methods: vec![quote! {
+                            #[doc = #doc]
+                            #[inline]
+                            #[track_caller]
+                            #must_use
+                            #vis fn #fn_name(
+                                &self,
+                                field: <#model as ::yew_callbacks::Form>::Field,
+                            ) -> ::yew::callback::Callback<#event_ty> {
+                                ::yew_callbacks::__private::get_or_insert(
+                                    &self.#form_field,
+                                    #path,
+                                    field,
+                                    |field| #callback,
+                                )
+                            }
+                        }],
+                        ..Default::default()
+                    }
+                }
+                FormKind::Submit => {
+                    let event_ty = quote! { ::yew::events::SubmitEvent };
+                    let ok = message(quote! { model });
+                    let callback = unless_inert(
+                        quote! { self.inert },
+                        quote! {{
+                            let dispatch = self.dispatch.clone();
+                            ::yew::callback::Callback::from(move |event: #event_ty| {
+                                let values = ::yew_callbacks::__private::form_values(&event);
+                                match <#model as ::yew_callbacks::Form>::from_values(values) {
+                                    ::core::result::Result::Ok(model) => dispatch.emit(#ok),
+                                    #on_error
+                                }
+                            })
+                        }},
+                    );
+                    let doc = format!(
+                        "Sends [`{path}`] with the [`{model_name}`] read from the submitted form, \
+                        without reloading the page."
+                    );
+
+                    Parts {
+                        fields: vec![quote! {
+                            #form_field: ::std::cell::OnceCell<
+                                ::yew::callback::Callback<#event_ty>,
+                            >,
+                        }],
+                        inits: vec![quote! {
+                            #form_field: ::core::default::Default::default(),
+                        }],
+                        methods: vec![quote! {
+                            #[doc = #doc]
+                            #[inline]
+                            #[track_caller]
+                            #must_use
+                            #vis fn #fn_name(&self) -> ::yew::callback::Callback<#event_ty> {
+                                ::yew_callbacks::__private::get_or_init(&self.#form_field, || {
+                                    #callback
+                                })
+                            }
+                        }],
+                        ..Default::default()
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+// a controlled input gets its value and a callback that reads the new one from the element, the
+// same variant can also be given to `onchange` and `onblur`
+fn bind_parts(cx: &Context, extractors: &[Option<(TokenStream, TokenStream)>]) -> Parts {
+    let Context {
+        e,
+        vis,
+        must_use,
+        curried_tys,
+        ..
+    } = *cx;
+    e.variants
+        .iter()
+        .zip(curried_tys.iter())
+        .zip(extractors.iter())
+        .filter(|((_, curried_ty), extractor)| {
+            cfg!(feature = "bind") && curried_ty.is_none() && extractor.is_none()
+        })
+        .filter_map(|((variant, _), _)| Some((variant, edit_input(variant)?)))
+        .map(|(variant, input)| {
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let edit_field = Ident::new(&format!("edit_callbacks_{snake_name}"), Span::call_site());
+            let edit_name = Ident::new(&format!("edit_{snake_name}"), Span::call_site());
+            let bind_name = Ident::new(&format!("bind_{snake_name}"), Span::call_site());
+            let callback = |event_ty: TokenStream| {
+                let value = match input {
+                    EditInput::Text => quote! {
+                        ::yew_callbacks::__private::element_value(&event)
+                    },
+                    EditInput::Event => quote! { event },
+                };
+                unless_inert(
+                    quote! { self.inert },
+                    quote! {{
+                        let callback = self.#fn_name();
+                        ::yew::callback::Callback::from(move |event: #event_ty| {
+                            callback.emit(::core::convert::From::from(#value))
+                        })
+                    }},
+                )
+            };
+            let oninput = callback(quote! { ::yew::events::InputEvent });
+            let onchange = callback(quote! { ::yew::events::Event });
+            let onblur = callback(quote! { ::yew::events::FocusEvent });
+            let edit_doc = format!(
+                "The callbacks of [`{fn_name}()`](Self::{fn_name}) for `oninput`, `onchange` and \
+                `onblur`."
+            );
+            let bind_doc = format!(
+                "The value and the `oninput` callback of a controlled input, the callback sends \
+                the new value with [`{fn_name}()`](Self::{fn_name})."
+            );
+            let bind_method = matches!(input, EditInput::Text).then(|| {
+                quote! {
+                    #[doc = #bind_doc]
+                    #[inline]
+                    #[track_caller]
+                    #must_use
+                    #vis fn #bind_name(
+                        &self,
+                        current: &::yew::AttrValue,
+                    ) -> ::yew_callbacks::InputBinding<
+                        ::yew::AttrValue,
+                        ::yew::callback::Callback<::yew::events::InputEvent>,
+                    > {
+                        ::yew_callbacks::InputBinding {
+                            value: ::core::clone::Clone::clone(current),
+                            oninput: ::yew_callbacks::__private::get_or_init(
+                                &self.#edit_field.oninput,
+                                || #oninput,
+                            ),
+                        }
+                    }
+                }
+            });
+
+            Parts {
+                fields: vec![quote! {
+                    #edit_field: ::yew_callbacks::EditCallbacks<
+                        ::std::cell::OnceCell<::yew::callback::Callback<::yew::events::InputEvent>>,
+                        ::std::cell::OnceCell<::yew::callback::Callback<::yew::events::Event>>,
+                        ::std::cell::OnceCell<::yew::callback::Callback<::yew::events::FocusEvent>>,
+                    >,
+                }],
+                inits: vec![quote! {
+                    #edit_field: ::core::default::Default::default(),
+                }],
+                methods: vec![quote! {
+                    #[doc = #edit_doc]
+                    #[inline]
+                    #[track_caller]
+                    #must_use
+                    #vis fn #edit_name(
+                        &self,
+                    ) -> ::yew_callbacks::EditCallbacks<
+                        ::yew::callback::Callback<::yew::events::InputEvent>,
+                        ::yew::callback::Callback<::yew::events::Event>,
+                        ::yew::callback::Callback<::yew::events::FocusEvent>,
+                    > {
+                        ::yew_callbacks::EditCallbacks {
+                            oninput: ::yew_callbacks::__private::get_or_init(
+                                &self.#edit_field.oninput,
+                                || #oninput,
+                            ),
+                            onchange: ::yew_callbacks::__private::get_or_init(
+                                &self.#edit_field.onchange,
+                                || #onchange,
+                            ),
+                            onblur: ::yew_callbacks::__private::get_or_init(
+                                &self.#edit_field.onblur,
+                                || #onblur,
+                            ),
+                        }
+                    }
+
+                    #bind_method
+                }],
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+// the other events of a variant are converted into its field and send the same message
+fn alias_parts(cx: &Context, navigates: &[Option<Navigate>]) -> Parts {
+    let Context {
+        e,
+        vis,
+        enum_path,
+        must_use,
+        ..
+    } = *cx;
+    e.variants
+        .iter()
+        .zip(navigates.iter())
+        .flat_map(|(variant, navigate)| {
+            aliases(variant)
+                .into_iter()
+                .map(move |alias| (variant, navigate, alias))
+        })
+        .map(|(variant, navigate, Alias { ty, map, suffix })| {
+            let name = &variant.ident;
+            let snake_name = name.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let alias_field =
+                Ident::new(&format!("alias_{snake_name}_{suffix}"), Span::call_site());
+            let alias_name = Ident::new(&format!("{snake_name}_{suffix}"), Span::call_site());
+            let values = variant
+                .fields
+                .iter()
+                .map(|field| {
+                    let value = default_value(field).unwrap_or_else(|| map.clone());
+
+                    match &field.ident {
+                        Some(ident) => quote! { #ident: #value },
+                        None => value,
+                    }
+                })
+                .collect::<Vec<_>>();
+            let message = match &variant.fields {
+                syn::Fields::Named(_) => quote! { #enum_path::#name { #(#values),* } },
+                _ => quote! { #enum_path::#name(#(#values),*) },
+            };
+            let message = validated(enum_path, variant, message);
+            let callback = unless_inert(
+                quote! { self.inert },
+                reform(
+                    deferred_dispatch(variant, quote! { self.dispatch }),
+                    quote! { self.navigator },
+                    &quote! { event: #ty },
+                    &message,
+                    navigate.as_ref(),
+                ),
+            );
+            let doc = format!(
+                "The same message as [`{fn_name}()`](Self::{fn_name}) from a `{}`.",
+                type_name(&quote! { #ty }),
+            );
+
+            Parts {
+                fields: vec![quote! {
+                    #alias_field: ::std::cell::OnceCell<::yew::callback::Callback<#ty>>,
+                }],
+                inits: vec![quote! {
+                    #alias_field: ::core::default::Default::default(),
+                }],
+                methods: vec![quote! {
+                    #[doc = #doc]
+                    #[inline]
+                    #[track_caller]
+                    #must_use
+                    #vis fn #alias_name(&self) -> ::yew::callback::Callback<#ty> {
+                        ::yew_callbacks::__private::get_or_init(&self.#alias_field, || {
+                            #callback
+                        })
+                    }
+                }],
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn external_parts(cx: &Context) -> Parts {
+    let Context { e, vis, tys, .. } = *cx;
+    e.variants
+        .iter()
+        .zip(tys.iter())
+        .filter(|(variant, _)| external(variant))
+        .map(|(variant, ty)| {
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let external_field = Ident::new(&format!("external_{snake_name}"), Span::call_site());
+            let set_name = Ident::new(&format!("set_external_{snake_name}"), Span::call_site());
+            let doc = format!(
+                " Sets the callback that [`{snake_name}()`](Self::{snake_name}) forwards to, \
+                usually given by the parent in the properties. The callback of the getter stays \
+                the same."
+            );
+
+            Parts {
+                fields: vec![quote! {
+                    #external_field: ::std::rc::Rc<
+                        ::std::cell::RefCell<
+                            ::core::option::Option<::yew::callback::Callback<#ty>>,
+                        >,
+                    >,
+                }],
+                inits: vec![quote! {
+                    #external_field: ::core::default::Default::default(),
+                }],
+                methods: vec![quote! {
+                    #[doc = #doc]
+                    #vis fn #set_name(
+                        &self,
+                        callback: impl ::core::convert::Into<
+                            ::core::option::Option<::yew::callback::Callback<#ty>>,
+                        >,
+                    ) {
+                        *self.#external_field.borrow_mut() = callback.into();
+                    }
+                }],
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+// a single callback for a whole list, the key of the item is read from the element
+fn delegated_parts(cx: &Context) -> Parts {
+    let Context {
+        e,
+        vis,
+        enum_path,
+        must_use,
+        ..
+    } = *cx;
+    e.variants
+        .iter()
+        .filter_map(|variant| Some((variant, delegate(variant)?)))
+        .map(|(variant, attribute)| {
+            let name = &variant.ident;
+            let snake_name = name.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let delegated_field = Ident::new(&format!("delegated_{snake_name}"), Span::call_site());
+            let delegated_name = Ident::new(&format!("{snake_name}_delegated"), Span::call_site());
+            let ty = variant
+                .fields
+                .iter()
+                .find(|field| is_input(field))
+                .map(|field| &field.ty);
+            let values = variant
+                .fields
+                .iter()
+                .map(|field| {
+                    let value = if is_curried(field) {
+                        quote! { key }
+                    } else {
+                        default_value(field).unwrap_or_else(|| quote! { event })
+                    };
+
+                    match &field.ident {
+                        Some(ident) => quote! { #ident: #value },
+                        None => value,
+                    }
+                })
+                .collect::<Vec<_>>();
+            let message = match &variant.fields {
+                syn::Fields::Named(_) => quote! { #enum_path::#name { #(#values),* } },
+                _ => quote! { #enum_path::#name(#(#values),*) },
+            };
+            let message = validated(enum_path, variant, message);
+            let dispatch = deferred_dispatch(variant, quote! { self.dispatch });
+            let callback = unless_inert(
+                quote! { self.inert },
+                quote! {{
+                    let dispatch = #dispatch.clone();
+                    ::yew::callback::Callback::from(move |event: #ty| {
+                        let key = ::yew_callbacks::__private::delegated_key(
+                            ::core::convert::AsRef::<::yew_callbacks::__private::web_sys::Event>
+                                ::as_ref(&event),
+                            #attribute,
+                        );
+                        if let ::core::option::Option::Some(key) = key {
+                            dispatch.emit(#message);
+                        }
+                    })
+                }},
+            );
+            let doc = format!(
+                " The same message as [`{fn_name}()`](Self::{fn_name}) for all the items at once, \
+                the key is read from the attribute `{}` of the closest element that has it.",
+                attribute.value(),
+            );
+
+            Parts {
+                fields: vec![quote! {
+                    #delegated_field: ::std::cell::OnceCell<::yew::callback::Callback<#ty>>,
+                }],
+                inits: vec![quote! {
+                    #delegated_field: ::core::default::Default::default(),
+                }],
+                methods: vec![quote! {
+                    #[doc = #doc]
+                    #[inline]
+                    #[track_caller]
+                    #must_use
+                    #vis fn #delegated_name(&self) -> ::yew::callback::Callback<#ty> {
+                        ::yew_callbacks::__private::get_or_init(&self.#delegated_field, || {
+                            #callback
+                        })
+                    }
+                }],
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn geolocation_parts(cx: &Context) -> Parts {
+    let Context { e, vis, .. } = *cx;
+    e.variants
+        .iter()
+        .filter(|variant| geolocation(variant))
+        .map(|variant| {
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let watch_field = Ident::new(&format!("geolocation_{snake_name}"), Span::call_site());
+            let start_name = Ident::new(&format!("start_{snake_name}"), Span::call_site());
+            let stop_name = Ident::new(&format!("stop_{snake_name}"), Span::call_site());
+            let is_running_name =
+                Ident::new(&format!("is_running_{snake_name}"), Span::call_site());
+
+            Parts {
+                fields: vec![quote! {
+                    #watch_field: ::yew_callbacks::__private::GeolocationSlot,
+                }],
+                inits: vec![quote! {
+                    #watch_field: ::core::default::Default::default(),
+                }],
+                methods: vec![quote! {
+                    #vis fn #start_name(&self) {
+                        let callback = self.#fn_name();
+                        self.#watch_field.watch(move |position| {
+                            if let ::core::option::Option::Some(position) =
+                                ::yew_callbacks::__private::FromPosition::from_position(position)
+                            {
+                                callback.emit(position);
+                            }
+                        });
+                    }
+
+                    #vis fn #stop_name(&self) {
+                        self.#watch_field.clear();
+                    }
+
+                    #vis fn #is_running_name(&self) -> bool {
+                        self.#watch_field.is_watching()
+                    }
+                }],
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+// a handle only holds the callback shared by all the keys and its own keys, it can be compared
+// in the properties of a child before any callback is created for it
+fn bound_parts(cx: &Context, makers: &[Option<(TokenStream, TokenStream)>]) -> Parts {
+    let Context {
+        e,
+        vis,
+        name,
+        enum_path_name,
+        must_use,
+        tys,
+        curried_tys,
+        options,
+        allow,
+        ..
+    } = *cx;
+    e
+        .variants
+        .iter()
+        .zip(tys.iter())
+        .zip(curried_tys.iter())
+        .zip(makers.iter())
+        .filter_map(|(((variant, ty), curried_ty), maker)| {
+            let curried_ty = curried_ty.as_ref()?;
+            if options.eager && maker.is_some() {
+                return None;
+            }
+            let fn_name = Ident::new(
+                &variant.ident.to_string().to_snake_case(),
+                Span::call_site(),
+            );
+            let bound_field = Ident::new(&format!("bound_{fn_name}"), Span::call_site());
+            let bound_name =
+                Ident::new(&format!("{name}Bound{}", variant.ident), Span::call_site());
+            let doc = format!(
+                " A handle returned by [`{name}::handle_{fn_name}()`], equal to the handles of the \
+                same cache and keys."
+            );
+            let factory_field = Ident::new(&format!("factory_{fn_name}"), Span::call_site());
+            let factory_name =
+                Ident::new(&format!("{name}{}Factory", variant.ident), Span::call_site());
+            let factory_doc = format!(
+                " Returned by [`{name}::{fn_name}_factory()`], creates the callbacks of any key \
+                on demand. Its clones share the callbacks and are equal to each other."
+            );
+            // named in the panic messages of the factory
+            let path = format!("{enum_path_name}::{}", variant.ident);
+
+            Some(
+            Parts {
+                fields: vec![quote! {
+                    #bound_field: ::std::cell::OnceCell<
+                        ::yew::callback::Callback<(#curried_ty, #ty)>,
+                    >,
+                    #factory_field: ::std::cell::OnceCell<#factory_name>,
+                }],
+                inits: vec![quote! {
+                    #bound_field: ::core::default::Default::default(),
+                    #factory_field: ::core::default::Default::default(),
+                }],
+                items: vec![quote! {
+                    #[doc = #doc]
+                    #allow
+                    #vis struct #bound_name {
+                        dispatcher: ::yew::callback::Callback<(#curried_ty, #ty)>,
+                        keys: #curried_ty,
+                    }
+
+                    #[automatically_derived]
+                    #allow
+                    impl #bound_name {
+                        /// The curried keys of the handle.
+                        #vis fn keys(&self) -> &#curried_ty {
+                            &self.keys
+                        }
+
+                        /// Sends the message of the keys, the same as the callback of the getter.
+                        #vis fn emit(&self, input: #ty) {
+                            self.dispatcher
+                                .emit((::core::clone::Clone::clone(&self.keys), input));
+                        }
+
+                        /// Creates a callback that sends the message of the keys. It is not
+                        /// cached, a new one is created on every call.
+                        #vis fn to_callback(&self) -> ::yew::callback::Callback<#ty> {
+                            let bound = ::core::clone::Clone::clone(self);
+                            ::yew::callback::Callback::from(move |input| bound.emit(input))
+                        }
+                    }
+
+                    #[automatically_derived]
+                    #allow
+                    impl ::core::clone::Clone for #bound_name {
+                        fn clone(&self) -> Self {
+                            Self {
+                                dispatcher: ::core::clone::Clone::clone(&self.dispatcher),
+                                keys: ::core::clone::Clone::clone(&self.keys),
+                            }
+                        }
+                    }
+
+                    #[automatically_derived]
+                    #allow
+                    impl ::core::cmp::PartialEq for #bound_name {
+                        fn eq(&self, other: &Self) -> bool {
+                            self.dispatcher == other.dispatcher && self.keys == other.keys
+                        }
+                    }
+
+                    #[automatically_derived]
+                    #allow
+                    impl ::core::fmt::Debug for #bound_name {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                            // the keys are not required to implement `Debug`
+                            f.debug_struct(::core::stringify!(#bound_name))
+                                .finish_non_exhaustive()
+                        }
+                    }
+
+                    #[doc = #factory_doc]
+                    #allow
+                    #vis struct #factory_name {
+                        dispatcher: ::yew::callback::Callback<(#curried_ty, #ty)>,
+                        callbacks: ::std::rc::Rc<
+                            ::std::cell::RefCell<
+                                ::yew_callbacks::__private::CurriedMap<
+                                    #curried_ty,
+                                    ::yew::callback::Callback<#ty>,
+                                >,
+                            >,
+                        >,
+                    }
+
+                    #[automatically_derived]
+                    #allow
+                    impl #factory_name {
+                        /// Returns the callback of the keys, the same one every time for the same
+                        /// keys.
+                        #[inline]
+                        #[track_caller]
+                        #must_use
+                        #vis fn get(&self, keys: #curried_ty) -> ::yew::callback::Callback<#ty> {
+                            ::yew_callbacks::__private::get_or_insert(
+                                &self.callbacks,
+                                #path,
+                                keys,
+                                |keys| {
+                                    self.dispatcher.reform(move |input| {
+                                        (::core::clone::Clone::clone(&keys), input)
+                                    })
+                                },
+                            )
+                        }
+
+                        /// Drops the callbacks whose keys are not in `current`, for example the
+                        /// items that have been scrolled out of view.
+                        #[track_caller]
+                        #vis fn retain<'a>(
+                            &self,
+                            current: impl ::core::iter::IntoIterator<Item = &'a #curried_ty>,
+                        ) {
+                            ::yew_callbacks::__private::retain_keys(&self.callbacks, #path, current);
+                        }
+                    }
+
+                    #[automatically_derived]
+                    #allow
+                    impl ::core::clone::Clone for #factory_name {
+                        fn clone(&self) -> Self {
+                            Self {
+                                dispatcher: ::core::clone::Clone::clone(&self.dispatcher),
+                                callbacks: ::core::clone::Clone::clone(&self.callbacks),
+                            }
+                        }
+                    }
+
+                    #[automatically_derived]
+                    #allow
+                    impl ::core::cmp::PartialEq for #factory_name {
+                        fn eq(&self, other: &Self) -> bool {
+                            ::std::rc::Rc::ptr_eq(&self.callbacks, &other.callbacks)
+                        }
+                    }
+
+                    #[automatically_derived]
+                    #allow
+                    impl ::core::fmt::Debug for #factory_name {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                            f.debug_struct(::core::stringify!(#factory_name))
+                                .field(
+                                    "callbacks",
+                                    &::yew_callbacks::__private::Cached::curried(&self.callbacks),
+                                )
+                                .finish_non_exhaustive()
+                        }
+                    }
+                }],
+                ..Default::default()
+            }
+            )
+        })
+        .collect()
+}
+
+fn event_source_parts(cx: &Context) -> Parts {
+    let Context { e, vis, .. } = *cx;
+    let event_source_handlers = e
+        .variants
+        .iter()
+        .filter_map(|variant| Some((variant, event_source(variant)?)))
+        .map(|(variant, EventSource { event_type, json })| {
+            let fn_name = Ident::new(
+                &variant.ident.to_string().to_snake_case(),
+                Span::call_site(),
+            );
+            let decode = if json {
+                quote! {
+                    ::yew_callbacks::__private::decode_json(
+                        ::yew_callbacks::__private::Frame::Text(data),
+                    )
+                }
+            } else {
+                quote! { ::core::option::Option::Some(data) }
+            };
+
+            quote! {
+                (#event_type, {
+                    let callback = self.#fn_name();
+                    ::std::boxed::Box::new(move |data: ::std::string::String| {
+                        if let ::core::option::Option::Some(value) = #decode {
+                            callback.emit(value);
+                        }
+                    })
+                }),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if event_source_handlers.is_empty() {
+        Parts::default()
+    } else {
+        Parts {
+            fields: vec![quote! {
+                event_source: ::yew_callbacks::__private::EventSourceSlot,
+            }],
+            inits: vec![quote! {
+                event_source: ::core::default::Default::default(),
+            }],
+            methods: vec![quote! {
+                #vis fn connect_event_source(&self, url: &str) {
+                    self.event_source.connect(url, ::std::vec![#(#event_source_handlers)*]);
+                }
+
+                #vis fn close_event_source(&self) {
+                    self.event_source.close();
+                }
+            }],
+            ..Default::default()
+        }
+    }
+}
+
+fn before_unload_parts(cx: &Context) -> Parts {
+    let Context { e, vis, .. } = *cx;
+    let before_unloads = e
+        .variants
+        .iter()
+        .filter_map(|variant| Some((variant, before_unload(variant)?)))
+        .collect::<Vec<_>>();
+    if let Some((variant, _)) = before_unloads.get(1) {
+        abort!(
+            variant.ident,
+            "`#[before_unload]` can only be used on one variant"
+        );
+    }
+    match before_unloads.first() {
+        Some((variant, when_dirty)) => {
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let stop_name = Ident::new(&format!("stop_{snake_name}"), Span::call_site());
+            let dirty_methods = when_dirty.then(|| {
+                quote! {
+                    #vis fn set_dirty(&self, dirty: bool) {
+                        self.before_unload.set_dirty(dirty);
+                    }
+
+                    #vis fn is_dirty(&self) -> bool {
+                        self.before_unload.is_dirty()
+                    }
+                }
+            });
+
+            Parts {
+                fields: vec![quote! {
+                    before_unload: ::yew_callbacks::__private::BeforeUnloadSlot,
+                }],
+                inits: vec![quote! {
+                    before_unload: ::core::default::Default::default(),
+                }],
+                starts: vec![quote! {
+                    let callback = callbacks.#fn_name();
+                    callbacks
+                        .before_unload
+                        .start(#when_dirty, move || callback.emit(()));
+                }],
+                methods: vec![quote! {
+                    #vis fn #stop_name(&self) {
+                        self.before_unload.stop();
+                    }
+
+                    #dirty_methods
+                }],
+                ..Default::default()
+            }
+        }
+        None => Parts::default(),
+    }
+}
+
+fn attach_parts(cx: &Context) -> Parts {
+    let Context { e, vis, .. } = *cx;
+    let methods = e
+        .variants
+        .iter()
+        .filter_map(|variant| Some((variant, attach(variant)?)))
+        .map(|(variant, event_type)| {
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let attach_name = Ident::new(&format!("attach_{snake_name}"), Span::call_site());
+            // registered with `passive: false` so the handler can call `prevent_default()`
+            let attach_active_name =
+                Ident::new(&format!("attach_{snake_name}_active"), Span::call_site());
+            let emit = match variant.fields.iter().next() {
+                None => quote! {
+                    move |_: ::yew_callbacks::__private::JsValue| callback.emit(())
+                },
+                Some(field) => {
+                    let ty = &field.ty;
+
+                    quote! {
+                        move |event: #ty| callback.emit(event)
+                    }
+                }
+            };
+
+            quote! {
+                #vis fn #attach_name(
+                    &self,
+                    target: &::yew_callbacks::__private::web_sys::EventTarget,
+                    options: ::yew_callbacks::EventListenerOptions,
+                ) -> ::yew_callbacks::ListenerHandle {
+                    let callback = self.#fn_name();
+                    ::yew_callbacks::__private::attach(target, #event_type, options, #emit)
+                }
+
+                #vis fn #attach_active_name(
+                    &self,
+                    node_ref: &::yew::html::NodeRef,
+                ) -> ::core::option::Option<::yew_callbacks::ListenerHandle> {
+                    let element = node_ref.cast::<::yew_callbacks::__private::web_sys::Element>()?;
+
+                    ::core::option::Option::Some(self.#attach_name(
+                        &element,
+                        ::yew_callbacks::EventListenerOptions::enable_prevent_default(),
+                    ))
+                }
+            }
+        })
+        .collect();
+
+    Parts {
+        methods,
+        ..Default::default()
+    }
+}
+
+// only the variants that receive events can be simulated, other types have no default value
+fn simulate_parts(cx: &Context, extractors: &[Option<(TokenStream, TokenStream)>]) -> Parts {
+    let Context { e, vis, tys, .. } = *cx;
+    let methods = e
+        .variants
+        .iter()
+        .zip(tys.iter())
+        .zip(extractors.iter())
+        .filter(|((variant, _), extractor)| {
+            let mut events = variant.fields.iter().filter(|field| is_input(field));
+
+            cfg!(feature = "test-util")
+                && match extractor {
+                    Some((ty, _)) => {
+                        syn::parse2::<syn::Type>(ty.clone()).is_ok_and(|ty| is_synthetic_type(&ty))
+                    }
+                    None => events.clone().next().is_some() && events.all(is_synthetic_event),
+                }
+        })
+        .map(|((variant, ty), _)| {
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let simulate_name = Ident::new(&format!("simulate_{snake_name}"), Span::call_site());
+            let (args, args_sig) = variant
+                .fields
+                .iter()
+                .enumerate()
+                .filter(|(_, field)| is_curried(field))
+                .map(|(i, field)| {
+                    let ident = field
+                        .ident
+                        .clone()
+                        .unwrap_or_else(|| Ident::new(&format!("arg_{i}"), Span::call_site()));
+                    let ty = &field.ty;
+                    let sig = quote! { #ident: #ty };
+
+                    (ident, sig)
+                })
+                .unzip::<_, _, Vec<_>, Vec<_>>();
+
+            quote! {
+                #[track_caller]
+                #vis fn #simulate_name(&self #(, #args_sig)*) {
+                    self.#fn_name(#(#args),*).emit(
+                        <#ty as ::yew_callbacks::__private::Synthetic>::synthetic(),
+                    );
+                }
+            }
+        })
+        .collect();
+
+    Parts {
+        methods,
+        ..Default::default()
+    }
+}
+
+fn clipboard_parts(cx: &Context, clipboards: &[Option<Clipboard>]) -> Parts {
+    let Context { e, vis, .. } = *cx;
+    let methods = e
+        .variants
+        .iter()
+        .zip(clipboards.iter())
+        .filter(|(_, clipboard)| matches!(clipboard, Some(Clipboard::Write)))
+        .map(|(variant, _)| {
+            let snake_name = variant.ident.to_string().to_snake_case();
+            let fn_name = Ident::new(&snake_name, Span::call_site());
+            let write_name = Ident::new(&format!("write_text_{snake_name}"), Span::call_site());
+            let done = if variant.fields.is_empty() {
+                quote! {
+                    move |written| {
+                        if written {
+                            callback.emit(());
+                        }
+                    }
+                }
+            } else {
+                quote! {
+                    move |written| callback.emit(written)
+                }
+            };
+
+            quote! {
+                #vis fn #write_name(
+                    &self,
+                    text: impl ::core::convert::Into<::std::string::String>,
+                ) {
+                    let callback = self.#fn_name();
+                    ::yew_callbacks::__private::write_clipboard(text.into(), #done);
+                }
+            }
+        })
+        .collect();
+
+    Parts {
+        methods,
+        ..Default::default()
+    }
+}
+
+fn allow_drop_parts(cx: &Context, drags: &[Option<syn::LitStr>]) -> Parts {
+    let Context { vis, must_use, .. } = *cx;
+    let has_drag = drags.iter().any(Option::is_some);
+    if has_drag {
+        Parts {
+            fields: vec![quote! {
+                allow_drop: ::std::cell::OnceCell<
+                    ::yew::callback::Callback<::yew::events::DragEvent>
+                >,
+            }],
+            inits: vec![quote! {
+                allow_drop: ::core::default::Default::default(),
+            }],
+            methods: vec![quote! {
+                #[inline]
+                #[track_caller]
+                #must_use
+                #vis fn allow_drop(&self) -> ::yew::callback::Callback<::yew::events::DragEvent> {
+                    ::yew_callbacks::__private::get_or_init(&self.allow_drop, || {
+                        ::yew::callback::Callback::from(|event: ::yew::events::DragEvent| {
+                            event.prevent_default()
+                        })
+                    })
+                }
+            }],
+            ..Default::default()
+        }
+    } else {
+        Parts::default()
+    }
+}
+
+fn pointer_capture_parts(cx: &Context, has_capture: bool) -> Parts {
+    let Context { vis, .. } = *cx;
+    if has_capture {
+        Parts {
+            fields: vec![quote! {
+                pointer_capture: ::yew_callbacks::__private::PointerCapture,
+            }],
+            setups: vec![quote! {
+                let pointer_capture = ::yew_callbacks::__private::PointerCapture::default();
+            }],
+            inits: vec![quote! {
+                pointer_capture,
+            }],
+            methods: vec![quote! {
+                #vis fn has_pointer_capture(&self) -> bool {
+                    self.pointer_capture.is_capturing()
+                }
+            }],
+            ..Default::default()
+        }
+    } else {
+        Parts::default()
+    }
+}
+
+// the callbacks adapted in the view are kept in a map by call site and key, like the curried
+// callbacks
+fn reforms_parts(cx: &Context, has_curried: bool) -> Parts {
+    let Context { vis, name, .. } = *cx;
+    if !has_curried {
+        return Parts::default();
+    }
+
+    Parts {
+        fields: vec![quote! {
+            reforms: ::std::cell::OnceCell<::yew_callbacks::__private::Reforms>,
+        }],
+        inits: vec![quote! {
+            reforms: ::core::default::Default::default(),
+        }],
+        methods: vec![quote! {
+            /// Returns the callback created by `make` the first time this line is run with this
+            /// `key`, and the same callback afterwards, for example a `reform()` of a getter in
+            /// `view()`. The key tells apart the callbacks of the same line, `()` if there is one.
+            #[track_caller]
+            #vis fn cached_reform<K, I>(
+                &self,
+                key: K,
+                make: impl ::core::ops::FnOnce() -> ::yew::callback::Callback<I>,
+            ) -> ::yew::callback::Callback<I>
+            where
+                K: ::core::hash::Hash + ::core::cmp::Eq + 'static,
+                I: 'static,
+            {
+                // no map is created until the first call
+                ::yew_callbacks::__private::get_or_init_ref(
+                    &self.reforms,
+                    ::core::default::Default::default,
+                )
+                .get_or_insert(
+                    ::core::stringify!(#name),
+                    ::core::panic::Location::caller(),
+                    key,
+                    make,
+                )
+            }
+
+            /// Drops the callbacks kept by `cached_reform()`, the next calls create them again.
+            #[track_caller]
+            #vis fn clear_reforms(&self) {
+                if let ::core::option::Option::Some(reforms) = self.reforms.get() {
+                    reforms.clear(::core::stringify!(#name));
+                }
+            }
+        }],
+        ..Default::default()
+    }
+}
+
+// the callbacks of an inert cache do not even look at what they receive
+fn unless_inert(inert: TokenStream, callback: TokenStream) -> TokenStream {
+    quote! {
+        if #inert {
+            ::yew::callback::Callback::from(|_| ())
+        } else {
+            #callback
+        }
+    }
+}
+
+fn reform(
+    dispatch: TokenStream,
+    navigator: TokenStream,
+    pat: &TokenStream,
+    body: &TokenStream,
+    navigate: Option<&Navigate>,
+) -> TokenStream {
+    match navigate {
+        None => quote! {
+            #dispatch.reform(move |#pat| #body)
+        },
+        Some(Navigate {
+            route,
+            silent: false,
+        }) => quote! {{
+            let navigator = #navigator.clone();
+            #dispatch.reform(move |#pat| {
+                navigator.push(&(#route));
+                #body
+            })
+        }},
+        Some(Navigate {
+            route,
+            silent: true,
+        }) => quote! {{
+            let navigator = #navigator.clone();
+            ::yew::callback::Callback::from(move |#pat| {
+                navigator.push(&(#route));
+                let _ = #body;
+            })
+        }},
+    }
+}
+
+// `#[deferred]` sends the message from a timeout so the event handler returns right away
+fn deferred_dispatch(variant: &syn::Variant, dispatch: TokenStream) -> TokenStream {
+    if !deferred(variant) {
+        return dispatch;
+    }
+
+    quote! {
+        ::yew::callback::Callback::from(::yew_callbacks::__private::defer({
+            let dispatch = #dispatch.clone();
+            move |message| dispatch.emit(message)
+        }))
+    }
+}
+
+// `#[curry]` only makes sense on the fields of a variant
+fn check_curry(variant: &syn::Variant) {
+    if let Some(attr) = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("curry"))
+    {
+        if variant.fields.is_empty() {
+            abort!(
+                attr,
+                "`#[curry]` cannot be used on a unit variant, there is no field to curry"
+            );
+        }
+        abort!(
+            attr,
+            "`#[curry]` goes on the fields to curry, not on the variant"
+        );
+    }
+
+    if let Some(field) = variant
+        .fields
+        .iter()
+        .find(|field| event_key(field).is_some())
+    {
+        let inputs = variant.fields.iter().filter(|field| is_input(field));
+        if inputs.count() != 1 {
+            abort!(
+                field,
+                "`#[curry(from_event = ...)]` requires a single field received by the callback"
+            );
+        }
+    }
+}
+
+#[derive(Default)]
+struct VariantOptions {
+    builder: bool,
+    skip: bool,
+    tuple: bool,
+}
+
+fn is_skipped(variant: &syn::Variant) -> bool {
+    variant_options(variant).skip
+}
+
+fn variant_options(variant: &syn::Variant) -> VariantOptions {
+    let mut options = VariantOptions::default();
+
+    for attr in variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("callbacks"))
+    {
+        let list = match attr.parse_meta() {
+            Ok(syn::Meta::List(list)) => list,
+            Ok(meta) => abort!(meta, "expected `#[callbacks(...)]`"),
+            Err(err) => abort!(err.span(), "{}", err),
+        };
+
+        for nested in list.nested.iter() {
+            match nested {
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("builder") => {
+                    let curried = variant.fields.iter().filter(|field| is_curried(field));
+                    if !matches!(variant.fields, syn::Fields::Named(_)) || curried.count() == 0 {
+                        abort!(
+                            path,
+                            "`#[callbacks(builder)]` requires a variant with named curried fields"
+                        );
+                    }
+                    options.builder = true;
+                }
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("skip") => {
+                    options.skip = true;
+                }
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("tuple") => {
+                    options.tuple = true;
+                }
+                _ => abort!(
+                    nested,
+                    "unknown `callbacks` argument on a variant, expected `builder`, `skip` or \
+                    `tuple`"
+                ),
+            }
+        }
+    }
+
+    options
+}
+
+#[derive(Clone, Copy)]
+enum EditInput {
+    Text,
+    Event,
+}
+
+// `String` and `AttrValue` are what the attribute `value` of an `<input>` takes and `Event` is what
+// `oninput`, `onchange` and `onblur` have in common; the variants of the features are sent by the
+// browser and not by an element
+fn edit_input(variant: &syn::Variant) -> Option<EditInput> {
+    if variant
+        .attrs
+        .iter()
+        .any(|attr| EXCLUSIVE_ATTRS.iter().any(|x| attr.path.is_ident(x)))
+    {
+        return None;
+    }
+
+    let mut fields = variant.fields.iter().filter(|field| is_input(field));
+    let field = match (fields.next(), fields.next()) {
+        (Some(field), None) => field,
+        _ => return None,
+    };
+    let segment = match &field.ty {
+        syn::Type::Path(ty) if ty.qself.is_none() => ty.path.segments.last()?,
+        _ => return None,
+    };
+
+    if segment.ident == "String" || segment.ident == "AttrValue" {
+        Some(EditInput::Text)
+    } else if segment.ident == "Event" {
+        Some(EditInput::Event)
+    } else {
+        None
+    }
+}
+
+// a callback receiving a tuple cannot be given to an element, which only shows up as a type error
+// in `html!`; the attributes of the features give the values themselves and are not concerned
+fn is_tuple_input(variant: &syn::Variant) -> bool {
+    let fields = variant.fields.iter().filter(|field| is_input(field));
+
+    fields.count() > 1
+        && !variant_options(variant).tuple
+        && !variant.attrs.iter().any(|attr| {
+            HELPER_ATTRS
+                .iter()
+                .any(|x| *x != "callbacks" && attr.path.is_ident(x))
+        })
+}
+
+// a trait object is fine as the input of a callback but it cannot be used as a key of the cache
+// nor be converted from an event, the errors of the generated code do not tell which variant it is
+fn check_trait_objects(variant: &syn::Variant, options: &Options) {
+    for field in variant.fields.iter() {
+        if !has_trait_object(&field.ty) || default_value(field).is_some() {
+            continue;
+        }
+
+        if is_curried(field) {
+            abort!(
+                field.ty,
+                "a trait object cannot be curried, it is neither `Clone` nor `Hash`{}",
+                SKIP_HINT
+            );
+        }
+        if options.by_name || options.kind {
+            abort!(
+                field.ty,
+                "a trait object cannot be converted from an event by `by_name`{}",
+                SKIP_HINT
+            );
+        }
+    }
+}
+
+const SKIP_HINT: &str = ", add `#[callbacks(skip)]` to the variant to leave it out";
+
+fn has_trait_object(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::TraitObject(_) => true,
+        syn::Type::Array(ty) => has_trait_object(&ty.elem),
+        syn::Type::Group(ty) => has_trait_object(&ty.elem),
+        syn::Type::Paren(ty) => has_trait_object(&ty.elem),
+        syn::Type::Ptr(ty) => has_trait_object(&ty.elem),
+        syn::Type::Reference(ty) => has_trait_object(&ty.elem),
+        syn::Type::Slice(ty) => has_trait_object(&ty.elem),
+        syn::Type::Tuple(ty) => ty.elems.iter().any(has_trait_object),
+        syn::Type::Path(ty) => ty
+            .path
+            .segments
+            .iter()
+            .any(|segment| match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) => args.args.iter().any(
+                    |arg| matches!(arg, syn::GenericArgument::Type(ty) if has_trait_object(ty)),
+                ),
+                _ => false,
+            }),
+        _ => false,
+    }
+}
+
+// the getters are documented even if the variants are not: the variant and the type of the
+// callback cannot be guessed from the name of the getter
+fn getter_doc(path: &str, variant: &syn::Variant, ty: &TokenStream) -> Vec<TokenStream> {
+    let mut lines = vec![format!(
+        " Returns the callback sending `{path}`, a `Callback<{}>`.",
+        type_name(ty),
+    )];
+    let curried = variant
+        .fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| is_curried(field))
+        .map(|(i, field)| {
+            let ty = &field.ty;
+            let ty = type_name(&quote! { #ty });
+
+            match &field.ident {
+                Some(ident) => format!("`{ident}: {ty}`"),
+                None => format!("`arg_{i}: {ty}`"),
+            }
+        })
+        .collect::<Vec<_>>();
+    if !curried.is_empty() {
+        lines.push(String::new());
+        lines.push(format!(
+            " There is one callback per value of {}.",
+            curried.join(", ")
+        ));
+    }
+
+    let mut doc = lines
+        .iter()
+        .map(|line| quote! { #[doc = #line] })
+        .collect::<Vec<_>>();
+    let variant_doc = variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .collect::<Vec<_>>();
+    if !variant_doc.is_empty() {
+        doc.push(quote! { #[doc = ""] });
+        doc.extend(variant_doc.into_iter().map(|attr| quote! { #attr }));
+    }
+
+    doc
+}
+
+// `quote!` puts spaces between all the tokens
+fn type_name(ty: &TokenStream) -> String {
+    let ty = match syn::parse2::<syn::Type>(ty.clone()) {
+        Ok(syn::Type::Paren(ty)) => *ty.elem,
+        Ok(ty) => ty,
+        Err(_) => return ty.to_string(),
+    };
+
+    quote! { #ty }
+        .to_string()
+        .replace(" :: ", "::")
+        .replace(":: ", "::")
+        .replace(" <", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace("& ", "&")
+        .replace("( ", "(")
+        .replace(" )", ")")
+}
+
+fn is_option(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(ty) => {
+            ty.qself.is_none()
+                && ty.path.segments.last().is_some_and(|segment| {
+                    segment.ident == "Option"
+                        && matches!(
+                            &segment.arguments,
+                            syn::PathArguments::AngleBracketed(args) if args.args.len() == 1
+                        )
+                })
+        }
+        _ => false,
+    }
+}
+
+fn is_curried(field: &syn::Field) -> bool {
+    field.attrs.iter().any(|x| {
+        x.path.get_ident().map(|x| x == "curry").unwrap_or(false)
+            && (x.tokens.is_empty() || matches!(curry_arg(x), CurryArg::Ttl(_)))
+    })
+}
+
+// the arguments of `#[curry(...)]`
+enum CurryArg {
+    FromEvent(syn::Path),
+    // in milliseconds
+    Ttl(u64),
+}
+
+fn curry_arg(attr: &syn::Attribute) -> CurryArg {
+    match attr.parse_args_with(|input: syn::parse::ParseStream| {
+        let ident = input.parse::<Ident>()?;
+        if ident != "from_event" && ident != "ttl" {
+            return Err(syn::Error::new(
+                ident.span(),
+                "expected `from_event` or `ttl`",
+            ));
+        }
+        input.parse::<syn::Token![=]>()?;
+        if ident == "from_event" {
+            return Ok(CurryArg::FromEvent(input.parse::<syn::Path>()?));
+        }
+        // `300s` is a single literal with the suffix `s`
+        let lit = input.parse::<syn::LitInt>()?;
+        let value = lit.base10_parse::<u64>()?;
+        let unit = match lit.suffix() {
+            "ms" => 1,
+            "s" => 1_000,
+            "min" => 60_000,
+            "h" => 3_600_000,
+            _ => {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    "expected a duration in `ms`, `s`, `min` or `h`, for example `300s`",
+                ))
+            }
+        };
+        if value == 0 {
+            return Err(syn::Error::new(lit.span(), "`ttl` cannot be 0"));
+        }
+        Ok(CurryArg::Ttl(value.saturating_mul(unit)))
+    }) {
+        Ok(arg) => arg,
+        Err(err) => abort!(err.span(), "{}", err),
+    }
+}
+
+// `#[curry(ttl = 300s)]` drops the callbacks of the variant that have not been used for that long
+fn curry_ttl(variant: &syn::Variant) -> Option<u64> {
+    let mut ttls = variant
+        .fields
+        .iter()
+        .flat_map(|field| field.attrs.iter())
+        .filter(|attr| attr.path.is_ident("curry") && !attr.tokens.is_empty())
+        .filter_map(|attr| match curry_arg(attr) {
+            CurryArg::Ttl(ttl) => Some((attr, ttl)),
+            CurryArg::FromEvent(_) => None,
+        });
+    let (attr, ttl) = ttls.next()?;
+    if !cfg!(feature = "ttl") {
+        abort!(
+            attr,
+            "`#[curry(ttl = ...)]` requires the `ttl` feature of `yew-callbacks`"
+        );
+    }
+    if let Some((attr, _)) = ttls.next() {
+        abort!(
+            attr,
+            "`ttl` applies to all the callbacks of the variant, it can only be given once"
+        );
+    }
+
+    Some(ttl)
+}
+
+// `<variant>_a11y()` is generated for the variants whose callback receives nothing and that are
+// not sent by the browser itself
+fn has_a11y(variant: &syn::Variant, ty: &TokenStream) -> bool {
+    ty.to_string() == "()"
+        && !variant
+            .attrs
+            .iter()
+            .any(|attr| EXCLUSIVE_ATTRS.iter().any(|x| attr.path.is_ident(x)))
+}
+
+// a variant with several named curried fields is keyed by a struct of its own instead of a tuple,
+// the keys keep their names in the signatures of the cache and in the debug output
+fn key_struct(cache_name: &Ident, variant: &syn::Variant) -> Option<Ident> {
+    let named = matches!(variant.fields, syn::Fields::Named(_));
+    let curried = variant
+        .fields
+        .iter()
+        .filter(|field| is_curried(field))
+        .count();
+
+    (named && curried > 1).then(|| {
+        Ident::new(
+            &format!("{cache_name}{}Key", variant.ident),
+            Span::call_site(),
+        )
+    })
+}
+
+// the keys of a variant, either to build them or as a pattern binding them to `args`
+fn keys_of(key_struct: Option<&Ident>, args: &[impl ToTokens]) -> TokenStream {
+    match key_struct {
+        Some(key_struct) => quote! { #key_struct { #(#args),* } },
+        None => quote! { (#(#args),*) },
+    }
+}
+
+// `#[curry(from_event = path)]` is not given to the getter, the key is read from what the callback
+// receives so a single callback is shared by all the keys
+fn event_key(field: &syn::Field) -> Option<syn::Path> {
+    field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("curry") && !attr.tokens.is_empty())
+        .find_map(|attr| match curry_arg(attr) {
+            CurryArg::FromEvent(path) => Some(path),
+            CurryArg::Ttl(_) => None,
+        })
+}
+
+fn with_event_keys(
+    variant: &syn::Variant,
+    source: &TokenStream,
+    ty: &TokenStream,
+    body: TokenStream,
+) -> TokenStream {
+    let keys = variant
+        .fields
+        .iter()
+        .enumerate()
+        .filter_map(|(i, field)| {
+            let path = event_key(field)?;
+            let ident = field
+                .ident
+                .clone()
+                .unwrap_or_else(|| Ident::new(&format!("arg_{i}"), Span::call_site()));
+
+            // the closure does not know what it receives until the message is built
+            Some(quote! {
+                let #ident = {
+                    let source: &#ty = &#source;
+                    #path(source)
+                };
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if keys.is_empty() {
+        body
+    } else {
+        quote! {{
+            #(#keys)*
+            #body
+        }}
+    }
+}
+
+// `#[skip]` fills the field itself, it is neither curried nor received by the callback
+fn default_value(field: &syn::Field) -> Option<TokenStream> {
+    let attr = field.attrs.iter().find(|attr| attr.path.is_ident("skip"))?;
+
+    if is_curried(field) || event_key(field).is_some() {
+        abort!(attr, "a field cannot be both curried and skipped");
+    }
+
+    if attr.tokens.is_empty() {
+        return Some(quote! { ::core::default::Default::default() });
+    }
+
+    match attr.parse_args::<syn::Expr>() {
+        Ok(value) => Some(quote! { #value }),
+        Err(err) => abort!(err.span(), "{}", err),
+    }
+}
+
+fn is_input(field: &syn::Field) -> bool {
+    !is_curried(field) && event_key(field).is_none() && default_value(field).is_none()
+}
+
+struct Navigate {
+    route: TokenStream,
+    silent: bool,
+}
+
+fn navigate(variant: &syn::Variant) -> Option<Navigate> {
+    let variant_attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("navigate"));
+    let field = variant.fields.iter().enumerate().find(|(_, field)| {
+        field
+            .attrs
+            .iter()
+            .any(|attr| attr.path.is_ident("navigate"))
+    });
+    let span = match (variant_attr, field) {
+        (None, None) => return None,
+        (Some(_), Some((_, field))) => abort!(
+            field,
+            "`#[navigate]` cannot be used on both the variant and one of its fields"
+        ),
+        (Some(attr), None) => attr.path.span(),
+        (None, Some((_, field))) => field.span(),
+    };
+
+    if !cfg!(feature = "router") {
+        abort!(
+            span,
+            "`#[navigate]` requires the `router` feature of `yew-callbacks`"
+        );
+    }
+
+    if let Some((i, field)) = field {
+        let route = field
+            .ident
+            .clone()
+            .unwrap_or_else(|| Ident::new(&format!("arg_{i}"), Span::call_site()));
+
+        return Some(Navigate {
+            route: quote! { #route },
+            silent: false,
+        });
+    }
+
+    let attr = variant_attr.unwrap();
+    match attr.parse_args_with(|input: syn::parse::ParseStream| {
+        let route = input.parse::<syn::Expr>()?;
+        let silent = if input.parse::<Option<syn::Token![,]>>()?.is_some() {
+            let ident = input.parse::<Ident>()?;
+            if ident != "silent" {
+                return Err(syn::Error::new(ident.span(), "expected `silent`"));
+            }
+            true
+        } else {
+            false
+        };
+
+        Ok(Navigate {
+            route: quote! { #route },
+            silent,
+        })
+    }) {
+        Ok(navigate) => Some(navigate),
+        Err(err) => abort!(err.span(), "{}", err),
+    }
+}
+
+struct Listen {
+    // `None` when the element of a `NodeRef` is given to `listen_*()`
+    target: Option<Ident>,
+    event_type: syn::LitStr,
+    detail: Option<syn::Type>,
+}
+
+fn listen(variant: &syn::Variant) -> Option<Listen> {
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("listen"))?;
+
+    if !cfg!(feature = "listen") {
+        abort!(
+            attr.path,
+            "`#[listen]` requires the `listen` feature of `yew-callbacks`"
+        );
+    }
+
+    if variant.fields.iter().any(is_curried) {
+        abort!(
+            variant,
+            "`#[listen]` cannot be used on a variant with curried fields"
+        );
+    }
+
+    if variant.fields.len() > 1 {
+        abort!(
+            variant.fields,
+            "`#[listen]` requires a variant with at most one field"
+        );
+    }
+
+    let listen = match attr.parse_args_with(|input: syn::parse::ParseStream| {
+        // the target can be omitted for custom events, they are dispatched on the window by default
+        let target = if input.peek2(syn::Token![=]) {
+            Some(Ident::new("Window", Span::call_site()))
+        } else {
+            let target = input.parse::<Ident>()?;
+            let target = match target.to_string().as_str() {
+                "window" => Some(Ident::new("Window", target.span())),
+                "document" => Some(Ident::new("Document", target.span())),
+                "node_ref" => None,
+                "service_worker" if cfg!(feature = "service-worker") => {
+                    Some(Ident::new("ServiceWorker", target.span()))
+                }
+                "service_worker" => {
+                    return Err(syn::Error::new(
+                        target.span(),
+                        "`service_worker` requires the `service-worker` feature",
+                    ))
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        target.span(),
+                        "expected `window`, `document`, `node_ref` or `service_worker`",
+                    ))
+                }
+            };
+            input.parse::<syn::Token![,]>()?;
+            target
+        };
+
+        if input.peek(syn::LitStr) {
+            let event_type = input.parse::<syn::LitStr>()?;
+
+            return Ok(Listen {
+                target,
+                event_type,
+                detail: None,
+            });
+        }
+
+        let ident = input.parse::<Ident>()?;
+        if ident != "custom" {
+            return Err(syn::Error::new(ident.span(), "expected `custom`"));
+        }
+        if is_service_worker(&target) {
+            return Err(syn::Error::new(
+                ident.span(),
+                "the service worker does not send custom events",
+            ));
+        }
+        if !cfg!(feature = "custom-event") {
+            return Err(syn::Error::new(
+                ident.span(),
+                "custom events require the `custom-event` feature of `yew-callbacks`",
+            ));
+        }
+        input.parse::<syn::Token![=]>()?;
+        let event_type = input.parse::<syn::LitStr>()?;
+
+        let detail = if input.is_empty() {
+            None
+        } else {
+            input.parse::<syn::Token![,]>()?;
+            let ident = input.parse::<Ident>()?;
+            if ident != "detail" {
+                return Err(syn::Error::new(ident.span(), "expected `detail`"));
+            }
+            input.parse::<syn::Token![=]>()?;
+            Some(input.parse::<syn::Type>()?)
+        };
+
+        Ok(Listen {
+            target,
+            event_type,
+            detail,
+        })
+    }) {
+        Ok(listen) => listen,
+        Err(err) => abort!(err.span(), "{}", err),
+    };
+
+    if listen.detail.is_some() && variant.fields.is_empty() {
+        abort!(
+            variant,
+            "`#[listen(custom = ..., detail = ...)]` requires a variant with a field for the detail"
+        );
+    }
+
+    Some(listen)
+}
+
+fn is_service_worker(target: &Option<Ident>) -> bool {
+    target
+        .as_ref()
+        .is_some_and(|target| target == "ServiceWorker")
+}
+
+struct Interval {
+    millis: u32,
+}
+
+fn is_fullscreen_change(event_type: &syn::LitStr, field: &syn::Field) -> bool {
+    event_type.value() == "fullscreenchange"
+        && matches!(&field.ty, syn::Type::Path(ty) if ty.path.is_ident("bool"))
+}
+
+// the events that `__private::Synthetic` can create
+const SYNTHETIC_EVENTS: &[&str] = &[
+    "Event",
+    "AnimationEvent",
+    "DragEvent",
+    "FocusEvent",
+    "InputEvent",
+    "KeyboardEvent",
+    "MouseEvent",
+    "PointerEvent",
+    "SubmitEvent",
+    "TouchEvent",
+    "TransitionEvent",
+    "WheelEvent",
+];
+
+fn is_synthetic_event(field: &syn::Field) -> bool {
+    is_synthetic_type(&field.ty)
+}
+
+fn is_synthetic_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(ty) => {
+            ty.qself.is_none()
+                && ty.path.segments.last().is_some_and(|segment| {
+                    segment.arguments.is_empty()
+                        && SYNTHETIC_EVENTS.iter().any(|x| segment.ident == x)
+                })
+        }
+        _ => false,
+    }
+}
+
+struct Input {
+    ty: TokenStream,
+    // converts `event` into the value of the field
+    convert: TokenStream,
+}
+
+fn input_type(variant: &syn::Variant) -> Option<Input> {
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("input"))?;
+
+    if let Some(other) = variant.attrs.iter().find(|other| {
+        ["clipboard", "data_transfer", "drop_files"]
+            .iter()
+            .chain(EXCLUSIVE_ATTRS)
+            .any(|x| other.path.is_ident(x))
+    }) {
+        abort!(
+            other.path,
+            "`#[input]` cannot be used together with `#[{}]`",
+            other.path.get_ident().unwrap()
+        );
+    }
+    if let Some((_, closure)) = transform(variant) {
+        abort!(
+            closure,
+            "`#[input]` cannot be used together with an inline transformation"
+        );
+    }
+
+    let events = variant.fields.iter().filter(|field| is_input(field));
+    if events.count() != 1 {
+        abort!(
+            attr,
+            "`#[input]` requires a variant with a single field that is not curried"
+        );
+    }
+
+    match attr.parse_args_with(|input: syn::parse::ParseStream| {
+        let ty = input.parse::<syn::Type>()?;
+        let with = if input.parse::<Option<syn::Token![,]>>()?.is_some() {
+            let ident = input.parse::<Ident>()?;
+            if ident != "with" {
+                return Err(syn::Error::new(ident.span(), "expected `with`"));
+            }
+            input.parse::<syn::Token![=]>()?;
+            Some(input.parse::<syn::Path>()?)
+        } else {
+            None
+        };
+
+        Ok(Input {
+            ty: quote! { #ty },
+            convert: match with {
+                Some(with) => quote! { #with(event) },
+                None => quote! { ::core::convert::From::from(event) },
+            },
+        })
+    }) {
+        Ok(input) => Some(input),
+        Err(err) => abort!(err.span(), "{}", err),
+    }
+}
+
+struct Alias {
+    ty: syn::Type,
+    // converts `event` into the value of the field
+    map: TokenStream,
+    // the suffix of the getter, `touch` for `TouchEvent`
+    suffix: String,
+}
+
+fn aliases(variant: &syn::Variant) -> Vec<Alias> {
+    let attrs = variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("alias"))
+        .collect::<Vec<_>>();
+    let attr = match attrs.first() {
+        Some(attr) => attr,
+        None => return Vec::new(),
+    };
+
+    if let Some(other) = variant.attrs.iter().find(|other| {
+        ["capture"]
+            .iter()
+            .chain(EXCLUSIVE_ATTRS)
+            .any(|x| other.path.is_ident(x))
+    }) {
+        abort!(
+            other.path,
+            "`#[alias]` cannot be used together with `#[{}]`",
+            other.path.get_ident().unwrap()
+        );
+    }
+    if let Some(field) = variant
+        .fields
+        .iter()
+        .find(|field| is_curried(field) || event_key(field).is_some())
+    {
+        abort!(
+            field,
+            "`#[alias]` cannot be used on a variant with curried fields"
+        );
+    }
+
+    let inputs = variant.fields.iter().filter(|field| is_input(field));
+    if inputs.count() != 1 {
+        abort!(attr, "`#[alias]` requires a variant with a single field");
+    }
+
+    let aliases = attrs
+        .into_iter()
+        .map(|attr| {
+            match attr.parse_args_with(|input: syn::parse::ParseStream| {
+                let ty = input.parse::<syn::Type>()?;
+                let map = if input.parse::<Option<syn::Token![,]>>()?.is_some() {
+                    let ident = input.parse::<Ident>()?;
+                    if ident != "map" {
+                        return Err(syn::Error::new(ident.span(), "expected `map`"));
+                    }
+                    input.parse::<syn::Token![=]>()?;
+                    let map = input.parse::<syn::Path>()?;
+                    quote! { #map(event) }
+                } else {
+                    quote! { ::core::convert::From::from(event) }
+                };
+                let suffix = match &ty {
+                    syn::Type::Path(path) if path.qself.is_none() => path
+                        .path
+                        .segments
+                        .last()
+                        .map(|segment| segment.ident.to_string()),
+                    _ => None,
+                };
+                let suffix = match suffix {
+                    Some(suffix) => suffix.trim_end_matches("Event").to_snake_case(),
+                    None => {
+                        return Err(syn::Error::new(
+                            ty.span(),
+                            "expected the name of a type, for example `TouchEvent`",
+                        ))
+                    }
+                };
+
+                Ok(Alias { ty, map, suffix })
+            }) {
+                Ok(alias) => alias,
+                Err(err) => abort!(err.span(), "{}", err),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    for (i, alias) in aliases.iter().enumerate() {
+        if aliases[..i]
+            .iter()
+            .any(|other| other.suffix == alias.suffix)
+        {
+            abort!(
+                alias.ty,
+                "another `#[alias]` of this variant already ends with `{}`",
+                alias.suffix
+            );
+        }
+    }
+
+    aliases
+}
+
+fn external(variant: &syn::Variant) -> bool {
+    let attr = match variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("external"))
+    {
+        Some(attr) => attr,
+        None => return false,
+    };
+
+    if !attr.tokens.is_empty() {
+        abort!(attr.tokens, "`#[external]` does not take any argument");
+    }
+
+    if let Some(other) = variant.attrs.iter().find(|other| {
+        [
+            "alias", "capture", "deferred", "delegate", "input", "navigate", "timeout", "validate",
+        ]
+        .iter()
+        .chain(EXCLUSIVE_ATTRS)
+        .any(|x| other.path.is_ident(x))
+    }) {
+        abort!(
+            other.path,
+            "`#[external]` cannot be used together with `#[{}]`",
+            other.path.get_ident().unwrap()
+        );
+    }
+
+    if let Some(field) = variant
+        .fields
+        .iter()
+        .find(|field| is_curried(field) || event_key(field).is_some())
+    {
+        abort!(
+            field,
+            "`#[external]` cannot be used on a variant with curried fields"
+        );
+    }
+
+    true
+}
+
+fn guard(variant: &syn::Variant) -> Option<syn::Path> {
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("guard"))?;
+
+    let path = match attr.parse_args::<syn::Path>() {
+        Ok(path) => path,
+        Err(_) => abort!(
+            attr,
+            "`#[guard]` expects the path of a function, for example `#[guard(can_edit)]`"
+        ),
+    };
+
+    if let Some(other) = variant
+        .attrs
+        .iter()
+        .find(|other| other.path.is_ident("external"))
+    {
+        abort!(
+            other.path,
+            "`#[guard]` cannot be used together with `#[external]`, the parent decides what its \
+            callback does"
+        );
+    }
+
+    Some(path)
+}
+
+fn delegate(variant: &syn::Variant) -> Option<syn::LitStr> {
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("delegate"))?;
+
+    if !cfg!(feature = "delegate") {
+        abort!(
+            attr.path,
+            "`#[delegate]` requires the `delegate` feature of `yew-callbacks`"
+        );
+    }
+
+    if let Some(other) = variant.attrs.iter().find(|other| {
+        ["capture", "input", "navigate"]
+            .iter()
+            .chain(EXCLUSIVE_ATTRS)
+            .any(|x| other.path.is_ident(x))
+    }) {
+        abort!(
+            other.path,
+            "`#[delegate]` cannot be used together with `#[{}]`",
+            other.path.get_ident().unwrap()
+        );
+    }
+
+    if let Some(field) = variant
+        .fields
+        .iter()
+        .find(|field| event_key(field).is_some())
+    {
+        abort!(
+            field,
+            "`#[delegate]` already reads the key from the event, `from_event` cannot be used"
+        );
+    }
+
+    let curried = variant.fields.iter().filter(|field| is_curried(field));
+    let inputs = variant.fields.iter().filter(|field| is_input(field));
+    if curried.count() != 1 || inputs.count() != 1 {
+        abort!(
+            attr,
+            "`#[delegate]` requires a variant with a single curried field and a single field for \
+            the event"
+        );
+    }
+
+    match attr.parse_args::<syn::LitStr>() {
+        Ok(attribute) => Some(attribute),
+        Err(err) => abort!(
+            err.span(),
+            "expected the name of the attribute that holds the key, for example \
+            `#[delegate(\"data-row\")]`"
+        ),
+    }
+}
+
+struct Validate {
+    check: syn::Path,
+    error: Ident,
+}
+
+fn validate(variant: &syn::Variant) -> Option<Validate> {
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("validate"))?;
+
+    let inputs = variant.fields.iter().filter(|field| is_input(field));
+    if inputs.count() != 1 {
+        abort!(
+            attr,
+            "`#[validate]` requires a variant with a single field that is not curried"
+        );
+    }
+
+    match attr.parse_args_with(|input: syn::parse::ParseStream| {
+        let check = input.parse::<syn::Path>()?;
+        if input.parse::<Option<syn::Token![,]>>()?.is_none() {
+            return Err(syn::Error::new(
+                input.span(),
+                "expected the variant of the errors, for example `error = Invalid`",
+            ));
+        }
+        let ident = input.parse::<Ident>()?;
+        if ident != "error" {
+            return Err(syn::Error::new(ident.span(), "expected `error`"));
+        }
+        input.parse::<syn::Token![=]>()?;
+        let error = input.parse::<Ident>()?;
+
+        Ok(Validate { check, error })
+    }) {
+        Ok(validate) => Some(validate),
+        Err(err) => abort!(err.span(), "{}", err),
+    }
+}
+
+// the message is built as usual, its value is checked and the error is sent instead if it is
+// refused
+fn validated(enum_path: &TokenStream, variant: &syn::Variant, message: TokenStream) -> TokenStream {
+    let Validate { check, error } = match validate(variant) {
+        Some(validate) => validate,
+        None => return message,
+    };
+    let name = &variant.ident;
+    let pattern = match &variant.fields {
+        syn::Fields::Named(syn::FieldsNamed { named: fields, .. }) => {
+            let ident = fields
+                .iter()
+                .find(|field| is_input(field))
+                .and_then(|field| field.ident.as_ref());
+
+            quote! { #enum_path::#name { #ident: value, .. } }
+        }
+        fields => {
+            let values = fields.iter().map(|field| {
+                if is_input(field) {
+                    quote! { value }
+                } else {
+                    quote! { _ }
+                }
+            });
+
+            quote! { #enum_path::#name(#(#values),*) }
+        }
+    };
+
+    quote! {{
+        let message = #message;
+        let checked = match &message {
+            #pattern => #check(value),
+            #[allow(unreachable_patterns)]
+            _ => ::core::unreachable!(),
+        };
+        match checked {
+            ::core::result::Result::Ok(()) => message,
+            ::core::result::Result::Err(err) => #enum_path::#error(err),
+        }
+    }}
+}
+
+// `define_callbacks!` accepts a closure in place of the discriminant: the callback receives the
+// argument of the closure and sends the variant with the value it returns
+fn transform(variant: &syn::Variant) -> Option<(&syn::Type, &syn::ExprClosure)> {
+    let closure = match &variant.discriminant {
+        Some((_, syn::Expr::Closure(closure))) => closure,
+        _ => return None,
+    };
+
+    if variant
+        .attrs
+        .iter()
+        .any(|attr| attr.path.is_ident("clipboard") || attr.path.is_ident("drag"))
+    {
+        abort!(
+            closure,
+            "an inline transformation cannot be combined with `#[clipboard]` or `#[drag]`"
+        );
+    }
+    if variant.fields.len() != 1 || variant.fields.iter().any(is_curried) {
+        abort!(
+            closure,
+            "an inline transformation requires a variant with exactly one field and no `#[curry]`"
+        );
+    }
+    match closure.inputs.iter().collect::<Vec<_>>().as_slice() {
+        [syn::Pat::Type(pat)] => Some((&pat.ty, closure)),
+        [_] => abort!(
+            closure.inputs,
+            "the argument of the transformation needs a type, like `|event: InputEvent| ...`"
+        ),
+        _ => abort!(
+            closure.inputs,
+            "the transformation takes exactly one argument, the event"
+        ),
+    }
+}
+
+fn attach(variant: &syn::Variant) -> Option<syn::LitStr> {
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("attach"))?;
+
+    if !cfg!(feature = "listen") {
+        abort!(
+            attr.path,
+            "`#[attach]` requires the `listen` feature of `yew-callbacks`"
+        );
+    }
+
+    if variant.fields.iter().any(is_curried) {
+        abort!(
+            variant,
+            "`#[attach]` cannot be used on a variant with curried fields"
+        );
+    }
+
+    if variant.fields.len() > 1 {
+        abort!(
+            variant.fields,
+            "`#[attach]` requires a variant with at most one field"
+        );
+    }
+
+    match attr.parse_args::<syn::LitStr>() {
+        Ok(event_type) => Some(event_type),
+        Err(err) => abort!(err.span(), "{}", err),
+    }
+}
+
+fn js(variant: &syn::Variant) -> Option<bool> {
+    let attr = variant.attrs.iter().find(|attr| attr.path.is_ident("js"))?;
+
+    if !cfg!(feature = "js-interop") {
+        abort!(
+            attr.path,
+            "`#[js]` requires the `js-interop` feature of `yew-callbacks`"
+        );
+    }
+
+    let mut values = variant.fields.iter().filter(|field| is_input(field));
+    if values.nth(1).is_some() {
+        abort!(
+            variant.fields,
+            "`#[js]` requires a variant with at most one field that is not curried"
+        );
+    }
+
+    if attr.tokens.is_empty() {
+        return Some(false);
+    }
+
+    match attr.parse_args::<Ident>() {
+        Ok(ident) if ident == "forget" => Some(true),
+        Ok(ident) => abort!(ident, "expected `forget`"),
+        Err(err) => abort!(err.span(), "{}", err),
+    }
+}
+
+fn interval(variant: &syn::Variant) -> Option<Interval> {
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("interval"))?;
+
+    if !cfg!(feature = "timers") {
+        abort!(
+            attr.path,
+            "`#[interval]` requires the `timers` feature of `yew-callbacks`"
+        );
+    }
+
+    if variant
+        .attrs
+        .iter()
+        .any(|attr| attr.path.is_ident("listen"))
+    {
+        abort!(
+            attr.path,
+            "`#[interval]` cannot be used together with `#[listen]`"
+        );
+    }
+
+    if variant.fields.iter().any(is_curried) {
+        abort!(
+            variant,
+            "`#[interval]` cannot be used on a variant with curried fields"
+        );
+    }
+
+    if variant.fields.len() > 1 {
+        abort!(
+            variant.fields,
+            "`#[interval]` requires a variant with at most one field"
+        );
+    }
+
+    match attr.parse_args_with(|input: syn::parse::ParseStream| {
+        let ident = input.parse::<Ident>()?;
+        if ident != "ms" {
+            return Err(syn::Error::new(ident.span(), "expected `ms`"));
+        }
+        input.parse::<syn::Token![=]>()?;
+        let millis = input.parse::<syn::LitInt>()?.base10_parse::<u32>()?;
+
+        Ok(Interval { millis })
+    }) {
+        Ok(interval) => Some(interval),
+        Err(err) => abort!(err.span(), "{}", err),
+    }
+}
+
+fn timeout(variant: &syn::Variant) -> bool {
+    let attr = match variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("timeout"))
+    {
+        Some(attr) => attr,
+        None => return false,
+    };
+
+    if !cfg!(feature = "timers") {
+        abort!(
+            attr.path,
+            "`#[timeout]` requires the `timers` feature of `yew-callbacks`"
+        );
+    }
+
+    if !attr.tokens.is_empty() {
+        abort!(attr.tokens, "`#[timeout]` does not take any argument");
+    }
+
+    if variant.fields.iter().any(is_curried) {
+        abort!(
+            variant,
+            "`#[timeout]` cannot be used on a variant with curried fields"
+        );
+    }
+
+    true
+}
+
+fn deferred(variant: &syn::Variant) -> bool {
+    let attr = match variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("deferred"))
+    {
+        Some(attr) => attr,
+        None => return false,
+    };
+
+    if !cfg!(feature = "timers") {
+        abort!(
+            attr.path,
+            "`#[deferred]` requires the `timers` feature of `yew-callbacks`"
+        );
+    }
+
+    if !attr.tokens.is_empty() {
+        abort!(attr.tokens, "`#[deferred]` does not take any argument");
+    }
+
+    if let Some(other) = variant
+        .attrs
+        .iter()
+        .find(|other| EXCLUSIVE_ATTRS.iter().any(|x| other.path.is_ident(x)))
+    {
+        abort!(
+            other.path,
+            "`#[deferred]` cannot be used together with `#[{}]`",
+            other.path.get_ident().unwrap()
+        );
+    }
+
+    true
+}
+
+fn raf(variant: &syn::Variant) -> bool {
+    let attr = match variant.attrs.iter().find(|attr| attr.path.is_ident("raf")) {
+        Some(attr) => attr,
+        None => return false,
+    };
+
+    if !cfg!(feature = "render") {
+        abort!(
+            attr.path,
+            "`#[raf]` requires the `render` feature of `yew-callbacks`"
+        );
+    }
+
+    if !attr.tokens.is_empty() {
+        abort!(attr.tokens, "`#[raf]` does not take any argument");
+    }
+
+    if variant
+        .attrs
+        .iter()
+        .any(|attr| attr.path.is_ident("listen") || attr.path.is_ident("interval"))
+    {
+        abort!(
+            attr.path,
+            "`#[raf]` cannot be used together with `#[listen]` or `#[interval]`"
+        );
+    }
+
+    if variant.fields.len() != 1 || variant.fields.iter().any(is_curried) {
+        abort!(
+            variant,
+            "`#[raf]` requires a variant with a single `f64` field for the timestamp"
+        );
+    }
+
+    true
+}
+
+struct Idle {
+    timeout: Option<u32>,
+}
+
+fn idle(variant: &syn::Variant) -> Option<Idle> {
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("idle"))?;
+
+    if !cfg!(feature = "idle") {
+        abort!(
+            attr.path,
+            "`#[idle]` requires the `idle` feature of `yew-callbacks`"
+        );
+    }
+
+    if let Some(other) = variant.attrs.iter().find(|other| {
+        EXCLUSIVE_ATTRS
+            .iter()
+            .any(|x| *x != "idle" && other.path.is_ident(x))
+    }) {
+        abort!(
+            other.path,
+            "`#[idle]` cannot be used together with `#[{}]`",
+            other.path.get_ident().unwrap()
+        );
+    }
+
+    if variant.fields.len() != 1 || variant.fields.iter().any(is_curried) {
+        abort!(
+            variant,
+            "`#[idle]` requires a variant with a single `f64` field for the remaining time"
+        );
+    }
+
+    if attr.tokens.is_empty() {
+        return Some(Idle { timeout: None });
+    }
+
+    match attr.parse_args_with(|input: syn::parse::ParseStream| {
+        let ident = input.parse::<Ident>()?;
+        if ident != "timeout" {
+            return Err(syn::Error::new(ident.span(), "expected `timeout`"));
+        }
+        input.parse::<syn::Token![=]>()?;
+        let millis = input.parse::<syn::LitInt>()?.base10_parse::<u32>()?;
+
+        Ok(Idle {
+            timeout: Some(millis),
+        })
+    }) {
+        Ok(idle) => Some(idle),
+        Err(err) => abort!(err.span(), "{}", err),
+    }
+}
+
+// attributes that generate a `stop_*()` method, only one of them can be used on a variant
+const EXCLUSIVE_ATTRS: &[&str] = &[
+    "listen",
+    "interval",
+    "raf",
+    "idle",
+    "connectivity",
+    "visibility",
+    "popstate",
+    "storage",
+    "broadcast",
+    "geolocation",
+    "bridge",
+    "before_unload",
+    "device_orientation",
+    "device_motion",
+    "scroll",
+    "gamepad",
+];
+
+// attributes that only send a `bool` when a state of the browser changes
+fn watcher(variant: &syn::Variant, name: &str) -> bool {
+    let attr = match variant.attrs.iter().find(|attr| attr.path.is_ident(name)) {
+        Some(attr) => attr,
+        None => return false,
+    };
+
+    if !cfg!(feature = "listen") {
+        abort!(
+            attr.path,
+            "`#[{}]` requires the `listen` feature of `yew-callbacks`",
+            name
+        );
+    }
+
+    if !attr.tokens.is_empty() {
+        abort!(attr.tokens, "`#[{}]` does not take any argument", name);
+    }
+
+    if let Some(other) = variant.attrs.iter().find(|other| {
+        EXCLUSIVE_ATTRS
+            .iter()
+            .any(|x| *x != name && other.path.is_ident(x))
+    }) {
+        abort!(
+            other.path,
+            "`#[{}]` cannot be used together with `#[{}]`",
+            name,
+            other.path.get_ident().unwrap()
+        );
+    }
+
+    if variant.fields.len() != 1 || variant.fields.iter().any(is_curried) {
+        abort!(
+            variant,
+            "`#[{}]` requires a variant with a single `bool` field",
+            name
+        );
+    }
+
+    true
+}
+
+fn popstate(variant: &syn::Variant) -> Option<Option<syn::Path>> {
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("popstate"))?;
+
+    if !cfg!(feature = "listen") {
+        abort!(
+            attr.path,
+            "`#[popstate]` requires the `listen` feature of `yew-callbacks`"
+        );
+    }
+
+    if let Some(other) = variant.attrs.iter().find(|other| {
+        EXCLUSIVE_ATTRS
+            .iter()
+            .any(|x| *x != "popstate" && other.path.is_ident(x))
+    }) {
+        abort!(
+            other.path,
+            "`#[popstate]` cannot be used together with `#[{}]`",
+            other.path.get_ident().unwrap()
+        );
+    }
+
+    if variant.fields.len() != 1 || variant.fields.iter().any(is_curried) {
+        abort!(
+            variant,
+            "`#[popstate]` requires a variant with a single field for the location"
+        );
+    }
+
+    if attr.tokens.is_empty() {
+        return Some(None);
+    }
+
+    match attr.parse_args_with(|input: syn::parse::ParseStream| {
+        let ident = input.parse::<Ident>()?;
+        if ident != "parse" {
+            return Err(syn::Error::new(ident.span(), "expected `parse`"));
+        }
+        input.parse::<syn::Token![=]>()?;
+
+        input.parse::<syn::Path>()
+    }) {
+        Ok(parse) => Some(Some(parse)),
+        Err(err) => abort!(err.span(), "{}", err),
+    }
+}
+
+fn storage(variant: &syn::Variant) -> Option<syn::LitStr> {
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("storage"))?;
+
+    if !cfg!(feature = "storage") {
+        abort!(
+            attr.path,
+            "`#[storage]` requires the `storage` feature of `yew-callbacks`"
+        );
+    }
+
+    if let Some(other) = variant.attrs.iter().find(|other| {
+        EXCLUSIVE_ATTRS
+            .iter()
+            .any(|x| *x != "storage" && other.path.is_ident(x))
+    }) {
+        abort!(
+            other.path,
+            "`#[storage]` cannot be used together with `#[{}]`",
+            other.path.get_ident().unwrap()
+        );
+    }
+
+    if variant.fields.len() != 1 || variant.fields.iter().any(is_curried) {
+        abort!(
+            variant,
+            "`#[storage]` requires a variant with a single field for the value"
+        );
+    }
+
+    match attr.parse_args::<syn::LitStr>() {
+        Ok(key) => Some(key),
+        Err(err) => abort!(err.span(), "{}", err),
+    }
+}
+
+fn broadcast(variant: &syn::Variant) -> Option<syn::LitStr> {
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("broadcast"))?;
+
+    if !cfg!(feature = "broadcast") {
+        abort!(
+            attr.path,
+            "`#[broadcast]` requires the `broadcast` feature of `yew-callbacks`"
+        );
+    }
+
+    if let Some(other) = variant.attrs.iter().find(|other| {
+        EXCLUSIVE_ATTRS
+            .iter()
+            .any(|x| *x != "broadcast" && other.path.is_ident(x))
+    }) {
+        abort!(
+            other.path,
+            "`#[broadcast]` cannot be used together with `#[{}]`",
+            other.path.get_ident().unwrap()
+        );
+    }
+
+    if variant.fields.len() != 1 || variant.fields.iter().any(is_curried) {
+        abort!(
+            variant,
+            "`#[broadcast]` requires a variant with a single field for the message"
+        );
+    }
+
+    match attr.parse_args::<syn::LitStr>() {
+        Ok(name) => Some(name),
+        Err(err) => abort!(err.span(), "{}", err),
+    }
+}
+
+#[derive(Default)]
+struct WebSocket {
+    json: bool,
+    open: Option<Ident>,
+    error: Option<Ident>,
+    closed: Option<Ident>,
+}
+
+fn websocket<'a>(
+    variant: &syn::Variant,
+    variants: impl IntoIterator<Item = &'a syn::Variant>,
+) -> Option<WebSocket> {
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("websocket"))?;
+
+    if !cfg!(feature = "net") {
+        abort!(
+            attr.path,
+            "`#[websocket]` requires the `net` feature of `yew-callbacks`"
+        );
+    }
+
+    if variant.fields.len() != 1 || variant.fields.iter().any(is_curried) {
+        abort!(
+            variant,
+            "`#[websocket]` requires a variant with a single field for the frames"
+        );
+    }
+
+    let mut websocket = WebSocket::default();
+    if attr.tokens.is_empty() {
+        return Some(websocket);
+    }
+
+    let list = match attr.parse_meta() {
+        Ok(syn::Meta::List(list)) => list,
+        Ok(meta) => abort!(meta, "expected `#[websocket(...)]`"),
+        Err(err) => abort!(err.span(), "{}", err),
+    };
+    let variants = variants.into_iter().collect::<Vec<_>>();
+
+    for nested in list.nested.iter() {
+        match nested {
+            syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("json") => {
+                websocket.json = true;
+            }
+            syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                path,
+                lit: syn::Lit::Str(lit),
+                ..
+            })) => {
+                let slot = if path.is_ident("open") {
+                    &mut websocket.open
+                } else if path.is_ident("error") {
+                    &mut websocket.error
+                } else if path.is_ident("closed") {
+                    &mut websocket.closed
+                } else {
+                    abort!(path, "expected `open`, `error` or `closed`");
+                };
+                let ident = match lit.parse::<Ident>() {
+                    Ok(ident) => ident,
+                    Err(err) => abort!(lit, "{}", err),
+                };
+                match variants.iter().find(|x| x.ident == ident) {
+                    Some(x) if matches!(x.fields, syn::Fields::Unit) => *slot = Some(ident),
+                    Some(x) => abort!(x, "`{}` must be a unit variant", ident),
+                    None => abort!(lit, "unknown variant `{}`", ident),
+                }
+            }
+            _ => abort!(nested, "unknown `websocket` argument"),
+        }
+    }
+
+    Some(websocket)
+}
+
+enum Clipboard {
+    Paste,
+    Write,
+}
+
+fn clipboard(variant: &syn::Variant) -> Option<Clipboard> {
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("clipboard"))?;
+
+    if !cfg!(feature = "clipboard") {
+        abort!(
+            attr.path,
+            "`#[clipboard]` requires the `clipboard` feature of `yew-callbacks`"
+        );
+    }
+
+    let clipboard = match attr.parse_args::<Ident>() {
+        Ok(ident) if ident == "paste" => Clipboard::Paste,
+        Ok(ident) if ident == "write" => Clipboard::Write,
+        Ok(ident) => abort!(ident, "expected `paste` or `write`"),
+        Err(err) => abort!(err.span(), "{}", err),
+    };
+
+    if variant.fields.iter().any(is_curried) {
+        abort!(
+            variant,
+            "`#[clipboard]` cannot be used on a variant with curried fields"
+        );
+    }
+
+    match clipboard {
+        Clipboard::Paste if variant.fields.len() != 1 => abort!(
+            variant,
+            "`#[clipboard(paste)]` requires a variant with a single field for the data"
+        ),
+        Clipboard::Write if variant.fields.len() > 1 => abort!(
+            variant,
+            "`#[clipboard(write)]` requires a variant with no field or a single `bool` field"
+        ),
+        _ => {}
+    }
+
+    Some(clipboard)
+}
+
+struct Sensor {
+    event_type: &'static str,
+    interface: &'static str,
+    extract: &'static str,
+}
+
+fn sensor(variant: &syn::Variant) -> Option<Sensor> {
+    let attr = variant.attrs.iter().find(|attr| {
+        attr.path.is_ident("device_orientation") || attr.path.is_ident("device_motion")
+    })?;
+    let name = attr.path.get_ident().unwrap();
+
+    if !cfg!(feature = "sensors") {
+        abort!(
+            attr.path,
+            "`#[{}]` requires the `sensors` feature of `yew-callbacks`",
+            name
+        );
+    }
+
+    if let Some(other) = variant.attrs.iter().find(|other| {
+        EXCLUSIVE_ATTRS
+            .iter()
+            .any(|x| name != x && other.path.is_ident(x))
+    }) {
+        abort!(
+            other.path,
+            "`#[{}]` cannot be used together with `#[{}]`",
+            name,
+            other.path.get_ident().unwrap()
+        );
+    }
+
+    if !attr.tokens.is_empty() {
+        abort!(attr.tokens, "`#[{}]` does not take any argument", name);
+    }
+
+    if variant.fields.len() != 1 || variant.fields.iter().any(is_curried) {
+        abort!(
+            variant,
+            "`#[{}]` requires a variant with a single field for the data of the sensor",
+            name
+        );
+    }
+
+    if name == "device_orientation" {
+        Some(Sensor {
+            event_type: "deviceorientation",
+            interface: "DeviceOrientationEvent",
+            extract: "device_orientation",
+        })
+    } else {
+        Some(Sensor {
+            event_type: "devicemotion",
+            interface: "DeviceMotionEvent",
+            extract: "device_motion",
+        })
+    }
+}
+
+fn gamepad(variant: &syn::Variant) -> bool {
+    let attr = match variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("gamepad"))
+    {
+        Some(attr) => attr,
+        None => return false,
+    };
+
+    if !cfg!(feature = "gamepad") {
+        abort!(
+            attr.path,
+            "`#[gamepad]` requires the `gamepad` feature of `yew-callbacks`"
+        );
+    }
+
+    if let Some(other) = variant.attrs.iter().find(|other| {
+        EXCLUSIVE_ATTRS
+            .iter()
+            .any(|x| *x != "gamepad" && other.path.is_ident(x))
+    }) {
+        abort!(
+            other.path,
+            "`#[gamepad]` cannot be used together with `#[{}]`",
+            other.path.get_ident().unwrap()
+        );
+    }
+
+    if !attr.tokens.is_empty() {
+        abort!(attr.tokens, "`#[gamepad]` does not take any argument");
+    }
+
+    if variant.fields.len() != 1 || variant.fields.iter().any(is_curried) {
+        abort!(
+            variant,
+            "`#[gamepad]` requires a variant with a single `GamepadInput` field"
+        );
+    }
+
+    true
+}
+
+struct Scroll {
+    node_ref: bool,
+    millis: u32,
+}
+
+fn scroll(variant: &syn::Variant) -> Option<Scroll> {
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("scroll"))?;
+
+    if !cfg!(feature = "scroll") {
+        abort!(
+            attr.path,
+            "`#[scroll]` requires the `scroll` feature of `yew-callbacks`"
+        );
+    }
+
+    if let Some(other) = variant.attrs.iter().find(|other| {
+        EXCLUSIVE_ATTRS
+            .iter()
+            .any(|x| *x != "scroll" && other.path.is_ident(x))
+    }) {
+        abort!(
+            other.path,
+            "`#[scroll]` cannot be used together with `#[{}]`",
+            other.path.get_ident().unwrap()
+        );
+    }
+
+    if variant.fields.len() != 1 || variant.fields.iter().any(is_curried) {
+        abort!(
+            variant,
+            "`#[scroll]` requires a variant with a single field for the scroll position"
+        );
+    }
+
+    match attr.parse_args_with(|input: syn::parse::ParseStream| {
+        let mut ident = input.parse::<Ident>()?;
+        let node_ref = ident == "node_ref";
+        if node_ref {
+            input.parse::<syn::Token![,]>()?;
+            ident = input.parse::<Ident>()?;
+        }
+        if ident != "throttle" {
+            return Err(syn::Error::new(ident.span(), "expected `throttle`"));
+        }
+        input.parse::<syn::Token![=]>()?;
+        let millis = input.parse::<syn::LitInt>()?.base10_parse::<u32>()?;
+
+        Ok(Scroll { node_ref, millis })
+    }) {
+        Ok(scroll) => Some(scroll),
+        Err(err) => abort!(err.span(), "{}", err),
+    }
+}
+
+fn before_unload(variant: &syn::Variant) -> Option<bool> {
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("before_unload"))?;
+
+    if !cfg!(feature = "listen") {
+        abort!(
+            attr.path,
+            "`#[before_unload]` requires the `listen` feature of `yew-callbacks`"
+        );
+    }
+
+    if let Some(other) = variant.attrs.iter().find(|other| {
+        EXCLUSIVE_ATTRS
+            .iter()
+            .any(|x| *x != "before_unload" && other.path.is_ident(x))
+    }) {
+        abort!(
+            other.path,
+            "`#[before_unload]` cannot be used together with `#[{}]`",
+            other.path.get_ident().unwrap()
+        );
+    }
+
+    if !variant.fields.is_empty() {
+        abort!(
+            variant.fields,
+            "`#[before_unload]` requires a variant without field"
+        );
+    }
+
+    if attr.tokens.is_empty() {
+        return Some(false);
+    }
+
+    match attr.parse_args::<Ident>() {
+        Ok(ident) if ident == "when_dirty" => Some(true),
+        Ok(ident) => abort!(ident, "expected `when_dirty`"),
+        Err(err) => abort!(err.span(), "{}", err),
+    }
+}
+
+struct Bridge {
+    worker: syn::Type,
+    path: syn::LitStr,
+}
+
+fn bridge(variant: &syn::Variant) -> Option<Bridge> {
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("bridge"))?;
+
+    if !cfg!(feature = "agent") {
+        abort!(
+            attr.path,
+            "`#[bridge]` requires the `agent` feature of `yew-callbacks`"
+        );
+    }
+
+    if let Some(other) = variant.attrs.iter().find(|other| {
+        EXCLUSIVE_ATTRS
+            .iter()
+            .any(|x| *x != "bridge" && other.path.is_ident(x))
+    }) {
+        abort!(
+            other.path,
+            "`#[bridge]` cannot be used together with `#[{}]`",
+            other.path.get_ident().unwrap()
+        );
+    }
+
+    if variant.fields.len() != 1 || variant.fields.iter().any(is_curried) {
+        abort!(
+            variant,
+            "`#[bridge]` requires a variant with a single field for the output of the worker"
+        );
+    }
+
+    match attr.parse_args_with(|input: syn::parse::ParseStream| {
+        let worker = input.parse::<syn::Type>()?;
+        input.parse::<syn::Token![,]>()?;
+        let path = input.parse::<syn::LitStr>()?;
+
+        Ok(Bridge { worker, path })
+    }) {
+        Ok(bridge) => Some(bridge),
+        Err(err) => abort!(err.span(), "{}", err),
+    }
+}
+
+enum FormKind {
+    Input,
+    Change,
+    Submit,
+}
+
+struct FormVariant {
+    model: syn::Path,
+    kind: FormKind,
+    error: Option<Ident>,
+}
+
+fn form_variant(variant: &syn::Variant) -> Option<FormVariant> {
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("form"))?;
+
+    if !cfg!(feature = "forms") {
+        abort!(
+            attr.path,
+            "`#[form]` requires the `forms` feature of `yew-callbacks`"
+        );
+    }
+
+    // the callbacks of a form build the message themselves
+    if let Some(other) = variant.attrs.iter().find(|other| {
+        HELPER_ATTRS
+            .iter()
+            .any(|x| *x != "form" && *x != "callbacks" && other.path.is_ident(x))
+    }) {
+        abort!(
+            other.path,
+            "`#[form]` cannot be used together with `#[{}]`",
+            other.path.get_ident().unwrap()
+        );
+    }
+
+    if variant.fields.len() != 1 || variant.fields.iter().any(is_curried) {
+        abort!(
+            variant,
+            "`#[form]` requires a variant with a single field for the edit or the model"
+        );
+    }
+
+    match attr.parse_args_with(|input: syn::parse::ParseStream| {
+        let model = input.parse::<syn::Path>()?;
+        let mut kind = FormKind::Input;
+        let mut error = None;
+
+        while input.parse::<Option<syn::Token![,]>>()?.is_some() {
+            let ident = input.parse::<Ident>()?;
+            match ident.to_string().as_str() {
+                "change" => kind = FormKind::Change,
+                "submit" => kind = FormKind::Submit,
+                "error" => {
+                    input.parse::<syn::Token![=]>()?;
+                    error = Some(input.parse::<Ident>()?);
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "expected `change`, `submit` or `error = Variant`",
+                    ))
+                }
+            }
+        }
+
+        Ok(FormVariant { model, kind, error })
+    }) {
+        Ok(form) => Some(form),
+        Err(err) => abort!(err.span(), "{}", err),
+    }
+}
+
+fn geolocation(variant: &syn::Variant) -> bool {
+    let attr = match variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("geolocation"))
+    {
+        Some(attr) => attr,
+        None => return false,
+    };
+
+    if !cfg!(feature = "geolocation") {
+        abort!(
+            attr.path,
+            "`#[geolocation]` requires the `geolocation` feature of `yew-callbacks`"
+        );
+    }
+
+    if let Some(other) = variant.attrs.iter().find(|other| {
+        EXCLUSIVE_ATTRS
+            .iter()
+            .any(|x| *x != "geolocation" && other.path.is_ident(x))
+    }) {
+        abort!(
+            other.path,
+            "`#[geolocation]` cannot be used together with `#[{}]`",
+            other.path.get_ident().unwrap()
+        );
+    }
+
+    if variant.fields.len() != 1 || variant.fields.iter().any(is_curried) {
+        abort!(
+            variant,
+            "`#[geolocation]` requires a variant with a single field for the position"
+        );
+    }
+
+    match attr.parse_args::<Ident>() {
+        Ok(ident) if ident == "watch" => true,
+        Ok(ident) => abort!(ident, "expected `watch`"),
+        Err(err) => abort!(err.span(), "{}", err),
+    }
+}
+
+fn capture(variant: &syn::Variant) -> bool {
+    let attr = match variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("capture"))
+    {
+        Some(attr) => attr,
+        None => return false,
+    };
+
+    if !cfg!(feature = "pointer") {
+        abort!(
+            attr.path,
+            "`#[capture]` requires the `pointer` feature of `yew-callbacks`"
+        );
+    }
+
+    if !attr.tokens.is_empty() {
+        abort!(attr.tokens, "`#[capture]` does not take any argument");
+    }
+
+    if let Some(other) = variant.attrs.iter().find(|attr| {
+        ["clipboard", "data_transfer", "drop_files"]
+            .iter()
+            .any(|name| attr.path.is_ident(name))
+    }) {
+        abort!(
+            other.path,
+            "`#[capture]` cannot be used together with `#[{}]`",
+            other.path.get_ident().unwrap()
+        );
+    }
+
+    let events = variant.fields.iter().filter(|field| is_input(field));
+    if events.count() != 1 {
+        abort!(
+            variant,
+            "`#[capture]` requires a variant with a single field for the `PointerEvent`"
+        );
+    }
+
+    true
+}
+
+fn drag(variant: &syn::Variant) -> Option<syn::LitStr> {
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("data_transfer") || attr.path.is_ident("drop_files"))?;
+    let name = attr.path.get_ident().unwrap();
+
+    if !cfg!(feature = "drag") {
+        abort!(
+            attr.path,
+            "`#[{}]` requires the `drag` feature of `yew-callbacks`",
+            name
+        );
+    }
+
+    if variant
+        .attrs
+        .iter()
+        .any(|attr| attr.path.is_ident("clipboard"))
+    {
+        abort!(
+            attr.path,
+            "`#[{}]` cannot be used together with `#[clipboard]`",
+            name
+        );
+    }
+
+    if variant.fields.len() != 1 || variant.fields.iter().any(is_curried) {
+        abort!(
+            variant,
+            "`#[{}]` requires a variant with a single field for the data",
+            name
+        );
+    }
+
+    if attr.path.is_ident("drop_files") || attr.tokens.is_empty() {
+        if !attr.tokens.is_empty() {
+            abort!(attr.tokens, "`#[drop_files]` does not take any argument");
+        }
+
+        return Some(syn::LitStr::new("text/plain", Span::call_site()));
+    }
+
+    match attr.parse_args::<syn::LitStr>() {
+        Ok(format) => Some(format),
+        Err(err) => abort!(err.span(), "{}", err),
+    }
+}
+
+struct EventSource {
+    event_type: syn::LitStr,
+    json: bool,
+}
+
+fn event_source(variant: &syn::Variant) -> Option<EventSource> {
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("event_source"))?;
+
+    if !cfg!(feature = "net") {
+        abort!(
+            attr.path,
+            "`#[event_source]` requires the `net` feature of `yew-callbacks`"
+        );
+    }
+
+    if variant.fields.len() != 1 || variant.fields.iter().any(is_curried) {
+        abort!(
+            variant,
+            "`#[event_source]` requires a variant with a single field for the data"
+        );
+    }
+
+    match attr.parse_args_with(|input: syn::parse::ParseStream| {
+        let event_type = input.parse::<syn::LitStr>()?;
+        let json = if input.parse::<Option<syn::Token![,]>>()?.is_some() {
+            let ident = input.parse::<Ident>()?;
+            if ident != "json" {
+                return Err(syn::Error::new(ident.span(), "expected `json`"));
+            }
+            true
+        } else {
+            false
+        };
+
+        Ok(EventSource { event_type, json })
+    }) {
+        Ok(event_source) => Some(event_source),
+        Err(err) => abort!(err.span(), "{}", err),
+    }
+}
+
+fn media_query(variant: &syn::Variant) -> Option<syn::LitStr> {
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("media_query"))?;
+
+    if !cfg!(feature = "listen") {
+        abort!(
+            attr.path,
+            "`#[media_query]` requires the `listen` feature of `yew-callbacks`"
+        );
+    }
+
+    if variant.fields.len() != 1 || variant.fields.iter().any(is_curried) {
+        abort!(
+            variant,
+            "`#[media_query]` requires a variant with a single `bool` field"
+        );
+    }
+
+    match attr.parse_args::<syn::LitStr>() {
+        Ok(query) => Some(query),
+        Err(err) => abort!(err.span(), "{}", err),
+    }
+}
+
+struct ObserveIntersection {
+    threshold: f64,
+}
+
+fn observe_intersection(variant: &syn::Variant) -> Option<ObserveIntersection> {
+    let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("observe_intersection"))?;
+
+    if !cfg!(feature = "observers") {
+        abort!(
+            attr.path,
+            "`#[observe_intersection]` requires the `observers` feature of `yew-callbacks`"
+        );
+    }
+
+    let curried = variant
+        .fields
+        .iter()
+        .filter(|field| is_curried(field))
+        .count();
+    if curried != 1 || variant.fields.len() != 2 {
+        abort!(
+            variant,
+            "`#[observe_intersection]` requires a variant with a curried key and a `bool` field"
+        );
+    }
+
+    if attr.tokens.is_empty() {
+        return Some(ObserveIntersection { threshold: 0.0 });
+    }
+
+    match attr.parse_args_with(|input: syn::parse::ParseStream| {
+        let ident = input.parse::<Ident>()?;
+        if ident != "threshold" {
+            return Err(syn::Error::new(ident.span(), "expected `threshold`"));
+        }
+        input.parse::<syn::Token![=]>()?;
+        let threshold = input.parse::<syn::LitFloat>()?;
+        let threshold = threshold.base10_parse::<f64>()?;
+
+        Ok(ObserveIntersection { threshold })
+    }) {
+        Ok(intersection) if (0.0..=1.0).contains(&intersection.threshold) => Some(intersection),
+        Ok(_) => abort!(attr.tokens, "the threshold must be between 0.0 and 1.0"),
+        Err(err) => abort!(err.span(), "{}", err),
+    }
+}
+
+const OPTIONS: &[&str] = &[
+    "eager", "by_name", "kind", "props", "handle", "context", "hook", "remote",
+];
+
+#[derive(Default)]
+struct Options {
+    eager: bool,
+    by_name: bool,
+    kind: bool,
+    props: bool,
+    handle: bool,
+    context: bool,
+    hook: bool,
+    remote: Option<syn::Path>,
+}
+
+impl Options {
+    fn from_attrs(attrs: &[syn::Attribute]) -> Self {
+        let mut options = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("callbacks")) {
+            let list = match attr.parse_meta() {
+                Ok(syn::Meta::List(list)) => list,
+                Ok(meta) => abort!(meta, "expected `#[callbacks(...)]`"),
+                Err(err) => abort!(err.span(), "{}", err),
+            };
+
+            for nested in list.nested.iter() {
+                match nested {
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("eager") => {
+                        options.eager = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("by_name") => {
+                        options.by_name = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("kind") => {
+                        options.kind = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("props") => {
+                        options.props = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("handle") => {
+                        options.handle = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("context") => {
+                        options.context = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("hook") => {
+                        options.hook = true;
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                        path,
+                        lit: syn::Lit::Str(lit),
+                        ..
+                    })) if path.is_ident("remote") => match lit.parse() {
+                        Ok(path) => options.remote = Some(path),
+                        Err(err) => abort!(lit, "{}", err),
+                    },
+                    syn::NestedMeta::Meta(meta) if meta.path().is_ident("remote") => abort!(
+                        meta,
+                        "expected the path of the enum, like `remote = \"other_crate::Msg\"`"
+                    ),
+                    syn::NestedMeta::Meta(meta)
+                        if OPTIONS.iter().any(|x| meta.path().is_ident(x)) =>
+                    {
+                        abort!(
+                            meta,
+                            "`{}` does not take any argument",
+                            meta.path().get_ident().unwrap()
+                        )
+                    }
+                    _ => abort!(
+                        nested,
+                        "unknown `callbacks` argument, expected one of {}",
+                        OPTIONS
+                            .iter()
+                            .map(|x| format!("`{x}`"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                }
+            }
+        }
+
+        // an inherent method cannot be added to the enum of another crate
+        if let (Some(remote), true) = (&options.remote, options.kind) {
+            abort!(remote, "`kind` cannot be used with `remote`");
+        }
+
+        options
+    }
+}